@@ -0,0 +1,329 @@
+//! Adapters for the DEXes a shitcoin can launch on.
+//!
+//! Every adapter talks to the pool factory the admin configured for its DEX, and shitcoins can
+//! only pick the DEXes the admin has enabled.
+
+use astroport::{
+    asset::{Asset, AssetInfo},
+    factory::{ExecuteMsg as PoolFactoryMsg, PairType},
+    pair::{ExecuteMsg as PairMsg, PoolResponse, QueryMsg as PairQuery},
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Binary, Coin, Decimal, Event, QuerierWrapper, StdError, StdResult, Uint128,
+    WasmMsg,
+};
+
+use crate::msg::Dex;
+
+/// The addresses a DEX reports after creating a pool.
+pub struct CreatedPool {
+    pub pair: String,
+    pub lp_token: String,
+}
+
+/// The reserves of a pool and the LP share minted against them.
+pub struct Pool {
+    pub reserves: Vec<Coin>,
+    pub total_share: Uint128,
+}
+
+pub trait DexAdapter {
+    /// Whether the pool is priced when it is created, in which case it is only created at
+    /// launch once the presale price is known.
+    fn creates_pool_at_launch(&self) -> bool {
+        false
+    }
+
+    /// Creates a pool for `denoms`, `price` is the amount of the first denom one unit of the
+    /// second is worth and only given for pools created at launch.
+    fn create_pool_msg(
+        &self,
+        factory: &str,
+        denoms: [&str; 2],
+        price: Option<Decimal>,
+    ) -> StdResult<WasmMsg>;
+
+    /// Finds the pair and LP token addresses in the events of a pool creation.
+    fn created_pool(&self, events: &[Event]) -> Option<CreatedPool>;
+
+    fn provide_liquidity_msg(&self, pair: &str, assets: [Coin; 2]) -> StdResult<WasmMsg>;
+
+    /// Finds the LP share minted in the events of a liquidity provision.
+    fn provided_share(&self, events: &[Event]) -> Option<Uint128>;
+
+    fn query_pool(&self, querier: &QuerierWrapper, pair: &str) -> StdResult<Pool>;
+}
+
+pub fn adapter(dex: Dex) -> &'static dyn DexAdapter {
+    match dex {
+        Dex::AstroportXyk {} => &AstroportXyk,
+        Dex::AstroportPcl {} => &AstroportPcl,
+        Dex::Mock {} => &Mock,
+    }
+}
+
+/// Finds the first value of `key` in the `wasm` events of a submessage.
+fn wasm_attribute<'a>(events: &'a [Event], key: &str) -> Option<&'a str> {
+    events
+        .iter()
+        .filter(|event| event.ty == "wasm")
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+}
+
+fn astroport_create_pair_msg(
+    factory: &str,
+    denoms: [&str; 2],
+    pair_type: PairType,
+    init_params: Option<Binary>,
+) -> StdResult<WasmMsg> {
+    let create_pair_msg = PoolFactoryMsg::CreatePair {
+        pair_type,
+        asset_infos: denoms.map(AssetInfo::native).to_vec(),
+        init_params,
+    };
+
+    Ok(WasmMsg::Execute {
+        contract_addr: factory.to_owned(),
+        msg: to_json_binary(&create_pair_msg)?,
+        funds: vec![],
+    })
+}
+
+fn astroport_created_pool(events: &[Event]) -> Option<CreatedPool> {
+    Some(CreatedPool {
+        pair: wasm_attribute(events, "pair_contract_addr")?.to_owned(),
+        lp_token: wasm_attribute(events, "liquidity_token_addr")?.to_owned(),
+    })
+}
+
+fn astroport_provide_liquidity_msg(pair: &str, assets: [Coin; 2]) -> StdResult<WasmMsg> {
+    let provide_liquidity_msg = PairMsg::ProvideLiquidity {
+        assets: assets
+            .iter()
+            .map(|coin| Asset {
+                info: AssetInfo::native(&coin.denom),
+                amount: coin.amount,
+            })
+            .collect(),
+        slippage_tolerance: None,
+        auto_stake: None,
+        receiver: None,
+    };
+
+    Ok(WasmMsg::Execute {
+        contract_addr: pair.to_owned(),
+        msg: to_json_binary(&provide_liquidity_msg)?,
+        funds: assets.to_vec(),
+    })
+}
+
+fn astroport_provided_share(events: &[Event]) -> Option<Uint128> {
+    wasm_attribute(events, "share").and_then(|share| share.parse().ok())
+}
+
+fn astroport_query_pool(querier: &QuerierWrapper, pair: &str) -> StdResult<Pool> {
+    let pool: PoolResponse = querier.query_wasm_smart(pair, &PairQuery::Pool {})?;
+
+    let reserves = pool
+        .assets
+        .into_iter()
+        .map(|asset| Coin {
+            denom: asset.info.to_string(),
+            amount: asset.amount,
+        })
+        .collect();
+
+    Ok(Pool {
+        reserves,
+        total_share: pool.total_share,
+    })
+}
+
+/// Astroport constant product pools.
+pub struct AstroportXyk;
+
+impl DexAdapter for AstroportXyk {
+    fn create_pool_msg(
+        &self,
+        factory: &str,
+        denoms: [&str; 2],
+        _price: Option<Decimal>,
+    ) -> StdResult<WasmMsg> {
+        astroport_create_pair_msg(factory, denoms, PairType::Xyk {}, None)
+    }
+
+    fn created_pool(&self, events: &[Event]) -> Option<CreatedPool> {
+        astroport_created_pool(events)
+    }
+
+    fn provide_liquidity_msg(&self, pair: &str, assets: [Coin; 2]) -> StdResult<WasmMsg> {
+        astroport_provide_liquidity_msg(pair, assets)
+    }
+
+    fn provided_share(&self, events: &[Event]) -> Option<Uint128> {
+        astroport_provided_share(events)
+    }
+
+    fn query_pool(&self, querier: &QuerierWrapper, pair: &str) -> StdResult<Pool> {
+        astroport_query_pool(querier, pair)
+    }
+}
+
+/// The init params of an Astroport concentrated liquidity pair, mirrored here so the garden
+/// does not depend on the pair crate.
+#[cw_serde]
+pub struct ConcentratedPoolParams {
+    pub amp: Decimal,
+    pub gamma: Decimal,
+    pub mid_fee: Decimal,
+    pub out_fee: Decimal,
+    pub fee_gamma: Decimal,
+    pub repeg_profit_threshold: Decimal,
+    pub min_price_scale_delta: Decimal,
+    pub price_scale: Decimal,
+    pub ma_half_time: u64,
+}
+
+impl ConcentratedPoolParams {
+    /// Astroport's recommended parameters for volatile pairs, starting at `price_scale`.
+    fn volatile(price_scale: Decimal) -> Self {
+        Self {
+            amp: Decimal::raw(10_000_000_000_000_000_000),
+            gamma: Decimal::raw(145_000_000_000_000),
+            mid_fee: Decimal::raw(2_600_000_000_000_000),
+            out_fee: Decimal::raw(45_000_000_000_000_000),
+            fee_gamma: Decimal::raw(230_000_000_000_000),
+            repeg_profit_threshold: Decimal::raw(2_000_000_000_000),
+            min_price_scale_delta: Decimal::raw(146_000_000_000_000),
+            price_scale,
+            ma_half_time: 600,
+        }
+    }
+}
+
+/// Astroport concentrated liquidity pools, which have to be created at the presale price.
+pub struct AstroportPcl;
+
+impl DexAdapter for AstroportPcl {
+    fn creates_pool_at_launch(&self) -> bool {
+        true
+    }
+
+    fn create_pool_msg(
+        &self,
+        factory: &str,
+        denoms: [&str; 2],
+        price: Option<Decimal>,
+    ) -> StdResult<WasmMsg> {
+        let price = price.ok_or_else(|| {
+            StdError::generic_err("concentrated liquidity pools need an initial price")
+        })?;
+
+        let init_params = to_json_binary(&ConcentratedPoolParams::volatile(price))?;
+
+        astroport_create_pair_msg(
+            factory,
+            denoms,
+            PairType::Custom("concentrated".to_owned()),
+            Some(init_params),
+        )
+    }
+
+    fn created_pool(&self, events: &[Event]) -> Option<CreatedPool> {
+        astroport_created_pool(events)
+    }
+
+    fn provide_liquidity_msg(&self, pair: &str, assets: [Coin; 2]) -> StdResult<WasmMsg> {
+        astroport_provide_liquidity_msg(pair, assets)
+    }
+
+    fn provided_share(&self, events: &[Event]) -> Option<Uint128> {
+        astroport_provided_share(events)
+    }
+
+    fn query_pool(&self, querier: &QuerierWrapper, pair: &str) -> StdResult<Pool> {
+        astroport_query_pool(querier, pair)
+    }
+}
+
+/// The minimal pool protocol spoken by the local mock DEX, whose pools double as their LP
+/// tokens and take the same cw20 `Burn` and `Transfer` messages.
+pub mod mock {
+    use cosmwasm_schema::{cw_serde, QueryResponses};
+    use cosmwasm_std::{Coin, Uint128};
+
+    #[cw_serde]
+    pub enum ExecuteMsg {
+        /// Sent to the factory, reports `pool` and `lp_token` attributes.
+        CreatePool { denoms: Vec<String> },
+        /// Sent to the pool with the liquidity as funds, reports a `share` attribute.
+        ProvideLiquidity {},
+    }
+
+    #[cw_serde]
+    #[derive(QueryResponses)]
+    pub enum QueryMsg {
+        #[returns(ReservesResponse)]
+        Reserves {},
+    }
+
+    #[cw_serde]
+    pub struct ReservesResponse {
+        pub reserves: Vec<Coin>,
+        pub total_share: Uint128,
+    }
+}
+
+/// A local mock DEX, for testing launches without Astroport.
+pub struct Mock;
+
+impl DexAdapter for Mock {
+    fn create_pool_msg(
+        &self,
+        factory: &str,
+        denoms: [&str; 2],
+        _price: Option<Decimal>,
+    ) -> StdResult<WasmMsg> {
+        let create_pool_msg = mock::ExecuteMsg::CreatePool {
+            denoms: denoms.map(str::to_owned).to_vec(),
+        };
+
+        Ok(WasmMsg::Execute {
+            contract_addr: factory.to_owned(),
+            msg: to_json_binary(&create_pool_msg)?,
+            funds: vec![],
+        })
+    }
+
+    fn created_pool(&self, events: &[Event]) -> Option<CreatedPool> {
+        Some(CreatedPool {
+            pair: wasm_attribute(events, "pool")?.to_owned(),
+            lp_token: wasm_attribute(events, "lp_token")?.to_owned(),
+        })
+    }
+
+    fn provide_liquidity_msg(&self, pair: &str, assets: [Coin; 2]) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: pair.to_owned(),
+            msg: to_json_binary(&mock::ExecuteMsg::ProvideLiquidity {})?,
+            funds: assets.to_vec(),
+        })
+    }
+
+    fn provided_share(&self, events: &[Event]) -> Option<Uint128> {
+        wasm_attribute(events, "share").and_then(|share| share.parse().ok())
+    }
+
+    fn query_pool(&self, querier: &QuerierWrapper, pair: &str) -> StdResult<Pool> {
+        let pool: mock::ReservesResponse =
+            querier.query_wasm_smart(pair, &mock::QueryMsg::Reserves {})?;
+
+        Ok(Pool {
+            reserves: pool.reserves,
+            total_share: pool.total_share,
+        })
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_string, StdError, Uint128};
+
+/// Generates [`ContractError`] with its [`fmt::Display`], along with the matching [`ErrorCode`]
+/// and [`ContractError::code`], from one table of variants and their messages.
+///
+/// Every message starts with the variant's code, as that string is all a client gets back from a
+/// failed transaction.
+macro_rules! contract_errors {
+    ($($variant:ident $({ $($field:ident: $ty:ty),* })? => $message:literal,)*) => {
+        #[derive(Debug, PartialEq)]
+        pub enum ContractError {
+            Std(StdError),
+            $($variant $({ $($field: $ty),* })?,)*
+        }
+
+        impl fmt::Display for ContractError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "[{}] ", self.code())?;
+
+                match self {
+                    ContractError::Std(err) => write!(f, "{err}"),
+                    $(ContractError::$variant $({ $($field),* })? => write!(f, $message),)*
+                }
+            }
+        }
+
+        /// Stable, machine-readable identifier for each [`ContractError`] variant.
+        ///
+        /// Codes are never renamed or reused, so clients can rely on them even when the human
+        /// readable messages change.
+        #[cw_serde]
+        #[derive(Copy, Eq, Hash)]
+        pub enum ErrorCode {
+            Std,
+            $($variant,)*
+        }
+
+        impl ContractError {
+            pub fn code(&self) -> ErrorCode {
+                match self {
+                    ContractError::Std(_) => ErrorCode::Std,
+                    $(ContractError::$variant { .. } => ErrorCode::$variant,)*
+                }
+            }
+        }
+    };
+}
+
+contract_errors! {
+    InvalidContractVersion { version: String } => "invalid contract version: {version}",
+    ContractMismatch { stored: String, current: String } => "cannot migrate {stored} to {current}",
+    ContractDowngrade { stored: String, current: String } =>
+        "cannot downgrade from {stored} to {current}",
+    NoPendingBackfill => "every index is already filled in",
+    NotAdmin => "only the garden admin can do that",
+    NotPendingAdmin => "you have not been proposed as the garden admin",
+    InvalidPresaleLength => "presale length has to be greater than zero",
+    InvalidPresaleLengthBounds =>
+        "min presale length has to be greater than zero and at most the max",
+    PresaleLengthOutOfBounds { min: u64, max: u64 } =>
+        "presale length must be between {min} and {max} seconds",
+    InvalidPresaleDenoms => "there has to be at least one presale denom and no duplicates",
+    PresaleDenomNotAccepted { denom: String } => "{denom} is not accepted for presales",
+    InvalidDexFactories => "there has to be at least one dex factory and no dex twice",
+    DexNotEnabled { dex: String } => "the {dex} dex is not enabled",
+    InvalidFeeRecipients =>
+        "there has to be at least one fee recipient and every weight has to be greater than zero",
+    InvalidCreatorFeeShare { max: u32 } => "creator fee share has to be at most {max} bps",
+    InvalidReferralFeeShare { max: u32 } => "referral fee share has to be at most {max} bps",
+    InvalidPresaleFeeRate { max: u32 } => "presale fee rate has to be less than {max} bps",
+    InvalidAllocationBounds =>
+        "allocation bounds have to be less than 10000 bps with a non-zero liquidity minimum",
+    InvalidAllocationTotal => "allocation has to add up to 10000 bps",
+    ZeroPresaleAllocation => "presale allocation has to be greater than zero",
+    LpAllocationTooLow { min: u32 } => "liquidity allocation has to be at least {min} bps",
+    CreatorAllocationTooHigh { max: u32 } => "creator allocation has to be at most {max} bps",
+    InvalidVestingSchedule => "vesting duration has to be greater than zero and at least the cliff",
+    InvalidLpLockDuration => "liquidity lock duration has to be greater than zero",
+    InvalidTickerLength { min: usize, max: usize } =>
+        "ticker must be between {min} and {max} characters",
+    InvalidTickerCharacters => "ticker can only contain letters and numbers",
+    ReservedTicker { ticker: String } => "ticker {ticker} is reserved",
+    DuplicateTicker { ticker: String } => "a shitcoin with the ticker {ticker} already exists",
+    InvalidNameLength { max: usize } => "name must be between 1 and {max} characters",
+    InvalidNameCharacters => "name can only contain printable ascii characters",
+    InvalidNameSpacing => "name cannot have leading, trailing or repeated spaces",
+    ZeroSupply => "supply must be greater than zero c'mon",
+    MissingCreateFee { denom: String } => "you must also send {denom} to create a shitcoin",
+    InsufficientCreateFee { amount: Uint128, denom: String } =>
+        "you must pay {amount} {denom} to create a shitcoin",
+    PresaleOver => "you're too late to enter this shitcoin's presale",
+    MissingPresaleFunds { denom: String } => "you must send {denom} to enter the presale",
+    SelfReferral => "nice try ser, you cannot refer yourself",
+    CreatorReferral => "the shitcoin creator cannot be a referrer",
+    BagTooSmol => "bag too smol",
+    PresaleNotOver => "patience young grasshopper the presale is not over",
+    PresaleRaised => "shitcoin is primed and ready for launch",
+    AlreadyLaunched => "shitcoin already launched",
+    NotLaunched => "shitcoin needs to be launched before claiming",
+    AlreadyClaimed => "shitcoins already claimed",
+    NotInPresale => "ser you did not enter this shitcoin presale",
+    NotCreator => "you are not the creator of this shitcoin",
+    InvalidAllowlistRoot { length: usize } =>
+        "allowlist root has to be a {length} byte sha256 hash",
+    InvalidAllowlistLength =>
+        "allowlist phase has to be greater than zero and end before the presale does",
+    AllowlistProofRequired { allowlist_end: u64 } =>
+        "this presale is allowlist only until {allowlist_end}, send a proof",
+    NotOnAllowlist => "ser you are not on the allowlist",
+    InvalidContributionLimits =>
+        "contribution limits have to be greater than zero with the minimum at most the maximum",
+    ContributionBelowMinimum { min: Uint128 } => "you have to contribute at least {min} in total",
+    ContributionAboveMaximum { remaining: Uint128 } =>
+        "you can only contribute {remaining} more to this presale",
+    ZeroPresaleCap => "presale caps have to be greater than zero",
+    SoftCapAboveHardCap => "soft cap cannot be greater than the hard cap",
+    PresaleFull => "this presale is full, the hard cap has been reached",
+    HardCapExceeded { remaining: Uint128 } => "this presale only has room for {remaining} more",
+    SoftCapNotReached => "the presale ended below its soft cap and cannot launch",
+    PresaleNotFailed => "no refunds ser, the presale reached its soft cap",
+    PoolPreseeded => "somebody seeded the pool before launch, claim a refund instead",
+    AlreadyRefunded => "presale already refunded",
+    InvalidWithdrawPenaltyRate { max: u32 } => "withdraw penalty rate has to be at most {max} bps",
+    WithdrawalsClosed => "the presale is over, withdrawals are closed",
+    InvalidWithdrawAmount { available: Uint128 } => "you can withdraw between 1 and {available}",
+    NothingVested => "nothing has vested since your last claim ser",
+    MissingLpShare => "liquidity provision did not report an lp share",
+    NoPendingLaunch => "no launch is waiting for its liquidity",
+    PairCreationFailed { reason: String } => "creating the pool failed: {reason}",
+    MissingPairAddress => "pool creation did not report the pair and lp token addresses",
+    NoPendingPair => "no shitcoin is waiting for its pool",
+    LpNotTimeLocked => "this shitcoin's liquidity is not time-locked",
+    LpStillLocked { unlocks_at: u64 } => "liquidity is locked until {unlocks_at}",
+    LpAlreadyClaimed => "liquidity already claimed",
+    MetadataLocked => "shitcoin metadata is locked once the denom admin is renounced at launch",
+    NotGuardian => "only the garden admin or guardian can do that",
+    OperationPaused { operation: String } => "{operation} is paused, hold tight ser",
+    CreatePausedPerShitcoin => "creating shitcoins can only be paused everywhere",
+    InvalidDelistReason { max: usize } => "delist reason must be between 1 and {max} characters",
+    Delisted { reason: String } => "this shitcoin was delisted: {reason}",
+}
+
+impl std::error::Error for ContractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContractError::Std(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<StdError> for ContractError {
+    fn from(err: StdError) -> Self {
+        ContractError::Std(err)
+    }
+}
+
+/// Prints the same snake_case code clients get in JSON.
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = to_json_string(self).map_err(|_| fmt::Error)?;
+
+        f.write_str(code.trim_matches('"'))
+    }
+}
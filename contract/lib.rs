@@ -1,126 +1,1661 @@
+pub mod dex;
+pub mod error;
+pub mod merkle;
+pub mod migrate;
 pub mod msg;
 pub mod state;
 
-use anyhow::{anyhow, bail, ensure, Result};
-use astroport::{
-    asset::{Asset, AssetInfo},
-    factory::{ExecuteMsg as PoolFactoryMsg, PairType},
-    pair::ExecuteMsg as PairMsg,
-    querier::query_pair_info,
-};
+use std::collections::BTreeSet;
+
+use astroport::{asset::AssetInfo, querier::query_pair_info};
 use cosmwasm_std::{
-    coin, coins, entry_point, to_json_binary, BankMsg, Binary, DenomUnit, Deps, DepsMut, Env,
-    Event, MessageInfo, StdError, Uint128, WasmMsg,
+    coin, coins, ensure, entry_point, to_json_binary, Addr, Api, BankMsg, Binary, CanonicalAddr,
+    Coin, Decimal, DenomUnit, Deps, DepsMut, Env, Event, HexBinary, MessageInfo, Reply, StdError,
+    StdResult, SubMsg, Uint128, WasmMsg,
 };
+use dex::DexAdapter;
+use error::ContractError;
 use msg::{
-    Config, DegenMetadata, ExecuteMsg, InstantiateMsg, QueryMsg, ShitcoinMetadata, ShitcoinPage,
+    Allocation, Allowlist, Claimable, Config, Cw20ExecuteMsg, DegenMetadata, Dex, DexFactory,
+    ExecuteMsg, FeeDestination, FeeRecipient, InstantiateMsg, LaunchSimulation, LpPolicy,
+    MigrateMsg, Moderation, Operation, Participant, ParticipantPage, PauseStatus, Position,
+    PositionPage, QueryMsg, ReferrerStats, ShitcoinMetadata, ShitcoinPage, ShitcoinStatus,
+    SortOrder, VestingStatus,
 };
 use neutron_sdk::bindings::msg::NeutronMsg;
 
-type Response = cosmwasm_std::Response<NeutronMsg>;
+type Response = cosmwasm_std::Response<NeutronMsg>;
+
+type Result<T, E = ContractError> = std::result::Result<T, E>;
+
+pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const HUNDRED_PERCENT_BPS: Uint128 = Uint128::new(10_000);
+pub const ONE_PERCENT_BPS: u32 = 100;
+pub const MAX_WITHDRAW_PENALTY_RATE: u32 = 5_000;
+pub const MAX_CREATOR_FEE_SHARE: u32 = 10_000;
+pub const MAX_REFERRAL_FEE_SHARE: u32 = 10_000;
+pub const TOTAL_ALLOCATION_BPS: u32 = 10_000;
+pub const DEFAULT_LP_ALLOCATION: u32 = 5_000;
+
+pub const PROVIDE_LIQUIDITY_REPLY_ID: u64 = 1;
+pub const CREATE_PAIR_REPLY_ID: u64 = 2;
+
+pub const DEFAULT_PAGE_LIMIT: u64 = 10;
+pub const MAX_PAGE_LIMIT: u64 = 30;
+/// A status filtered page gives up after looking at this many shitcoins, so a rare status cannot
+/// make one query walk the whole garden. The page may come back short, next still points on.
+pub const MAX_PAGE_SCAN: u64 = 100;
+
+/// Submissions indexed by one BackfillIndexes call when it does not give a limit.
+pub const DEFAULT_BACKFILL_LIMIT: u64 = 100;
+
+pub const MIN_TICKER_LENGTH: usize = 3;
+pub const MAX_TICKER_LENGTH: usize = 12;
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_DELIST_REASON_LENGTH: usize = 256;
+
+/// Tickers that would be confused with established assets, compared case-insensitively.
+pub const RESERVED_TICKERS: &[&str] = &[
+    "ntrn", "atom", "usdc", "usdt", "axl", "btc", "wbtc", "eth", "weth", "osmo", "tia", "dydx",
+];
+
+fn validate_presale_length(presale_length: u64) -> Result<()> {
+    ensure!(presale_length > 0, ContractError::InvalidPresaleLength);
+
+    Ok(())
+}
+
+fn validate_presale_length_bounds(min_presale_length: u64, max_presale_length: u64) -> Result<()> {
+    ensure!(
+        min_presale_length > 0 && min_presale_length <= max_presale_length,
+        ContractError::InvalidPresaleLengthBounds
+    );
+
+    Ok(())
+}
+
+fn validate_presale_length_in_bounds(
+    presale_length: u64,
+    min_presale_length: u64,
+    max_presale_length: u64,
+) -> Result<()> {
+    ensure!(
+        (min_presale_length..=max_presale_length).contains(&presale_length),
+        ContractError::PresaleLengthOutOfBounds {
+            min: min_presale_length,
+            max: max_presale_length,
+        }
+    );
+
+    Ok(())
+}
+
+fn validate_presale_denoms(presale_denoms: &[String]) -> Result<()> {
+    let unique = presale_denoms.iter().collect::<BTreeSet<_>>();
+
+    ensure!(
+        !presale_denoms.is_empty() && unique.len() == presale_denoms.len(),
+        ContractError::InvalidPresaleDenoms
+    );
+
+    Ok(())
+}
+
+fn validate_dex_factories(deps: Deps, dex_factories: &[DexFactory]) -> Result<()> {
+    let unique = dex_factories
+        .iter()
+        .map(|dex_factory| state::dex_name(dex_factory.dex))
+        .collect::<BTreeSet<_>>();
+
+    ensure!(
+        !dex_factories.is_empty() && unique.len() == dex_factories.len(),
+        ContractError::InvalidDexFactories
+    );
+
+    for dex_factory in dex_factories {
+        deps.api.addr_validate(&dex_factory.address)?;
+    }
+
+    Ok(())
+}
+
+/// The pool factory shitcoins on `dex` are created with, as long as the admin has enabled it.
+fn enabled_dex_factory(deps: Deps, dex: Dex) -> Result<String> {
+    state::dex_factory(deps.storage, dex).ok_or_else(|| ContractError::DexNotEnabled {
+        dex: state::dex_name(dex).to_owned(),
+    })
+}
+
+fn validate_fee_recipients(deps: Deps, fee_recipients: &[FeeRecipient]) -> Result<()> {
+    ensure!(
+        !fee_recipients.is_empty()
+            && fee_recipients
+                .iter()
+                .all(|fee_recipient| fee_recipient.weight > 0),
+        ContractError::InvalidFeeRecipients
+    );
+
+    for fee_recipient in fee_recipients {
+        if let FeeDestination::Address { address } = &fee_recipient.destination {
+            deps.api.addr_validate(address)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_creator_fee_share(creator_fee_share: u32) -> Result<()> {
+    ensure!(
+        creator_fee_share <= MAX_CREATOR_FEE_SHARE,
+        ContractError::InvalidCreatorFeeShare {
+            max: MAX_CREATOR_FEE_SHARE
+        }
+    );
+
+    Ok(())
+}
+
+fn validate_referral_fee_share(referral_fee_share: u32) -> Result<()> {
+    ensure!(
+        referral_fee_share <= MAX_REFERRAL_FEE_SHARE,
+        ContractError::InvalidReferralFeeShare {
+            max: MAX_REFERRAL_FEE_SHARE
+        }
+    );
+
+    Ok(())
+}
+
+fn validate_presale_fee_rate(presale_fee_rate: u32) -> Result<()> {
+    ensure!(
+        presale_fee_rate < ONE_PERCENT_BPS,
+        ContractError::InvalidPresaleFeeRate {
+            max: ONE_PERCENT_BPS
+        }
+    );
+
+    Ok(())
+}
+
+fn validate_withdraw_penalty_rate(withdraw_penalty_rate: u32) -> Result<()> {
+    ensure!(
+        withdraw_penalty_rate <= MAX_WITHDRAW_PENALTY_RATE,
+        ContractError::InvalidWithdrawPenaltyRate {
+            max: MAX_WITHDRAW_PENALTY_RATE
+        }
+    );
+
+    Ok(())
+}
+
+fn validate_allocation_bounds(min_lp_allocation: u32, max_creator_allocation: u32) -> Result<()> {
+    ensure!(
+        min_lp_allocation > 0
+            && min_lp_allocation < TOTAL_ALLOCATION_BPS
+            && max_creator_allocation < TOTAL_ALLOCATION_BPS,
+        ContractError::InvalidAllocationBounds
+    );
+
+    Ok(())
+}
+
+fn validate_allocation(deps: Deps, allocation: &Allocation) -> Result<()> {
+    let total = [allocation.lp, allocation.presale, allocation.creator]
+        .into_iter()
+        .map(u64::from)
+        .sum::<u64>();
+
+    ensure!(
+        total == TOTAL_ALLOCATION_BPS as u64,
+        ContractError::InvalidAllocationTotal
+    );
+
+    ensure!(allocation.presale > 0, ContractError::ZeroPresaleAllocation);
+
+    let min_lp_allocation = state::min_lp_allocation(deps.storage);
+
+    ensure!(
+        allocation.lp >= min_lp_allocation,
+        ContractError::LpAllocationTooLow {
+            min: min_lp_allocation
+        }
+    );
+
+    let max_creator_allocation = state::max_creator_allocation(deps.storage);
+
+    ensure!(
+        allocation.creator <= max_creator_allocation,
+        ContractError::CreatorAllocationTooHigh {
+            max: max_creator_allocation
+        }
+    );
+
+    Ok(())
+}
+
+/// Half liquidity and half presale, or as close to it as the liquidity minimum allows.
+fn default_allocation(deps: Deps) -> Allocation {
+    let lp = DEFAULT_LP_ALLOCATION.max(state::min_lp_allocation(deps.storage));
+
+    Allocation {
+        lp,
+        presale: TOTAL_ALLOCATION_BPS - lp,
+        creator: 0,
+    }
+}
+
+struct AllocationAmounts {
+    lp: Uint128,
+    presale: Uint128,
+    creator: Uint128,
+}
+
+/// Splits a shitcoin supply by its allocation, any rounding dust goes to the presale.
+fn allocation_amounts(supply: Uint128, allocation: &Allocation) -> AllocationAmounts {
+    let lp = supply.multiply_ratio(allocation.lp, TOTAL_ALLOCATION_BPS);
+
+    let creator = supply.multiply_ratio(allocation.creator, TOTAL_ALLOCATION_BPS);
+
+    AllocationAmounts {
+        lp,
+        presale: supply - lp - creator,
+        creator,
+    }
+}
+
+fn validate_vesting_schedule(cliff: u64, duration: u64) -> Result<()> {
+    ensure!(
+        duration > 0 && cliff <= duration,
+        ContractError::InvalidVestingSchedule
+    );
+
+    Ok(())
+}
+
+/// Nothing is released before the cliff, after which the total is released linearly from the
+/// start of the schedule until the duration has passed.
+fn vested_amount(total: Uint128, elapsed: u64, cliff: u64, duration: u64) -> Uint128 {
+    if elapsed < cliff {
+        return Uint128::zero();
+    }
+
+    if elapsed >= duration {
+        return total;
+    }
+
+    total.multiply_ratio(elapsed, duration)
+}
+
+fn validate_lp_policy(lp_policy: &LpPolicy) -> Result<()> {
+    if let LpPolicy::TimeLock { duration } = lp_policy {
+        ensure!(*duration > 0, ContractError::InvalidLpLockDuration);
+    }
+
+    Ok(())
+}
+
+fn lp_policy_name(lp_policy: &LpPolicy) -> &'static str {
+    match lp_policy {
+        LpPolicy::Burn {} => "burn",
+        LpPolicy::Lock {} => "lock",
+        LpPolicy::TimeLock { .. } => "time_lock",
+    }
+}
+
+fn validate_ticker(ticker: &str) -> Result<()> {
+    ensure!(
+        (MIN_TICKER_LENGTH..=MAX_TICKER_LENGTH).contains(&ticker.len()),
+        ContractError::InvalidTickerLength {
+            min: MIN_TICKER_LENGTH,
+            max: MAX_TICKER_LENGTH,
+        }
+    );
+
+    ensure!(
+        ticker.chars().all(|c| c.is_ascii_alphanumeric()),
+        ContractError::InvalidTickerCharacters
+    );
+
+    let subdenom = ticker.to_lowercase();
+
+    ensure!(
+        !RESERVED_TICKERS.contains(&subdenom.as_str()),
+        ContractError::ReservedTicker {
+            ticker: ticker.to_owned()
+        }
+    );
+
+    Ok(())
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    ensure!(
+        !name.is_empty() && name.len() <= MAX_NAME_LENGTH,
+        ContractError::InvalidNameLength {
+            max: MAX_NAME_LENGTH
+        }
+    );
+
+    ensure!(
+        name.chars().all(|c| c.is_ascii_graphic() || c == ' '),
+        ContractError::InvalidNameCharacters
+    );
+
+    ensure!(
+        name.trim() == name && !name.contains("  "),
+        ContractError::InvalidNameSpacing
+    );
+
+    Ok(())
+}
+
+fn validate_presale_caps(soft_cap: Option<Uint128>, hard_cap: Option<Uint128>) -> Result<()> {
+    ensure!(
+        !soft_cap.is_some_and(|cap| cap.is_zero()) && !hard_cap.is_some_and(|cap| cap.is_zero()),
+        ContractError::ZeroPresaleCap
+    );
+
+    if let (Some(soft_cap), Some(hard_cap)) = (soft_cap, hard_cap) {
+        ensure!(soft_cap <= hard_cap, ContractError::SoftCapAboveHardCap);
+    }
+
+    Ok(())
+}
+
+fn validate_contribution_limits(
+    min_contribution: Option<Uint128>,
+    max_contribution: Option<Uint128>,
+) -> Result<()> {
+    ensure!(
+        !min_contribution.is_some_and(|min| min.is_zero())
+            && !max_contribution.is_some_and(|max| max.is_zero()),
+        ContractError::InvalidContributionLimits
+    );
+
+    if let (Some(min), Some(max)) = (min_contribution, max_contribution) {
+        ensure!(min <= max, ContractError::InvalidContributionLimits);
+    }
+
+    Ok(())
+}
+
+fn validate_allowlist(allowlist: &Allowlist, presale_length: u64) -> Result<()> {
+    ensure!(
+        allowlist.root.len() == merkle::HASH_LENGTH,
+        ContractError::InvalidAllowlistRoot {
+            length: merkle::HASH_LENGTH
+        }
+    );
+
+    ensure!(
+        allowlist.length > 0 && allowlist.length < presale_length,
+        ContractError::InvalidAllowlistLength
+    );
+
+    Ok(())
+}
+
+/// The share of the presale allocation owed to `presale_submission`.
+fn presale_claim_amount(deps: Deps, denom: &str, presale_submission: Uint128) -> Result<Uint128> {
+    let presale_raise =
+        state::presale_raise(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    let shitcoin_supply =
+        state::shitcoin_supply(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    let allocation = state::shitcoin_allocation(deps.storage, denom)
+        .ok_or_else(|| StdError::not_found(denom))?;
+
+    let total_claimable_amount = allocation_amounts(shitcoin_supply, &allocation).presale;
+
+    Ok(total_claimable_amount.multiply_ratio(presale_submission, presale_raise))
+}
+
+/// A presale has failed once it has ended without reaching its soft cap.
+fn presale_failed(deps: Deps, denom: &str, presale_raise: Uint128) -> bool {
+    state::presale_soft_cap(deps.storage, denom).is_some_and(|soft_cap| presale_raise < soft_cap)
+}
+
+fn shitcoin_dex(deps: Deps, denom: &str) -> Result<&'static dyn DexAdapter> {
+    let dex = state::shitcoin_dex(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    Ok(dex::adapter(dex))
+}
+
+/// The pair and LP token addresses of a shitcoin's pool, unless it is only created at launch.
+///
+/// Shitcoins created before pairs were captured at creation still have to ask the factory.
+fn shitcoin_pair(deps: Deps, denom: &str) -> Result<Option<(String, String)>> {
+    if let (Some(pair), Some(lp_token)) = (
+        state::shitcoin_pair(deps.storage, denom),
+        state::shitcoin_lp_token(deps.storage, denom),
+    ) {
+        return Ok(Some((pair, lp_token)));
+    }
+
+    if shitcoin_dex(deps, denom)?.creates_pool_at_launch() {
+        return Ok(None);
+    }
+
+    let pool_factory = state::shitcoin_pool_factory(deps.storage, denom)
+        .ok_or_else(|| StdError::not_found(denom))?;
+
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, denom)
+        .ok_or_else(|| StdError::not_found(denom))?;
+
+    let pair_info = query_pair_info(
+        &deps.querier,
+        pool_factory,
+        &[AssetInfo::native(denom), AssetInfo::native(presale_denom)],
+    )?;
+
+    Ok(Some((
+        pair_info.contract_addr.into_string(),
+        pair_info.liquidity_token.into_string(),
+    )))
+}
+
+/// Whether somebody seeded the pool before the garden launched into it, which would have the
+/// garden deposit at whatever price they set.
+///
+/// Only liquidity provided by anyone else counts. Tokens sent straight to the pool mint no LP
+/// share, so the garden's deposit absorbs them and its LP share ends up backing them. Refusing to
+/// launch over them would let anybody force a presale into refunds for the price of a donation.
+///
+/// The trade-off is that a donation moves the launch price away from the presale price. The
+/// shitcoin only leaves the contract once it launched, so before launch only the presale side can
+/// be topped up: the launch price can only be pushed up, and only at the donor's expense.
+fn pool_preseeded(deps: Deps, denom: &str) -> Result<bool> {
+    // pools created at launch are seeded right away
+    let Some((pair, _)) = shitcoin_pair(deps, denom)? else {
+        return Ok(false);
+    };
+
+    let pool = shitcoin_dex(deps, denom)?.query_pool(&deps.querier, &pair)?;
+
+    Ok(!pool.total_share.is_zero())
+}
+
+/// The shitcoin's liquidity allocation and presale raise, as provided to its pool at launch.
+fn launch_liquidity(deps: Deps, denom: &str) -> Result<[Coin; 2]> {
+    let shitcoin_supply =
+        state::shitcoin_supply(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    let presale_raise =
+        state::presale_raise(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    let allocation = state::shitcoin_allocation(deps.storage, denom)
+        .ok_or_else(|| StdError::not_found(denom))?;
+
+    let lp_shitcoin_amount = allocation_amounts(shitcoin_supply, &allocation).lp;
+
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, denom)
+        .ok_or_else(|| StdError::not_found(denom))?;
+
+    Ok([
+        coin(lp_shitcoin_amount.u128(), denom),
+        coin(presale_raise.u128(), presale_denom),
+    ])
+}
+
+/// Provides the shitcoin's liquidity allocation and presale raise to its pool.
+fn seed_pool_msg(deps: Deps, denom: &str, pair: &str) -> Result<SubMsg<NeutronMsg>> {
+    let seed_pool_msg =
+        shitcoin_dex(deps, denom)?.provide_liquidity_msg(pair, launch_liquidity(deps, denom)?)?;
+
+    Ok(SubMsg::reply_on_success(
+        seed_pool_msg,
+        PROVIDE_LIQUIDITY_REPLY_ID,
+    ))
+}
+
+/// Whether an unlaunched shitcoin can no longer launch because its pool was pre-seeded.
+fn presale_aborted(deps: Deps, denom: &str) -> Result<bool> {
+    if state::presale_aborted(deps.storage, denom).unwrap_or_default() {
+        return Ok(true);
+    }
+
+    pool_preseeded(deps, denom)
+}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response> {
+    validate_presale_length(msg.presale_length)?;
+    validate_presale_length_bounds(msg.min_presale_length, msg.max_presale_length)?;
+    validate_presale_length_in_bounds(
+        msg.presale_length,
+        msg.min_presale_length,
+        msg.max_presale_length,
+    )?;
+    validate_dex_factories(deps.as_ref(), &msg.dex_factories)?;
+    validate_fee_recipients(deps.as_ref(), &msg.fee_recipients)?;
+    validate_creator_fee_share(msg.creator_fee_share)?;
+    validate_referral_fee_share(msg.referral_fee_share)?;
+    validate_presale_denoms(&msg.presale_denoms)?;
+    validate_presale_fee_rate(msg.presale_fee_rate)?;
+    validate_withdraw_penalty_rate(msg.presale_withdraw_penalty_rate)?;
+    validate_contribution_limits(msg.min_contribution, msg.max_contribution)?;
+    validate_allocation_bounds(msg.min_lp_allocation, msg.max_creator_allocation)?;
+    validate_vesting_schedule(msg.creator_vesting_cliff, msg.creator_vesting_duration)?;
+
+    let admin = match msg.admin {
+        Some(admin) => deps.api.addr_validate(&admin)?,
+        None => info.sender,
+    };
+
+    let denom_admin_burn_address = match msg.denom_admin_burn_address {
+        Some(address) => deps.api.addr_validate(&address)?,
+        None => zero_address(deps.api)?,
+    };
+
+    state::set_contract_name(deps.storage, CONTRACT_NAME);
+    state::set_contract_version(deps.storage, CONTRACT_VERSION);
+    state::set_admin(deps.storage, admin.as_str());
+    state::set_dex_factories(deps.storage, &msg.dex_factories);
+    state::set_dex(deps.storage, msg.dex);
+    state::set_fee_recipients(deps.storage, &msg.fee_recipients);
+    state::set_creator_fee_share(deps.storage, msg.creator_fee_share);
+    state::set_referral_fee_share(deps.storage, msg.referral_fee_share);
+    state::set_create_fee_denom(deps.storage, &msg.create_fee_denom);
+    state::set_create_fee(deps.storage, msg.create_fee);
+    state::set_presale_denoms(deps.storage, &msg.presale_denoms);
+    state::set_presale_length(deps.storage, msg.presale_length);
+    state::set_min_presale_length(deps.storage, msg.min_presale_length);
+    state::set_max_presale_length(deps.storage, msg.max_presale_length);
+    state::set_presale_fee_rate(deps.storage, msg.presale_fee_rate);
+    state::set_presale_withdraw_penalty_rate(deps.storage, msg.presale_withdraw_penalty_rate);
+
+    if let Some(min_contribution) = msg.min_contribution {
+        state::set_min_contribution(deps.storage, min_contribution);
+    }
+
+    if let Some(max_contribution) = msg.max_contribution {
+        state::set_max_contribution(deps.storage, max_contribution);
+    }
+
+    state::set_min_lp_allocation(deps.storage, msg.min_lp_allocation);
+    state::set_max_creator_allocation(deps.storage, msg.max_creator_allocation);
+    state::set_creator_vesting_cliff(deps.storage, msg.creator_vesting_cliff);
+    state::set_creator_vesting_duration(deps.storage, msg.creator_vesting_duration);
+    state::set_denom_admin_burn_address(deps.storage, denom_admin_burn_address.as_str());
+
+    enabled_dex_factory(deps.as_ref(), msg.dex)?;
+
+    Ok(Response::default())
+}
+
+#[entry_point]
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response> {
+    if let Some(contract_name) = state::contract_name(deps.storage) {
+        ensure!(
+            contract_name == CONTRACT_NAME,
+            ContractError::ContractMismatch {
+                stored: contract_name,
+                current: CONTRACT_NAME.to_owned(),
+            }
+        );
+    }
+
+    let stored_version =
+        state::contract_version(deps.storage).unwrap_or_else(|| migrate::UNVERSIONED.to_owned());
+
+    let from = migrate::parse_version(&stored_version)?;
+
+    let to = migrate::parse_version(CONTRACT_VERSION)?;
+
+    ensure!(
+        from <= to,
+        ContractError::ContractDowngrade {
+            stored: stored_version,
+            current: CONTRACT_VERSION.to_owned(),
+        }
+    );
+
+    let applied = migrate::run(deps.branch(), &env, from, to)?;
+
+    state::set_contract_name(deps.storage, CONTRACT_NAME);
+    state::set_contract_version(deps.storage, CONTRACT_VERSION);
+
+    let event = config_event("contract-migrated")
+        .add_attribute("from_version", stored_version)
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_attribute("migrations", applied.join(","));
+
+    Ok(Response::default().add_event(event))
+}
+
+/// Indexes the next submissions made before the indexes existed, see [`migrate::backfill_indexes`].
+/// Until it is done the indexes can miss positions and participants of older presales.
+pub fn backfill_indexes(deps: DepsMut, info: MessageInfo, limit: Option<u64>) -> Result<Response> {
+    ensure_admin(deps.as_ref(), &info)?;
+
+    let limit = limit.unwrap_or(DEFAULT_BACKFILL_LIMIT).max(1);
+
+    let done = migrate::backfill_indexes(deps.storage, limit)?;
+
+    let event = config_event("indexes-backfilled").add_attribute("done", done.to_string());
+
+    Ok(Response::default().add_event(event))
+}
+
+/// The all-zero account of the chain, which nobody holds a key for. The tokenfactory parses new
+/// denom admins as addresses, so renouncing to an empty one would be rejected.
+fn zero_address(api: &dyn Api) -> StdResult<Addr> {
+    api.addr_humanize(&CanonicalAddr::from(vec![0; 20]))
+}
+
+fn denom(env: &Env, subdenom: &str) -> String {
+    format!("factory/{}/{subdenom}", env.contract.address)
+}
+
+fn event(kind: &str, denom: &str, degen: Option<&str>) -> Event {
+    let event = Event::new("shitcoin-garden")
+        .add_attribute("kind", kind)
+        .add_attribute("denom", denom);
+
+    let Some(degen) = degen else {
+        return event;
+    };
+
+    event.add_attribute("degen", degen)
+}
+
+fn config_event(kind: &str) -> Event {
+    Event::new("shitcoin-garden-config").add_attribute("kind", kind)
+}
+
+fn ensure_admin(deps: Deps, info: &MessageInfo) -> Result<()> {
+    let admin = state::admin(deps.storage);
+
+    ensure!(
+        admin.as_str() == info.sender.as_str(),
+        ContractError::NotAdmin
+    );
+
+    Ok(())
+}
+
+fn ensure_admin_or_guardian(deps: Deps, info: &MessageInfo) -> Result<()> {
+    let admin = state::admin(deps.storage);
+
+    let guardian = state::guardian(deps.storage);
+
+    ensure!(
+        admin.as_str() == info.sender.as_str() || guardian.as_deref() == Some(info.sender.as_str()),
+        ContractError::NotGuardian
+    );
+
+    Ok(())
+}
+
+fn operation_name(operation: &Operation) -> &'static str {
+    match operation {
+        Operation::CreateShitcoin {} => "create_shitcoin",
+        Operation::EnterPresale {} => "enter_presale",
+        Operation::ExtendPresale {} => "extend_presale",
+        Operation::LaunchShitcoin {} => "launch_shitcoin",
+        Operation::ClaimShitcoin {} => "claim_shitcoin",
+        Operation::SetUrl {} => "set_url",
+    }
+}
+
+/// Rejects `operation` when it is paused everywhere, or for `denom` when there is one.
+fn ensure_not_paused(deps: Deps, operation: Operation, denom: Option<&str>) -> Result<()> {
+    let name = operation_name(&operation);
+
+    let paused = state::paused(deps.storage, name, None)
+        || denom.is_some_and(|denom| state::paused(deps.storage, name, Some(denom)));
+
+    ensure!(
+        !paused,
+        ContractError::OperationPaused {
+            operation: name.to_owned()
+        }
+    );
+
+    Ok(())
+}
+
+/// Splits a platform fee between the fee recipients by weight, any rounding dust goes to the
+/// first recipient.
+fn platform_fee_msgs(deps: Deps, fee: Uint128, fee_denom: &str) -> Vec<BankMsg> {
+    let fee_recipients = state::fee_recipients(deps.storage);
+
+    let total_weight = fee_recipients
+        .iter()
+        .map(|fee_recipient| u64::from(fee_recipient.weight))
+        .sum::<u64>();
+
+    let mut shares = fee_recipients
+        .into_iter()
+        .map(|fee_recipient| {
+            let share = fee.multiply_ratio(fee_recipient.weight, total_weight);
+
+            (fee_recipient.destination, share)
+        })
+        .collect::<Vec<_>>();
+
+    let dust = fee - shares.iter().map(|(_, share)| *share).sum::<Uint128>();
+
+    shares[0].1 += dust;
+
+    shares
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(destination, amount)| {
+            let amount = coins(amount.u128(), fee_denom);
+
+            match destination {
+                FeeDestination::Address { address } => BankMsg::Send {
+                    to_address: address,
+                    amount,
+                },
+                FeeDestination::Burn {} => BankMsg::Burn { amount },
+            }
+        })
+        .collect()
+}
+
+/// Gives the shitcoin creator their share of a presale fee and splits the rest between the
+/// platform fee recipients, any rounding dust goes to the platform.
+fn presale_fee_msgs(deps: Deps, denom: &str, fee: Uint128, fee_denom: &str) -> Vec<BankMsg> {
+    let creator =
+        state::shitcoin_creator(deps.storage, denom).expect("shitcoin creator must be set");
+
+    let creator_fee_share = state::creator_fee_share(deps.storage);
+
+    let creator_fee = fee.multiply_ratio(creator_fee_share, HUNDRED_PERCENT_BPS);
+
+    let platform_fee = fee - creator_fee;
+
+    let mut fee_msgs = vec![];
+
+    if !creator_fee.is_zero() {
+        fee_msgs.push(BankMsg::Send {
+            to_address: creator,
+            amount: coins(creator_fee.u128(), fee_denom),
+        });
+    }
+
+    fee_msgs.extend(platform_fee_msgs(deps, platform_fee, fee_denom));
+
+    fee_msgs
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_shitcoin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ticker: String,
+    name: String,
+    supply: Uint128,
+    soft_cap: Option<Uint128>,
+    hard_cap: Option<Uint128>,
+    presale_length: Option<u64>,
+    allocation: Option<Allocation>,
+    lp_policy: Option<LpPolicy>,
+    dex: Option<Dex>,
+    presale_denom: Option<String>,
+    allowlist: Option<Allowlist>,
+    min_contribution: Option<Uint128>,
+    max_contribution: Option<Uint128>,
+) -> Result<Response> {
+    ensure_not_paused(deps.as_ref(), Operation::CreateShitcoin {}, None)?;
+
+    ensure!(supply.u128() > 0, ContractError::ZeroSupply);
+
+    validate_ticker(&ticker)?;
+    validate_name(&name)?;
+    validate_presale_caps(soft_cap, hard_cap)?;
+
+    let presale_length = match presale_length {
+        Some(presale_length) => {
+            validate_presale_length_in_bounds(
+                presale_length,
+                state::min_presale_length(deps.storage),
+                state::max_presale_length(deps.storage),
+            )?;
+
+            presale_length
+        }
+        None => state::presale_length(deps.storage),
+    };
+
+    if let Some(allowlist) = &allowlist {
+        validate_allowlist(allowlist, presale_length)?;
+    }
+
+    let min_contribution = min_contribution.or_else(|| state::min_contribution(deps.storage));
+
+    let max_contribution = max_contribution.or_else(|| state::max_contribution(deps.storage));
+
+    validate_contribution_limits(min_contribution, max_contribution)?;
+
+    let allocation = match allocation {
+        Some(allocation) => {
+            validate_allocation(deps.as_ref(), &allocation)?;
+
+            allocation
+        }
+        None => default_allocation(deps.as_ref()),
+    };
+
+    let lp_policy = lp_policy.unwrap_or(LpPolicy::Lock {});
+
+    validate_lp_policy(&lp_policy)?;
+
+    let dex = dex.unwrap_or_else(|| state::dex(deps.storage));
+
+    let pool_factory = enabled_dex_factory(deps.as_ref(), dex)?;
+
+    let presale_denoms = state::presale_denoms(deps.storage);
+
+    let presale_denom = match presale_denom {
+        Some(presale_denom) => {
+            ensure!(
+                presale_denoms.contains(&presale_denom),
+                ContractError::PresaleDenomNotAccepted {
+                    denom: presale_denom
+                }
+            );
+
+            presale_denom
+        }
+        None => presale_denoms[0].clone(),
+    };
+
+    let subdenom = ticker.to_lowercase();
+
+    if state::ticker_denom(deps.storage, &subdenom).is_some() {
+        return Err(ContractError::DuplicateTicker { ticker });
+    }
+
+    let create_fee_denom = state::create_fee_denom(deps.storage);
+
+    let fee_payment = cw_utils::must_pay(&info, &create_fee_denom).map_err(|_| {
+        ContractError::MissingCreateFee {
+            denom: create_fee_denom.clone(),
+        }
+    })?;
+
+    let create_fee_amount = state::create_fee(deps.storage);
+
+    if fee_payment < create_fee_amount {
+        return Err(ContractError::InsufficientCreateFee {
+            amount: create_fee_amount,
+            denom: create_fee_denom,
+        });
+    }
+
+    let denom = denom(&env, &subdenom);
+
+    let creator = info.sender.into_string();
+
+    let shitcoin_index = state::shitcoin_count(deps.storage);
+
+    let shitcoin_count = shitcoin_index + 1;
+
+    let presale_end = env.block.time.seconds() + presale_length;
+
+    let total_supply = supply * Uint128::new(10u128.pow(6));
+
+    state::set_shitcoin_count(deps.storage, shitcoin_count);
+    state::set_shitcoin_denom(deps.storage, shitcoin_index, &denom);
+    state::set_shitcoin_creator(deps.storage, &denom, &creator);
+    state::add_creator_shitcoin(deps.storage, &creator, &denom);
+    state::set_shitcoin_ticker(deps.storage, &denom, &ticker);
+    state::set_ticker_denom(deps.storage, &subdenom, &denom);
+    state::set_shitcoin_name(deps.storage, &denom, &name);
+    state::set_shitcoin_supply(deps.storage, &denom, total_supply);
+    state::set_shitcoin_allocation(deps.storage, &denom, &allocation);
+    state::set_shitcoin_lp_policy(deps.storage, &denom, &lp_policy);
+    state::set_shitcoin_dex(deps.storage, &denom, dex);
+    state::set_shitcoin_pool_factory(deps.storage, &denom, &pool_factory);
+    state::set_shitcoin_presale_denom(deps.storage, &denom, &presale_denom);
+    state::set_vesting_cliff(
+        deps.storage,
+        &denom,
+        state::creator_vesting_cliff(deps.storage),
+    );
+    state::set_vesting_duration(
+        deps.storage,
+        &denom,
+        state::creator_vesting_duration(deps.storage),
+    );
+    state::set_shitcoin_presale_length(deps.storage, &denom, presale_length);
+    state::set_presale_end(deps.storage, &denom, presale_end);
+    state::set_presale_raise(deps.storage, &denom, Uint128::zero());
+
+    if let Some(allowlist) = allowlist {
+        let allowlist_end = env.block.time.seconds() + allowlist.length;
+
+        state::set_presale_allowlist_root(deps.storage, &denom, &allowlist.root);
+        state::set_presale_allowlist_end(deps.storage, &denom, allowlist_end);
+    }
+
+    if let Some(soft_cap) = soft_cap {
+        state::set_presale_soft_cap(deps.storage, &denom, soft_cap);
+    }
+
+    if let Some(hard_cap) = hard_cap {
+        state::set_presale_hard_cap(deps.storage, &denom, hard_cap);
+    }
+
+    if let Some(min_contribution) = min_contribution {
+        state::set_presale_min_contribution(deps.storage, &denom, min_contribution);
+    }
+
+    if let Some(max_contribution) = max_contribution {
+        state::set_presale_max_contribution(deps.storage, &denom, max_contribution);
+    }
+
+    let create_msg = NeutronMsg::submit_create_denom(&subdenom);
+
+    let metadata_msg = NeutronMsg::SetDenomMetadata {
+        description: "shitcoin".to_owned(),
+        denom_units: vec![
+            DenomUnit {
+                denom: denom.clone(),
+                exponent: 0,
+                aliases: vec![],
+            },
+            DenomUnit {
+                denom: ticker.clone(),
+                exponent: 6,
+                aliases: vec![],
+            },
+        ],
+        base: denom.clone(),
+        display: ticker.clone(),
+        name,
+        symbol: ticker.clone(),
+        uri: String::new(),
+        uri_hash: String::new(),
+    };
+
+    let mint_msg = NeutronMsg::submit_mint_tokens(&denom, total_supply, env.contract.address);
+
+    let mut response = Response::default().add_messages([create_msg, metadata_msg, mint_msg]);
+
+    let adapter = dex::adapter(dex);
+
+    if !adapter.creates_pool_at_launch() {
+        let create_pool_msg =
+            adapter.create_pool_msg(&pool_factory, [&denom, &presale_denom], None)?;
+
+        // the pair and lp token addresses are only known once the factory has created the pool
+        state::set_pending_pair(deps.storage, &denom);
+
+        response =
+            response.add_submessage(SubMsg::reply_always(create_pool_msg, CREATE_PAIR_REPLY_ID));
+    }
+
+    // the creator pays the create fee, so it all goes to the platform
+    let create_fee_msgs = platform_fee_msgs(deps.as_ref(), fee_payment, &create_fee_denom);
+
+    let event = event("shitcoin-created", &denom, None);
+
+    Ok(response.add_messages(create_fee_msgs).add_event(event))
+}
+
+pub fn enter_presale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    referrer: Option<String>,
+    allowlist_proof: Option<Vec<HexBinary>>,
+) -> Result<Response> {
+    ensure_not_paused(deps.as_ref(), Operation::EnterPresale {}, Some(&denom))?;
+
+    if let Moderation::Delisted { reason } = state::shitcoin_moderation(deps.storage, &denom) {
+        return Err(ContractError::Delisted { reason });
+    }
+
+    let presale_end =
+        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) == 0 {
+        return Err(ContractError::PresaleOver);
+    }
+
+    // proofs are only checked during the allowlist phase, anyone can enter afterwards
+    if let Some(allowlist_end) = state::presale_allowlist_end(deps.storage, &denom) {
+        if env.block.time.seconds() < allowlist_end {
+            let root = state::presale_allowlist_root(deps.storage, &denom)
+                .expect("set with the allowlist end");
+
+            let proof =
+                allowlist_proof.ok_or(ContractError::AllowlistProofRequired { allowlist_end })?;
+
+            ensure!(
+                merkle::verify(&root, info.sender.as_str(), &proof),
+                ContractError::NotOnAllowlist
+            );
+        }
+    }
+
+    if let Some(referrer) = &referrer {
+        deps.api.addr_validate(referrer)?;
+
+        ensure!(
+            referrer != info.sender.as_str(),
+            ContractError::SelfReferral
+        );
+
+        let creator = state::shitcoin_creator(deps.storage, &denom)
+            .ok_or_else(|| StdError::not_found(&denom))?;
+
+        ensure!(*referrer != creator, ContractError::CreatorReferral);
+    }
+
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let amount = cw_utils::must_pay(&info, &presale_denom).map_err(|_| {
+        ContractError::MissingPresaleFunds {
+            denom: presale_denom.clone(),
+        }
+    })?;
+
+    let fee_rate = state::presale_fee_rate(deps.storage);
+
+    let mut fee = (amount * Uint128::new(fee_rate as _)) / HUNDRED_PERCENT_BPS;
+
+    if fee.is_zero() {
+        return Err(ContractError::BagTooSmol);
+    }
+
+    let submission = amount - fee;
+
+    let current_raise =
+        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if let Some(hard_cap) = state::presale_hard_cap(deps.storage, &denom) {
+        if current_raise >= hard_cap {
+            return Err(ContractError::PresaleFull);
+        }
+
+        let remaining = hard_cap - current_raise;
+
+        if submission > remaining {
+            return Err(ContractError::HardCapExceeded { remaining });
+        }
+    }
+
+    let current_submission =
+        state::presale_submission(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+
+    if let Some(min) = state::presale_min_contribution(deps.storage, &denom) {
+        ensure!(
+            current_submission + submission >= min,
+            ContractError::ContributionBelowMinimum { min }
+        );
+    }
+
+    if let Some(max) = state::presale_max_contribution(deps.storage, &denom) {
+        let remaining = max.saturating_sub(current_submission);
+
+        ensure!(
+            submission <= remaining,
+            ContractError::ContributionAboveMaximum { remaining }
+        );
+    }
+
+    state::set_presale_raise(deps.storage, &denom, current_raise + submission);
+    state::set_presale_submission(
+        deps.storage,
+        &denom,
+        info.sender.as_str(),
+        current_submission + submission,
+    );
+    state::add_degen_presale(deps.storage, info.sender.as_str(), &denom);
+    state::add_presale_participant(deps.storage, &denom, info.sender.as_str());
+
+    let mut event = event("presale-entered", &denom, Some(info.sender.as_str()));
+
+    let mut fee_msgs = vec![];
+
+    // the referrer's share comes off the top, the rest is split as usual
+    if let Some(referrer) = referrer {
+        let referral_fee_share = state::referral_fee_share(deps.storage);
+
+        let referral_fee = fee.multiply_ratio(referral_fee_share, HUNDRED_PERCENT_BPS);
+
+        let referrals = state::referrer_referrals(deps.storage, &referrer).unwrap_or_default();
+
+        state::set_referrer_referrals(deps.storage, &referrer, referrals + 1);
+
+        if !referral_fee.is_zero() {
+            state::add_referrer_earnings(deps.storage, &referrer, &presale_denom, referral_fee);
+
+            fee_msgs.push(BankMsg::Send {
+                to_address: referrer.clone(),
+                amount: coins(referral_fee.u128(), &presale_denom),
+            });
+        }
+
+        fee -= referral_fee;
+
+        event = event
+            .add_attribute("referrer", referrer)
+            .add_attribute("referral_fee", referral_fee);
+    }
+
+    fee_msgs.extend(presale_fee_msgs(deps.as_ref(), &denom, fee, &presale_denom));
+
+    Ok(Response::default().add_messages(fee_msgs).add_event(event))
+}
+
+pub fn withdraw_presale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response> {
+    let presale_end =
+        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) == 0 {
+        return Err(ContractError::WithdrawalsClosed);
+    }
+
+    let current_submission =
+        state::presale_submission(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+
+    if amount.is_zero() || amount > current_submission {
+        return Err(ContractError::InvalidWithdrawAmount {
+            available: current_submission,
+        });
+    }
+
+    // degens can always withdraw everything, but cannot stay in below the minimum
+    if let Some(min) = state::presale_min_contribution(deps.storage, &denom) {
+        let remaining = current_submission - amount;
+
+        ensure!(
+            remaining.is_zero() || remaining >= min,
+            ContractError::ContributionBelowMinimum { min }
+        );
+    }
+
+    let current_raise =
+        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    state::set_presale_raise(deps.storage, &denom, current_raise - amount);
+    state::set_presale_submission(
+        deps.storage,
+        &denom,
+        info.sender.as_str(),
+        current_submission - amount,
+    );
+
+    if current_submission == amount {
+        state::remove_degen_presale(deps.storage, info.sender.as_str(), &denom);
+        state::remove_presale_participant(deps.storage, &denom, info.sender.as_str());
+    }
+
+    let penalty_rate = state::presale_withdraw_penalty_rate(deps.storage);
+
+    let penalty = (amount * Uint128::new(penalty_rate as _)) / HUNDRED_PERCENT_BPS;
+
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let send_withdrawal = BankMsg::Send {
+        to_address: info.sender.clone().into_string(),
+        amount: coins((amount - penalty).u128(), &presale_denom),
+    };
+
+    let fee_msgs = presale_fee_msgs(deps.as_ref(), &denom, penalty, &presale_denom);
+
+    let event = event("presale-withdrawn", &denom, Some(info.sender.as_str()))
+        .add_attribute("amount", amount)
+        .add_attribute("penalty", penalty);
+
+    Ok(Response::default()
+        .add_message(send_withdrawal)
+        .add_messages(fee_msgs)
+        .add_event(event))
+}
+
+pub fn extend_presale(deps: DepsMut, env: Env, denom: String) -> Result<Response> {
+    ensure_not_paused(deps.as_ref(), Operation::ExtendPresale {}, Some(&denom))?;
+
+    let presale_end =
+        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
+        return Err(ContractError::PresaleNotOver);
+    }
+
+    let presale_raise =
+        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if !presale_raise.is_zero() {
+        return Err(ContractError::PresaleRaised);
+    }
+
+    let presale_length = state::shitcoin_presale_length(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    state::set_presale_end(
+        deps.storage,
+        &denom,
+        env.block.time.seconds() + presale_length,
+    );
+
+    let event = event("presale-extended", &denom, None);
+
+    Ok(Response::default().add_event(event))
+}
+
+pub fn launch_shitcoin(deps: DepsMut, env: Env, denom: String) -> Result<Response> {
+    ensure_not_paused(deps.as_ref(), Operation::LaunchShitcoin {}, Some(&denom))?;
+
+    let presale_end =
+        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
+        return Err(ContractError::PresaleNotOver);
+    }
+
+    let shitcoin_launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
+
+    if shitcoin_launched {
+        return Err(ContractError::AlreadyLaunched);
+    }
+
+    let shitcoin_supply =
+        state::shitcoin_supply(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let presale_raise =
+        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_failed(deps.as_ref(), &denom, presale_raise) {
+        return Err(ContractError::SoftCapNotReached);
+    }
+
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    // once refunds have opened up the raise is no longer whole, even if the pool is emptied
+    if presale_aborted(deps.as_ref(), &denom)? {
+        return Err(ContractError::PoolPreseeded);
+    }
+
+    let allocation = state::shitcoin_allocation(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let lp_shitcoin_amount = allocation_amounts(shitcoin_supply, &allocation).lp;
+
+    state::set_shitcoin_launched(deps.storage, &denom, true);
+
+    // the creator allocation stays in the contract and vests from launch
+    state::set_vesting_start(deps.storage, &denom, env.block.time.seconds());
+
+    let lp_policy = state::shitcoin_lp_policy(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    if let LpPolicy::TimeLock { duration } = lp_policy {
+        state::set_shitcoin_lp_unlock(deps.storage, &denom, env.block.time.seconds() + duration);
+    }
+
+    // the lp share minted is only known once the liquidity has been provided
+    state::set_pending_launch(deps.storage, &denom);
+
+    state::set_shitcoin_admin_renounced(deps.storage, &denom, true);
+
+    let mut response = Response::default();
+
+    match shitcoin_pair(deps.as_ref(), &denom)? {
+        Some((pair, lp_token)) => {
+            state::set_shitcoin_pair(deps.storage, &denom, &pair);
+
+            state::set_shitcoin_lp_token(deps.storage, &denom, &lp_token);
+
+            response = response.add_submessage(seed_pool_msg(deps.as_ref(), &denom, &pair)?);
+        }
+        None => {
+            // the pool starts out at the price the garden is about to seed it at
+            let price = Decimal::checked_from_ratio(lp_shitcoin_amount, presale_raise)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+            // the factory the shitcoin was created with, even if its dex was disabled since
+            let pool_factory = state::shitcoin_pool_factory(deps.storage, &denom)
+                .ok_or_else(|| StdError::not_found(&denom))?;
+
+            let create_pool_msg = shitcoin_dex(deps.as_ref(), &denom)?.create_pool_msg(
+                &pool_factory,
+                [&denom, &presale_denom],
+                Some(price),
+            )?;
+
+            // the pool is seeded from the creation reply once its address is known
+            state::set_pending_pair(deps.storage, &denom);
+
+            response = response
+                .add_submessage(SubMsg::reply_always(create_pool_msg, CREATE_PAIR_REPLY_ID));
+        }
+    }
+
+    // the burn address can never mint again, making the supply provably fixed
+    let burn_address = state::denom_admin_burn_address(deps.storage);
+
+    let renounce_admin_msg = NeutronMsg::submit_change_admin(&denom, burn_address);
+
+    Ok(response.add_message(renounce_admin_msg))
+}
+
+fn handle_create_pair_reply(deps: DepsMut, msg: Reply) -> Result<Response> {
+    let denom = state::pending_pair(deps.storage).ok_or(ContractError::NoPendingPair)?;
+
+    state::clear_pending_pair(deps.storage);
+
+    // failing here reverts the whole creation or launch, including the create fee
+    let response = msg
+        .result
+        .into_result()
+        .map_err(|reason| ContractError::PairCreationFailed { reason })?;
+
+    let pool = shitcoin_dex(deps.as_ref(), &denom)?
+        .created_pool(&response.events)
+        .ok_or(ContractError::MissingPairAddress)?;
+
+    state::set_shitcoin_pair(deps.storage, &denom, &pool.pair);
+
+    state::set_shitcoin_lp_token(deps.storage, &denom, &pool.lp_token);
+
+    // pools created at launch are seeded as soon as they exist
+    if state::pending_launch(deps.storage).as_deref() == Some(denom.as_str()) {
+        let seed_pool_msg = seed_pool_msg(deps.as_ref(), &denom, &pool.pair)?;
+
+        return Ok(Response::default().add_submessage(seed_pool_msg));
+    }
+
+    Ok(Response::default())
+}
+
+fn handle_provide_liquidity_reply(deps: DepsMut, msg: Reply) -> Result<Response> {
+    let denom = state::pending_launch(deps.storage).ok_or(ContractError::NoPendingLaunch)?;
+
+    state::clear_pending_launch(deps.storage);
+
+    let response = msg.result.into_result().map_err(StdError::generic_err)?;
+
+    let lp_amount = shitcoin_dex(deps.as_ref(), &denom)?
+        .provided_share(&response.events)
+        .ok_or(ContractError::MissingLpShare)?;
+
+    state::set_shitcoin_lp_amount(deps.storage, &denom, lp_amount);
+
+    let lp_token = state::shitcoin_lp_token(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let lp_policy = state::shitcoin_lp_policy(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let mut response = Response::default();
+
+    if let LpPolicy::Burn {} = lp_policy {
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: lp_token.clone(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount: lp_amount })?,
+            funds: vec![],
+        });
+    }
+
+    let event = event("shitcoin-launched", &denom, None)
+        .add_attribute("lp_token", lp_token)
+        .add_attribute("lp_amount", lp_amount)
+        .add_attribute("lp_policy", lp_policy_name(&lp_policy));
+
+    Ok(response.add_event(event))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response> {
+    match msg.id {
+        PROVIDE_LIQUIDITY_REPLY_ID => handle_provide_liquidity_reply(deps, msg),
+        CREATE_PAIR_REPLY_ID => handle_create_pair_reply(deps, msg),
+        id => Err(StdError::generic_err(format!("unknown reply id: {id}")).into()),
+    }
+}
+
+pub fn claim_shitcoin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response> {
+    ensure_not_paused(deps.as_ref(), Operation::ClaimShitcoin {}, Some(&denom))?;
+
+    let presale_end =
+        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
+        return Err(ContractError::PresaleNotOver);
+    }
+
+    let shitcoin_launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
+
+    if !shitcoin_launched {
+        return Err(ContractError::NotLaunched);
+    }
+
+    let presale_claimed =
+        state::presale_claimed(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+
+    if presale_claimed {
+        return Err(ContractError::AlreadyClaimed);
+    }
+
+    let presale_submission =
+        state::presale_submission(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+
+    if presale_submission.is_zero() {
+        return Err(ContractError::NotInPresale);
+    }
+
+    let claimable = presale_claim_amount(deps.as_ref(), &denom, presale_submission)?;
+
+    state::set_presale_claimed(deps.storage, &denom, info.sender.as_str(), true);
+
+    let send_shitcoins = BankMsg::Send {
+        to_address: info.sender.clone().into_string(),
+        amount: coins(claimable.u128(), &denom),
+    };
+
+    let event = event("shitcoin-claimed", &denom, Some(info.sender.as_str()));
+
+    Ok(Response::default()
+        .add_message(send_shitcoins)
+        .add_event(event))
+}
+
+pub fn refund_presale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response> {
+    let presale_end =
+        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
+        return Err(ContractError::PresaleNotOver);
+    }
+
+    let presale_raise =
+        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let presale_failed = presale_failed(deps.as_ref(), &denom, presale_raise);
+
+    let shitcoin_launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
+
+    // a pre-seeded pool blocks the launch, so degens get their funds back instead
+    let presale_aborted =
+        !presale_failed && !shitcoin_launched && presale_aborted(deps.as_ref(), &denom)?;
+
+    if !presale_failed && !presale_aborted {
+        return Err(ContractError::PresaleNotFailed);
+    }
+
+    if presale_aborted {
+        state::set_presale_aborted(deps.storage, &denom, true);
+    }
+
+    let presale_refunded =
+        state::presale_refunded(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+
+    if presale_refunded {
+        return Err(ContractError::AlreadyRefunded);
+    }
+
+    let presale_submission =
+        state::presale_submission(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
 
-pub const HUNDRED_PERCENT_BPS: Uint128 = Uint128::new(10_000);
-pub const ONE_PERCENT_BPS: u32 = 100;
+    if presale_submission.is_zero() {
+        return Err(ContractError::NotInPresale);
+    }
 
-#[entry_point]
-pub fn instantiate(
-    deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    msg: InstantiateMsg,
-) -> Result<Response> {
-    ensure!(
-        msg.presale_length > 0,
-        "presale length has to be greater than zero"
-    );
-    ensure!(
-        msg.presale_fee_rate < ONE_PERCENT_BPS,
-        "presale fee rate has to be less than {ONE_PERCENT_BPS} bps"
-    );
+    state::set_presale_refunded(deps.storage, &denom, info.sender.as_str(), true);
 
-    deps.api.addr_validate(&msg.fee_recipient)?;
-    deps.api.addr_validate(&msg.pool_factory_address)?;
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
 
-    state::set_pool_factory_address(deps.storage, &msg.pool_factory_address);
-    state::set_platform_fee_recipient(deps.storage, &msg.fee_recipient);
-    state::set_create_fee_denom(deps.storage, &msg.create_fee_denom);
-    state::set_create_fee(deps.storage, msg.create_fee);
-    state::set_presale_denom(deps.storage, &msg.presale_denom);
-    state::set_presale_length(deps.storage, msg.presale_length);
-    state::set_presale_fee_rate(deps.storage, msg.presale_fee_rate);
+    let send_refund = BankMsg::Send {
+        to_address: info.sender.clone().into_string(),
+        amount: coins(presale_submission.u128(), presale_denom),
+    };
 
-    Ok(Response::default())
-}
+    let event = event("presale-refunded", &denom, Some(info.sender.as_str()));
 
-fn denom(env: &Env, subdenom: &str) -> String {
-    format!("factory/{}/{subdenom}", env.contract.address)
+    Ok(Response::default()
+        .add_message(send_refund)
+        .add_event(event))
 }
 
-fn event(kind: &str, denom: &str, degen: Option<&str>) -> Event {
-    let event = Event::new("shitcoin-garden")
-        .add_attribute("kind", kind)
-        .add_attribute("denom", denom);
+pub fn claim_vested(deps: DepsMut, env: Env, info: MessageInfo, denom: String) -> Result<Response> {
+    let vesting = vesting_status(deps.as_ref(), &env, denom.clone())?;
 
-    let Some(degen) = degen else {
-        return event;
+    if vesting.creator.as_str() != info.sender.as_str() {
+        return Err(ContractError::NotCreator);
+    }
+
+    if vesting.start.is_none() {
+        return Err(ContractError::NotLaunched);
+    }
+
+    let claimable = vesting.released - vesting.claimed;
+
+    if claimable.is_zero() {
+        return Err(ContractError::NothingVested);
+    }
+
+    state::set_vesting_claimed(deps.storage, &denom, vesting.released);
+
+    let send_vested = BankMsg::Send {
+        to_address: vesting.creator.clone(),
+        amount: coins(claimable.u128(), &denom),
     };
 
-    event.add_attribute("degen", degen)
+    let event =
+        event("vesting-claimed", &denom, Some(&vesting.creator)).add_attribute("amount", claimable);
+
+    Ok(Response::default()
+        .add_message(send_vested)
+        .add_event(event))
 }
 
-pub fn create_shitcoin(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    ticker: String,
-    name: String,
-    supply: Uint128,
-) -> Result<Response> {
-    ensure!(supply.u128() > 0, "supply must be greater than zero c'mon");
+pub fn claim_lp(deps: DepsMut, env: Env, info: MessageInfo, denom: String) -> Result<Response> {
+    let creator =
+        state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
 
-    let create_fee_denom = state::create_fee_denom(deps.storage);
+    if creator.as_str() != info.sender.as_str() {
+        return Err(ContractError::NotCreator);
+    }
 
-    let fee_payment = cw_utils::must_pay(&info, &create_fee_denom)
-        .map_err(|_| anyhow!("you must also send {create_fee_denom} to create a shitcoin"))?;
+    let lp_policy = state::shitcoin_lp_policy(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
 
-    let create_fee_amount = state::create_fee(deps.storage);
+    if !matches!(lp_policy, LpPolicy::TimeLock { .. }) {
+        return Err(ContractError::LpNotTimeLocked);
+    }
 
-    if fee_payment < create_fee_amount {
-        bail!("you must pay {create_fee_amount} {create_fee_denom} to create a shitcoin");
+    let (Some(lp_token), Some(lp_amount), Some(unlocks_at)) = (
+        state::shitcoin_lp_token(deps.storage, &denom),
+        state::shitcoin_lp_amount(deps.storage, &denom),
+        state::shitcoin_lp_unlock(deps.storage, &denom),
+    ) else {
+        return Err(ContractError::NotLaunched);
+    };
+
+    if env.block.time.seconds() < unlocks_at {
+        return Err(ContractError::LpStillLocked { unlocks_at });
     }
 
-    let subdenom = ticker.to_lowercase();
+    let lp_claimed = state::shitcoin_lp_claimed(deps.storage, &denom).unwrap_or_default();
 
-    let denom = denom(&env, &subdenom);
+    if lp_claimed {
+        return Err(ContractError::LpAlreadyClaimed);
+    }
 
-    let creator = info.sender.into_string();
+    state::set_shitcoin_lp_claimed(deps.storage, &denom, true);
 
-    let shitcoin_index = state::shitcoin_count(deps.storage);
+    let transfer_lp = WasmMsg::Execute {
+        contract_addr: lp_token,
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: creator.clone(),
+            amount: lp_amount,
+        })?,
+        funds: vec![],
+    };
 
-    let shitcoin_count = shitcoin_index + 1;
+    let event = event("lp-claimed", &denom, Some(&creator)).add_attribute("amount", lp_amount);
 
-    let presale_length = state::presale_length(deps.storage);
+    Ok(Response::default()
+        .add_message(transfer_lp)
+        .add_event(event))
+}
 
-    let presale_end = env.block.time.seconds() + presale_length;
+pub fn set_shitcoin_url(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    url: String,
+) -> Result<Response> {
+    ensure_not_paused(deps.as_ref(), Operation::SetUrl {}, Some(&denom))?;
 
-    let total_supply = supply * Uint128::new(10u128.pow(6));
+    let creator =
+        state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
 
-    state::set_shitcoin_count(deps.storage, shitcoin_count);
-    state::set_shitcoin_denom(deps.storage, shitcoin_index, &denom);
-    state::set_shitcoin_creator(deps.storage, &denom, &creator);
-    state::set_shitcoin_ticker(deps.storage, &denom, &ticker);
-    state::set_shitcoin_name(deps.storage, &denom, &name);
-    state::set_shitcoin_supply(deps.storage, &denom, total_supply);
-    state::set_presale_end(deps.storage, &denom, presale_end);
-    state::set_presale_raise(deps.storage, &denom, Uint128::zero());
+    if creator.as_str() != info.sender.as_str() {
+        return Err(ContractError::NotCreator);
+    }
 
-    let create_msg = NeutronMsg::submit_create_denom(subdenom.to_lowercase());
+    let admin_renounced = state::shitcoin_admin_renounced(deps.storage, &denom).unwrap_or_default();
+
+    if admin_renounced {
+        return Err(ContractError::MetadataLocked);
+    }
 
-    let pair_denom = state::presale_denom(deps.storage);
+    state::set_shitcoin_url(deps.storage, &denom, &url);
 
-    let pool_factory = state::pool_factory_address(deps.storage);
+    let ticker =
+        state::shitcoin_ticker(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
 
-    let pfee_recipient = state::platform_fee_recipient(deps.storage);
+    let name =
+        state::shitcoin_name(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
 
     let metadata_msg = NeutronMsg::SetDenomMetadata {
         description: "shitcoin".to_owned(),
@@ -140,305 +1675,295 @@ pub fn create_shitcoin(
         display: ticker.clone(),
         name,
         symbol: ticker.clone(),
-        uri: String::new(),
+        uri: url,
         uri_hash: String::new(),
     };
 
-    let mint_msg = NeutronMsg::submit_mint_tokens(&denom, total_supply, env.contract.address);
-
-    let create_pair_msg = PoolFactoryMsg::CreatePair {
-        pair_type: PairType::Xyk {},
-        asset_infos: vec![AssetInfo::native(&denom), AssetInfo::native(pair_denom)],
-        init_params: None,
-    };
-
-    let create_pool_msg = WasmMsg::Execute {
-        contract_addr: pool_factory,
-        msg: to_json_binary(&create_pair_msg)?,
-        funds: vec![],
-    };
-
-    let send_create_fee = BankMsg::Send {
-        to_address: pfee_recipient,
-        amount: coins(fee_payment.u128(), create_fee_denom),
-    };
-
-    let event = event("shitcoin-created", &denom, None);
+    let event = event("shitcoin-url-set", &denom, None);
 
     Ok(Response::default()
-        .add_messages([create_msg, metadata_msg, mint_msg])
-        .add_message(create_pool_msg)
-        .add_message(send_create_fee)
+        .add_message(metadata_msg)
         .add_event(event))
 }
 
-pub fn enter_presale(
+#[allow(clippy::too_many_arguments)]
+pub fn update_config(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    denom: String,
+    dex_factories: Option<Vec<DexFactory>>,
+    dex: Option<Dex>,
+    fee_recipients: Option<Vec<FeeRecipient>>,
+    creator_fee_share: Option<u32>,
+    referral_fee_share: Option<u32>,
+    create_fee_denom: Option<String>,
+    create_fee: Option<Uint128>,
+    presale_denoms: Option<Vec<String>>,
+    presale_length: Option<u64>,
+    min_presale_length: Option<u64>,
+    max_presale_length: Option<u64>,
+    presale_fee_rate: Option<u32>,
+    presale_withdraw_penalty_rate: Option<u32>,
+    min_contribution: Option<Uint128>,
+    max_contribution: Option<Uint128>,
+    min_lp_allocation: Option<u32>,
+    max_creator_allocation: Option<u32>,
+    creator_vesting_cliff: Option<u64>,
+    creator_vesting_duration: Option<u64>,
 ) -> Result<Response> {
-    let presale_end =
-        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    ensure_admin(deps.as_ref(), &info)?;
 
-    if presale_end.saturating_sub(env.block.time.seconds()) == 0 {
-        bail!("you're too late to enter this shitcoin's presale");
+    if let Some(dex_factories) = dex_factories {
+        validate_dex_factories(deps.as_ref(), &dex_factories)?;
+        state::set_dex_factories(deps.storage, &dex_factories);
     }
 
-    let presale_denom = state::presale_denom(deps.storage);
+    if let Some(dex) = dex {
+        state::set_dex(deps.storage, dex);
+    }
 
-    let amount = cw_utils::must_pay(&info, &presale_denom)
-        .map_err(|_| anyhow!("you must send {presale_denom} to enter the presale"))?;
+    // the default dex and the enabled ones can be updated independently, so check them together
+    enabled_dex_factory(deps.as_ref(), state::dex(deps.storage))?;
 
-    let fee_rate = state::presale_fee_rate(deps.storage);
+    if let Some(fee_recipients) = fee_recipients {
+        validate_fee_recipients(deps.as_ref(), &fee_recipients)?;
+        state::set_fee_recipients(deps.storage, &fee_recipients);
+    }
 
-    let fee = (amount * Uint128::new(fee_rate as _)) / HUNDRED_PERCENT_BPS;
+    if let Some(creator_fee_share) = creator_fee_share {
+        validate_creator_fee_share(creator_fee_share)?;
+        state::set_creator_fee_share(deps.storage, creator_fee_share);
+    }
 
-    if fee.is_zero() {
-        bail!("bag too smol")
+    if let Some(referral_fee_share) = referral_fee_share {
+        validate_referral_fee_share(referral_fee_share)?;
+        state::set_referral_fee_share(deps.storage, referral_fee_share);
     }
 
-    let submission = amount - fee;
+    if let Some(create_fee_denom) = create_fee_denom {
+        state::set_create_fee_denom(deps.storage, &create_fee_denom);
+    }
 
-    let current_raise =
-        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    if let Some(create_fee) = create_fee {
+        state::set_create_fee(deps.storage, create_fee);
+    }
 
-    let current_submission =
-        state::presale_submission(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+    // shitcoins keep the denom they were created with, even once it is no longer accepted
+    if let Some(presale_denoms) = presale_denoms {
+        validate_presale_denoms(&presale_denoms)?;
+        state::set_presale_denoms(deps.storage, &presale_denoms);
+    }
 
-    state::set_presale_raise(deps.storage, &denom, current_raise + submission);
-    state::set_presale_submission(
-        deps.storage,
-        &denom,
-        info.sender.as_str(),
-        current_submission + submission,
-    );
+    if let Some(presale_length) = presale_length {
+        validate_presale_length(presale_length)?;
+        state::set_presale_length(deps.storage, presale_length);
+    }
 
-    let creator =
-        state::shitcoin_creator(deps.storage, &denom).expect("shitcoin creator must be set");
+    if let Some(min_presale_length) = min_presale_length {
+        state::set_min_presale_length(deps.storage, min_presale_length);
+    }
 
-    let pfee_recipient = state::platform_fee_recipient(deps.storage);
+    if let Some(max_presale_length) = max_presale_length {
+        state::set_max_presale_length(deps.storage, max_presale_length);
+    }
 
-    let creator_fee = fee.multiply_ratio(1u128, 2u128);
+    // the default length and its bounds can be updated independently, so check them together
+    let min_presale_length = state::min_presale_length(deps.storage);
+    let max_presale_length = state::max_presale_length(deps.storage);
 
-    let platform_fee = fee - creator_fee;
+    validate_presale_length_bounds(min_presale_length, max_presale_length)?;
+    validate_presale_length_in_bounds(
+        state::presale_length(deps.storage),
+        min_presale_length,
+        max_presale_length,
+    )?;
 
-    let send_cfee_msg = BankMsg::Send {
-        to_address: creator,
-        amount: coins(creator_fee.u128(), &presale_denom),
-    };
+    if let Some(presale_fee_rate) = presale_fee_rate {
+        validate_presale_fee_rate(presale_fee_rate)?;
+        state::set_presale_fee_rate(deps.storage, presale_fee_rate);
+    }
 
-    let send_pfee_msg = BankMsg::Send {
-        to_address: pfee_recipient,
-        amount: coins(platform_fee.u128(), &presale_denom),
-    };
+    if let Some(presale_withdraw_penalty_rate) = presale_withdraw_penalty_rate {
+        validate_withdraw_penalty_rate(presale_withdraw_penalty_rate)?;
+        state::set_presale_withdraw_penalty_rate(deps.storage, presale_withdraw_penalty_rate);
+    }
 
-    let event = event("presale-entered", &denom, Some(info.sender.as_str()));
+    // existing shitcoins keep the limits they were created with
+    match min_contribution {
+        Some(min_contribution) if min_contribution.is_zero() => {
+            state::clear_min_contribution(deps.storage)
+        }
+        Some(min_contribution) => state::set_min_contribution(deps.storage, min_contribution),
+        None => {}
+    }
 
-    Ok(Response::default()
-        .add_messages([send_cfee_msg, send_pfee_msg])
-        .add_event(event))
-}
+    match max_contribution {
+        Some(max_contribution) if max_contribution.is_zero() => {
+            state::clear_max_contribution(deps.storage)
+        }
+        Some(max_contribution) => state::set_max_contribution(deps.storage, max_contribution),
+        None => {}
+    }
 
-pub fn extend_presale(deps: DepsMut, env: Env, denom: String) -> Result<Response> {
-    let presale_end =
-        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    validate_contribution_limits(
+        state::min_contribution(deps.storage),
+        state::max_contribution(deps.storage),
+    )?;
 
-    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
-        bail!("patience young grasshopper the presale is not over");
+    if let Some(min_lp_allocation) = min_lp_allocation {
+        state::set_min_lp_allocation(deps.storage, min_lp_allocation);
     }
 
-    let presale_raise =
-        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    if let Some(max_creator_allocation) = max_creator_allocation {
+        state::set_max_creator_allocation(deps.storage, max_creator_allocation);
+    }
 
-    if !presale_raise.is_zero() {
-        bail!("shitcoin is primed and ready for launch");
+    validate_allocation_bounds(
+        state::min_lp_allocation(deps.storage),
+        state::max_creator_allocation(deps.storage),
+    )?;
+
+    if let Some(creator_vesting_cliff) = creator_vesting_cliff {
+        state::set_creator_vesting_cliff(deps.storage, creator_vesting_cliff);
     }
 
-    let presale_length = state::presale_length(deps.storage);
+    if let Some(creator_vesting_duration) = creator_vesting_duration {
+        state::set_creator_vesting_duration(deps.storage, creator_vesting_duration);
+    }
 
-    state::set_presale_end(
-        deps.storage,
-        &denom,
-        env.block.time.seconds() + presale_length,
-    );
+    validate_vesting_schedule(
+        state::creator_vesting_cliff(deps.storage),
+        state::creator_vesting_duration(deps.storage),
+    )?;
 
-    let event = event("presale-extended", &denom, None);
+    let event = config_event("config-updated");
 
     Ok(Response::default().add_event(event))
 }
 
-pub fn launch_shitcoin(deps: DepsMut, env: Env, denom: String) -> Result<Response> {
-    let presale_end =
-        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
-
-    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
-        bail!("patience young grasshopper the presale is not over");
-    }
-
-    let shitcoin_launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
-
-    if shitcoin_launched {
-        bail!("shitcoin already launched");
-    }
-
-    let shitcoin_supply =
-        state::shitcoin_supply(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+pub fn propose_admin(deps: DepsMut, info: MessageInfo, admin: String) -> Result<Response> {
+    ensure_admin(deps.as_ref(), &info)?;
 
-    let presale_raise =
-        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    let admin = deps.api.addr_validate(&admin)?;
 
-    let presale_denom = state::presale_denom(deps.storage);
+    state::set_pending_admin(deps.storage, admin.as_str());
 
-    let pool_factory = state::pool_factory_address(deps.storage);
+    let event = config_event("admin-proposed").add_attribute("pending_admin", admin);
 
-    let pair_info = query_pair_info(
-        &deps.querier,
-        pool_factory,
-        &[AssetInfo::native(&denom), AssetInfo::native(&presale_denom)],
-    )?;
+    Ok(Response::default().add_event(event))
+}
 
-    let lp_shitcoin_amount = shitcoin_supply.multiply_ratio(1u128, 2u128);
+pub fn accept_admin(deps: DepsMut, info: MessageInfo) -> Result<Response> {
+    let pending_admin = state::pending_admin(deps.storage);
 
-    state::set_shitcoin_launched(deps.storage, &denom, true);
+    ensure!(
+        pending_admin.as_deref() == Some(info.sender.as_str()),
+        ContractError::NotPendingAdmin
+    );
 
-    let provide_liquidity_msg = PairMsg::ProvideLiquidity {
-        assets: vec![
-            Asset {
-                info: AssetInfo::native(&denom),
-                amount: lp_shitcoin_amount,
-            },
-            Asset {
-                info: AssetInfo::native(&presale_denom),
-                amount: presale_raise,
-            },
-        ],
-        slippage_tolerance: None,
-        auto_stake: None,
-        receiver: None,
-    };
+    state::set_admin(deps.storage, info.sender.as_str());
+    state::clear_pending_admin(deps.storage);
 
-    let seed_pool_msg = WasmMsg::Execute {
-        contract_addr: pair_info.contract_addr.into_string(),
-        msg: to_json_binary(&provide_liquidity_msg)?,
-        funds: vec![
-            coin(lp_shitcoin_amount.u128(), &denom),
-            coin(presale_raise.u128(), presale_denom),
-        ],
-    };
+    let event = config_event("admin-accepted").add_attribute("admin", info.sender);
 
-    let event = event("shitcoin-launched", &denom, None);
+    Ok(Response::default().add_event(event))
+}
 
-    Ok(Response::default()
-        .add_message(seed_pool_msg)
-        .add_event(event))
+fn moderation_name(moderation: &Moderation) -> &'static str {
+    match moderation {
+        Moderation::Visible {} => "visible",
+        Moderation::Hidden {} => "hidden",
+        Moderation::Delisted { .. } => "delisted",
+    }
 }
 
-pub fn claim_shitcoin(
+pub fn moderate_shitcoin(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
     denom: String,
+    moderation: Moderation,
 ) -> Result<Response> {
-    let presale_end =
-        state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
-
-    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
-        bail!("patience young grasshopper the presale is not over");
-    }
+    ensure_admin(deps.as_ref(), &info)?;
 
-    let shitcoin_launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
+    state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
 
-    if !shitcoin_launched {
-        bail!("shitcoin needs to be launched before claiming");
-    }
+    let mut event = event("shitcoin-moderated", &denom, None)
+        .add_attribute("moderation", moderation_name(&moderation));
 
-    let presale_claimed =
-        state::presale_claimed(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+    if let Moderation::Delisted { reason } = &moderation {
+        ensure!(
+            !reason.is_empty() && reason.len() <= MAX_DELIST_REASON_LENGTH,
+            ContractError::InvalidDelistReason {
+                max: MAX_DELIST_REASON_LENGTH
+            }
+        );
 
-    if presale_claimed {
-        bail!("shitcoins already claimed");
+        event = event.add_attribute("reason", reason);
     }
 
-    let presale_raise =
-        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
-
-    let presale_submission =
-        state::presale_submission(deps.storage, &denom, info.sender.as_str()).unwrap_or_default();
+    state::set_shitcoin_moderation(deps.storage, &denom, &moderation);
 
-    if presale_submission.is_zero() {
-        bail!("ser you did not enter this shitcoin presale");
-    }
+    Ok(Response::default().add_event(event))
+}
 
-    let shitcoin_supply =
-        state::shitcoin_supply(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+pub fn set_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response> {
+    ensure_admin(deps.as_ref(), &info)?;
 
-    let total_claimable_amount = shitcoin_supply.multiply_ratio(1u128, 2u128);
+    let Some(guardian) = guardian else {
+        state::clear_guardian(deps.storage);
 
-    let claimable = total_claimable_amount.multiply_ratio(presale_submission, presale_raise);
+        return Ok(Response::default().add_event(config_event("guardian-removed")));
+    };
 
-    state::set_presale_claimed(deps.storage, &denom, info.sender.as_str(), true);
+    let guardian = deps.api.addr_validate(&guardian)?;
 
-    let send_shitcoins = BankMsg::Send {
-        to_address: info.sender.clone().into_string(),
-        amount: coins(claimable.u128(), &denom),
-    };
+    state::set_guardian(deps.storage, guardian.as_str());
 
-    let event = event("shitcoin-claimed", &denom, Some(info.sender.as_str()));
+    let event = config_event("guardian-set").add_attribute("guardian", guardian);
 
-    Ok(Response::default()
-        .add_message(send_shitcoins)
-        .add_event(event))
+    Ok(Response::default().add_event(event))
 }
 
-pub fn set_shitcoin_url(
+/// Pauses or unpauses `operation` for `denom`, or for every shitcoin without one. Pausing
+/// everywhere and for one shitcoin are independent, both have to be lifted for it to resume.
+pub fn set_pause(
     deps: DepsMut,
     info: MessageInfo,
-    denom: String,
-    url: String,
+    operation: Operation,
+    denom: Option<String>,
+    paused: bool,
 ) -> Result<Response> {
-    let creator =
-        state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    ensure_admin_or_guardian(deps.as_ref(), &info)?;
 
-    if creator.as_str() != info.sender.as_str() {
-        bail!("you are not the creator of this shitcoin");
-    }
+    if let Some(denom) = denom.as_deref() {
+        ensure!(
+            !matches!(operation, Operation::CreateShitcoin {}),
+            ContractError::CreatePausedPerShitcoin
+        );
 
-    state::set_shitcoin_url(deps.storage, &denom, &url);
+        state::shitcoin_creator(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+    }
 
-    let ticker =
-        state::shitcoin_ticker(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    let name = operation_name(&operation);
 
-    let name =
-        state::shitcoin_name(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+    if paused {
+        state::set_paused(deps.storage, name, denom.as_deref());
+    } else {
+        state::clear_paused(deps.storage, name, denom.as_deref());
+    }
 
-    let metadata_msg = NeutronMsg::SetDenomMetadata {
-        description: "shitcoin".to_owned(),
-        denom_units: vec![
-            DenomUnit {
-                denom: denom.clone(),
-                exponent: 0,
-                aliases: vec![],
-            },
-            DenomUnit {
-                denom: ticker.clone(),
-                exponent: 6,
-                aliases: vec![],
-            },
-        ],
-        base: denom.clone(),
-        display: ticker.clone(),
-        name,
-        symbol: ticker.clone(),
-        uri: url,
-        uri_hash: String::new(),
-    };
+    let mut event = config_event(if paused { "paused" } else { "unpaused" })
+        .add_attribute("operation", name)
+        .add_attribute("by", info.sender);
 
-    let event = event("shitcoin-url-set", &denom, None);
+    if let Some(denom) = denom {
+        event = event.add_attribute("denom", denom);
+    }
 
-    Ok(Response::default()
-        .add_message(metadata_msg)
-        .add_event(event))
+    Ok(Response::default().add_event(event))
 }
 
 #[entry_point]
@@ -448,9 +1973,44 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> R
             ticker,
             name,
             supply,
-        } => create_shitcoin(deps, env, info, ticker, name, supply),
-
-        ExecuteMsg::EnterPresale { denom } => enter_presale(deps, env, info, denom),
+            soft_cap,
+            hard_cap,
+            presale_length,
+            allocation,
+            lp_policy,
+            dex,
+            presale_denom,
+            allowlist,
+            min_contribution,
+            max_contribution,
+        } => create_shitcoin(
+            deps,
+            env,
+            info,
+            ticker,
+            name,
+            supply,
+            soft_cap,
+            hard_cap,
+            presale_length,
+            allocation,
+            lp_policy,
+            dex,
+            presale_denom,
+            allowlist,
+            min_contribution,
+            max_contribution,
+        ),
+
+        ExecuteMsg::EnterPresale {
+            denom,
+            referrer,
+            allowlist_proof,
+        } => enter_presale(deps, env, info, denom, referrer, allowlist_proof),
+
+        ExecuteMsg::WithdrawPresale { denom, amount } => {
+            withdraw_presale(deps, env, info, denom, amount)
+        }
 
         ExecuteMsg::ExtendPresale { denom } => extend_presale(deps, env, denom),
 
@@ -458,19 +2018,101 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> R
 
         ExecuteMsg::ClaimShitcoin { denom } => claim_shitcoin(deps, env, info, denom),
 
+        ExecuteMsg::RefundPresale { denom } => refund_presale(deps, env, info, denom),
+
+        ExecuteMsg::ClaimVested { denom } => claim_vested(deps, env, info, denom),
+
+        ExecuteMsg::ClaimLp { denom } => claim_lp(deps, env, info, denom),
+
         ExecuteMsg::SetUrl { denom, url } => set_shitcoin_url(deps, info, denom, url),
+
+        ExecuteMsg::ModerateShitcoin { denom, moderation } => {
+            moderate_shitcoin(deps, info, denom, moderation)
+        }
+
+        ExecuteMsg::UpdateConfig {
+            dex_factories,
+            dex,
+            fee_recipients,
+            creator_fee_share,
+            referral_fee_share,
+            create_fee_denom,
+            create_fee,
+            presale_denoms,
+            presale_length,
+            min_presale_length,
+            max_presale_length,
+            presale_fee_rate,
+            presale_withdraw_penalty_rate,
+            min_contribution,
+            max_contribution,
+            min_lp_allocation,
+            max_creator_allocation,
+            creator_vesting_cliff,
+            creator_vesting_duration,
+        } => update_config(
+            deps,
+            info,
+            dex_factories,
+            dex,
+            fee_recipients,
+            creator_fee_share,
+            referral_fee_share,
+            create_fee_denom,
+            create_fee,
+            presale_denoms,
+            presale_length,
+            min_presale_length,
+            max_presale_length,
+            presale_fee_rate,
+            presale_withdraw_penalty_rate,
+            min_contribution,
+            max_contribution,
+            min_lp_allocation,
+            max_creator_allocation,
+            creator_vesting_cliff,
+            creator_vesting_duration,
+        ),
+
+        ExecuteMsg::ProposeAdmin { admin } => propose_admin(deps, info, admin),
+
+        ExecuteMsg::AcceptAdmin {} => accept_admin(deps, info),
+
+        ExecuteMsg::SetGuardian { guardian } => set_guardian(deps, info, guardian),
+
+        ExecuteMsg::Pause { operation, denom } => set_pause(deps, info, operation, denom, true),
+
+        ExecuteMsg::Unpause { operation, denom } => set_pause(deps, info, operation, denom, false),
+
+        ExecuteMsg::BackfillIndexes { limit } => backfill_indexes(deps, info, limit),
     }
 }
 
 pub fn config(deps: Deps) -> Result<Config> {
     Ok(Config {
-        pool_factory_address: state::pool_factory_address(deps.storage),
-        fee_recipient: state::platform_fee_recipient(deps.storage),
+        admin: state::admin(deps.storage),
+        pending_admin: state::pending_admin(deps.storage),
+        guardian: state::guardian(deps.storage),
+        dex_factories: state::dex_factories(deps.storage),
+        dex: state::dex(deps.storage),
+        fee_recipients: state::fee_recipients(deps.storage),
+        creator_fee_share: state::creator_fee_share(deps.storage),
+        referral_fee_share: state::referral_fee_share(deps.storage),
         create_fee_denom: state::create_fee_denom(deps.storage),
         create_fee: state::create_fee(deps.storage),
-        presale_denom: state::presale_denom(deps.storage),
+        presale_denoms: state::presale_denoms(deps.storage),
         presale_length: state::presale_length(deps.storage),
+        min_presale_length: state::min_presale_length(deps.storage),
+        max_presale_length: state::max_presale_length(deps.storage),
         presale_fee_rate: state::presale_fee_rate(deps.storage),
+        presale_withdraw_penalty_rate: state::presale_withdraw_penalty_rate(deps.storage),
+        min_contribution: state::min_contribution(deps.storage),
+        max_contribution: state::max_contribution(deps.storage),
+        min_lp_allocation: state::min_lp_allocation(deps.storage),
+        max_creator_allocation: state::max_creator_allocation(deps.storage),
+        creator_vesting_cliff: state::creator_vesting_cliff(deps.storage),
+        creator_vesting_duration: state::creator_vesting_duration(deps.storage),
+        denom_admin_burn_address: state::denom_admin_burn_address(deps.storage),
     })
 }
 
@@ -486,6 +2128,12 @@ pub fn shitcoin_metadata(deps: Deps, env: &Env, denom: String) -> Result<Shitcoi
 
     let url = state::shitcoin_url(deps.storage, &denom).unwrap_or_default();
 
+    let presale_denom = state::shitcoin_presale_denom(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let presale_length = state::shitcoin_presale_length(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
     let presale_end =
         state::presale_end(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
 
@@ -499,53 +2147,304 @@ pub fn shitcoin_metadata(deps: Deps, env: &Env, denom: String) -> Result<Shitcoi
 
     let ended = presale_end.saturating_sub(env.block.time.seconds()) == 0;
 
+    let soft_cap = state::presale_soft_cap(deps.storage, &denom);
+
+    let hard_cap = state::presale_hard_cap(deps.storage, &denom);
+
+    let allocation = state::shitcoin_allocation(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let lp_policy = state::shitcoin_lp_policy(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let dex =
+        state::shitcoin_dex(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let pair = state::shitcoin_pair(deps.storage, &denom);
+
+    let lp_token = state::shitcoin_lp_token(deps.storage, &denom);
+
+    let lp_amount = state::shitcoin_lp_amount(deps.storage, &denom);
+
+    let lp_unlocks_at = state::shitcoin_lp_unlock(deps.storage, &denom);
+
+    let lp_claimed = state::shitcoin_lp_claimed(deps.storage, &denom).unwrap_or_default();
+
+    let admin_renounced = state::shitcoin_admin_renounced(deps.storage, &denom).unwrap_or_default();
+
+    let allowlist_root = state::presale_allowlist_root(deps.storage, &denom);
+
+    let allowlist_end = state::presale_allowlist_end(deps.storage, &denom);
+
+    let min_contribution = state::presale_min_contribution(deps.storage, &denom);
+
+    let max_contribution = state::presale_max_contribution(deps.storage, &denom);
+
+    let participants = state::presale_participants(deps.storage, &denom);
+
+    let moderation = state::shitcoin_moderation(deps.storage, &denom);
+
     Ok(ShitcoinMetadata {
         denom,
         creator,
         ticker,
         name,
         url,
+        presale_denom,
+        presale_length,
         presale_end,
+        allowlist_root,
+        allowlist_end,
         presale_raise,
+        participants,
         supply,
         launched,
         ended,
+        soft_cap,
+        hard_cap,
+        min_contribution,
+        max_contribution,
+        allocation,
+        lp_policy,
+        dex,
+        pair,
+        lp_token,
+        lp_amount,
+        lp_unlocks_at,
+        lp_claimed,
+        admin_renounced,
+        moderation,
     })
 }
 
+pub fn shitcoin_by_ticker(deps: Deps, env: &Env, ticker: String) -> Result<ShitcoinMetadata> {
+    let denom = state::ticker_denom(deps.storage, &ticker.to_lowercase())
+        .ok_or_else(|| StdError::not_found(&ticker))?;
+
+    shitcoin_metadata(deps, env, denom)
+}
+
+/// Derived from state alone, so a pool seeded ahead of launch only shows up once refunds open.
+fn shitcoin_status(deps: Deps, env: &Env, denom: &str) -> Result<ShitcoinStatus> {
+    if state::shitcoin_launched(deps.storage, denom).unwrap_or_default() {
+        return Ok(ShitcoinStatus::Launched {});
+    }
+
+    let presale_end =
+        state::presale_end(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    if presale_end.saturating_sub(env.block.time.seconds()) != 0 {
+        return Ok(ShitcoinStatus::Active {});
+    }
+
+    let presale_raise =
+        state::presale_raise(deps.storage, denom).ok_or_else(|| StdError::not_found(denom))?;
+
+    if presale_failed(deps, denom, presale_raise)
+        || state::presale_aborted(deps.storage, denom).unwrap_or_default()
+    {
+        return Ok(ShitcoinStatus::Abandoned {});
+    }
+
+    Ok(ShitcoinStatus::Ended {})
+}
+
 pub fn shitcoins(
     deps: Deps,
     env: &Env,
-    page: Option<u64>,
+    start_after: Option<u64>,
     limit: Option<u64>,
+    order: Option<SortOrder>,
+    status: Option<ShitcoinStatus>,
 ) -> Result<ShitcoinPage> {
-    let page = page.unwrap_or_default();
-
     let total = state::shitcoin_count(deps.storage);
 
-    let limit = limit.unwrap_or(10).min(total);
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT) as usize;
 
-    let start = page * limit;
+    let indexes: Box<dyn Iterator<Item = u64>> = match order.unwrap_or(SortOrder::Ascending {}) {
+        SortOrder::Ascending {} => {
+            Box::new(start_after.map_or(0, |idx| idx.saturating_add(1))..total)
+        }
+        SortOrder::Descending {} => Box::new((0..start_after.unwrap_or(total).min(total)).rev()),
+    };
+
+    let mut indexes = indexes.peekable();
+
+    let mut shitcoins = Vec::with_capacity(limit);
 
-    let end = total.max(start + limit);
+    let mut last = None;
 
-    let mut shitcoins = Vec::with_capacity(((end + 1) - start) as _);
+    let mut scanned = 0;
 
-    for idx in start..end {
-        dbg!(idx);
+    while shitcoins.len() < limit && scanned < MAX_PAGE_SCAN {
+        let Some(idx) = indexes.next() else {
+            break;
+        };
+
+        scanned += 1;
 
         let denom = state::shitcoin_denom(deps.storage, idx).expect("valid index");
 
-        let shitcoin_meta = shitcoin_metadata(deps, env, denom)?;
+        last = Some(idx);
+
+        if let Some(status) = status {
+            if shitcoin_status(deps, env, &denom)? != status {
+                continue;
+            }
+        }
+
+        shitcoins.push(shitcoin_metadata(deps, env, denom)?);
+    }
+
+    // only point past the last shitcoin looked at if there is anything left to look at
+    let next = last.filter(|_| indexes.peek().is_some());
+
+    Ok(ShitcoinPage {
+        total,
+        shitcoins,
+        next,
+    })
+}
 
-        shitcoins.push(shitcoin_meta);
+/// The entries on a page of a list that is only ever appended to, oldest first, with the cursor
+/// for the next page. Removed entries leave a gap, for which `entry` returns none, so the indexes
+/// of the rest never move under a client paging through. A page looks at no more than
+/// [`MAX_PAGE_SCAN`] slots, so it can come back short when there are many gaps.
+fn page_entries<T>(
+    slots: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+    mut entry: impl FnMut(u64) -> Result<Option<T>>,
+) -> Result<(Vec<T>, Option<u64>)> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT) as usize;
+
+    let start = start_after
+        .map_or(0, |idx| idx.saturating_add(1))
+        .min(slots);
+
+    let mut entries = Vec::with_capacity(limit);
+
+    let mut idx = start;
+
+    while idx < slots && entries.len() < limit && idx - start < MAX_PAGE_SCAN {
+        entries.extend(entry(idx)?);
+
+        idx += 1;
     }
 
+    let next = (idx < slots).then(|| idx - 1);
+
+    Ok((entries, next))
+}
+
+pub fn shitcoins_by_creator(
+    deps: Deps,
+    env: &Env,
+    creator: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> Result<ShitcoinPage> {
+    let total = state::creator_shitcoin_count(deps.storage, &creator);
+
+    let (shitcoins, next) = page_entries(total, start_after, limit, |idx| {
+        let denom = state::creator_shitcoin(deps.storage, &creator, idx).expect("valid index");
+
+        shitcoin_metadata(deps, env, denom).map(Some)
+    })?;
+
     Ok(ShitcoinPage {
-        page,
-        limit,
         total,
         shitcoins,
+        next,
+    })
+}
+
+pub fn positions_by_degen(
+    deps: Deps,
+    degen: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> Result<PositionPage> {
+    let total = state::degen_presales(deps.storage, &degen);
+
+    let slots = state::degen_presale_count(deps.storage, &degen);
+
+    let (positions, next) = page_entries(slots, start_after, limit, |idx| {
+        let Some(denom) = state::degen_presale(deps.storage, &degen, idx) else {
+            return Ok(None);
+        };
+
+        let ticker = state::shitcoin_ticker(deps.storage, &denom)
+            .ok_or_else(|| StdError::not_found(&denom))?;
+
+        let presale_submission =
+            state::presale_submission(deps.storage, &denom, &degen).unwrap_or_default();
+
+        let shitcoins_claimed =
+            state::presale_claimed(deps.storage, &denom, &degen).unwrap_or_default();
+
+        let presale_refunded =
+            state::presale_refunded(deps.storage, &denom, &degen).unwrap_or_default();
+
+        let launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
+
+        let claimable = if launched && !shitcoins_claimed && !presale_submission.is_zero() {
+            presale_claim_amount(deps, &denom, presale_submission)?
+        } else {
+            Uint128::zero()
+        };
+
+        Ok(Some(Position {
+            denom,
+            ticker,
+            presale_submission,
+            shitcoins_claimed,
+            presale_refunded,
+            claimable,
+        }))
+    })?;
+
+    Ok(PositionPage {
+        total,
+        positions,
+        next,
+    })
+}
+
+pub fn presale_participants(
+    deps: Deps,
+    denom: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> Result<ParticipantPage> {
+    state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let total = state::presale_participants(deps.storage, &denom);
+
+    let slots = state::presale_participant_count(deps.storage, &denom);
+
+    let (participants, next) = page_entries(slots, start_after, limit, |idx| {
+        let Some(address) = state::presale_participant(deps.storage, &denom, idx) else {
+            return Ok(None);
+        };
+
+        let presale_submission =
+            state::presale_submission(deps.storage, &denom, &address).unwrap_or_default();
+
+        let shitcoins_claimed =
+            state::presale_claimed(deps.storage, &denom, &address).unwrap_or_default();
+
+        Ok(Some(Participant {
+            address,
+            presale_submission,
+            shitcoins_claimed,
+        }))
+    })?;
+
+    Ok(ParticipantPage {
+        total,
+        participants,
+        next,
     })
 }
 
@@ -558,9 +2457,189 @@ pub fn degen_metadata(deps: Deps, denom: String, degen: String) -> Result<DegenM
     let shitcoins_claimed =
         state::presale_claimed(deps.storage, &denom, &degen).unwrap_or_default();
 
+    let presale_refunded =
+        state::presale_refunded(deps.storage, &denom, &degen).unwrap_or_default();
+
+    let min_contribution = state::presale_min_contribution(deps.storage, &denom);
+
+    let max_contribution = state::presale_max_contribution(deps.storage, &denom);
+
+    let presale_raise = state::presale_raise(deps.storage, &denom).unwrap_or_default();
+
+    // whichever of the degen's maximum and the room left under the hard cap is smaller
+    let remaining_contribution = [
+        max_contribution.map(|max| max.saturating_sub(presale_submission)),
+        state::presale_hard_cap(deps.storage, &denom)
+            .map(|hard_cap| hard_cap.saturating_sub(presale_raise)),
+    ]
+    .into_iter()
+    .flatten()
+    .min();
+
     Ok(DegenMetadata {
         presale_submission,
         shitcoins_claimed,
+        presale_refunded,
+        min_contribution,
+        max_contribution,
+        remaining_contribution,
+    })
+}
+
+pub fn claimable(deps: Deps, denom: String, degen: String) -> Result<Claimable> {
+    state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let launched = state::shitcoin_launched(deps.storage, &denom).unwrap_or_default();
+
+    let presale_submission =
+        state::presale_submission(deps.storage, &denom, &degen).unwrap_or_default();
+
+    let shitcoins_claimed =
+        state::presale_claimed(deps.storage, &denom, &degen).unwrap_or_default();
+
+    let presale_refunded =
+        state::presale_refunded(deps.storage, &denom, &degen).unwrap_or_default();
+
+    let amount = if presale_submission.is_zero() || shitcoins_claimed || presale_refunded {
+        Uint128::zero()
+    } else {
+        presale_claim_amount(deps, &denom, presale_submission)?
+    };
+
+    Ok(Claimable {
+        denom,
+        degen,
+        amount,
+        claimable: launched && !amount.is_zero(),
+    })
+}
+
+pub fn simulate_launch(deps: Deps, denom: String) -> Result<LaunchSimulation> {
+    let shitcoin_supply =
+        state::shitcoin_supply(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let presale_raise =
+        state::presale_raise(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let allocation = state::shitcoin_allocation(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let lp_shitcoin_amount = allocation_amounts(shitcoin_supply, &allocation).lp;
+
+    // the same amounts the pool gets seeded with, priced by their ratio
+    let (price, market_cap) = if lp_shitcoin_amount.is_zero() {
+        (Decimal::zero(), Uint128::zero())
+    } else {
+        let price = Decimal::checked_from_ratio(presale_raise, lp_shitcoin_amount)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+        let market_cap = shitcoin_supply.multiply_ratio(presale_raise, lp_shitcoin_amount);
+
+        (price, market_cap)
+    };
+
+    Ok(LaunchSimulation {
+        denom,
+        lp_shitcoin_amount,
+        lp_presale_amount: presale_raise,
+        price,
+        market_cap,
+    })
+}
+
+pub fn vesting_status(deps: Deps, env: &Env, denom: String) -> Result<VestingStatus> {
+    let creator =
+        state::shitcoin_creator(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let supply =
+        state::shitcoin_supply(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let allocation = state::shitcoin_allocation(deps.storage, &denom)
+        .ok_or_else(|| StdError::not_found(&denom))?;
+
+    let cliff =
+        state::vesting_cliff(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let duration =
+        state::vesting_duration(deps.storage, &denom).ok_or_else(|| StdError::not_found(&denom))?;
+
+    let start = state::vesting_start(deps.storage, &denom);
+
+    let claimed = state::vesting_claimed(deps.storage, &denom).unwrap_or_default();
+
+    let total = allocation_amounts(supply, &allocation).creator;
+
+    let released = start.map_or(Uint128::zero(), |start| {
+        let elapsed = env.block.time.seconds().saturating_sub(start);
+
+        vested_amount(total, elapsed, cliff, duration)
+    });
+
+    Ok(VestingStatus {
+        creator,
+        total,
+        locked: total - released,
+        released,
+        claimed,
+        start,
+        cliff,
+        duration,
+    })
+}
+
+pub fn pause_status(deps: Deps, denom: Option<String>) -> Result<PauseStatus> {
+    const OPERATIONS: [Operation; 6] = [
+        Operation::CreateShitcoin {},
+        Operation::EnterPresale {},
+        Operation::ExtendPresale {},
+        Operation::LaunchShitcoin {},
+        Operation::ClaimShitcoin {},
+        Operation::SetUrl {},
+    ];
+
+    let paused = OPERATIONS
+        .into_iter()
+        .filter(|operation| state::paused(deps.storage, operation_name(operation), None))
+        .collect();
+
+    let denom_paused = match denom.as_deref() {
+        Some(denom) => {
+            state::shitcoin_creator(deps.storage, denom)
+                .ok_or_else(|| StdError::not_found(denom))?;
+
+            OPERATIONS
+                .into_iter()
+                .filter(|operation| {
+                    state::paused(deps.storage, operation_name(operation), Some(denom))
+                })
+                .collect()
+        }
+        None => vec![],
+    };
+
+    Ok(PauseStatus {
+        paused,
+        denom_paused,
+    })
+}
+
+pub fn referrer_stats(deps: Deps, address: String) -> Result<ReferrerStats> {
+    let referrals = state::referrer_referrals(deps.storage, &address).unwrap_or_default();
+
+    let earnings = state::referrer_denoms(deps.storage, &address)
+        .into_iter()
+        .map(|denom| {
+            let amount =
+                state::referrer_earnings(deps.storage, &address, &denom).unwrap_or_default();
+
+            coin(amount.u128(), denom)
+        })
+        .collect();
+
+    Ok(ReferrerStats {
+        address,
+        referrals,
+        earnings,
     })
 }
 
@@ -579,8 +2658,39 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary> {
             to_json_binary(&response)?
         }
 
-        QueryMsg::Shitcoins { page, limit } => {
-            let response = shitcoins(deps, &env, page, limit)?;
+        QueryMsg::ShitcoinByTicker { ticker } => {
+            let response = shitcoin_by_ticker(deps, &env, ticker)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::Shitcoins {
+            start_after,
+            limit,
+            order,
+            status,
+        } => {
+            let response = shitcoins(deps, &env, start_after, limit, order, status)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::ShitcoinsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => {
+            let response = shitcoins_by_creator(deps, &env, creator, start_after, limit)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::PositionsByDegen {
+            degen,
+            start_after,
+            limit,
+        } => {
+            let response = positions_by_degen(deps, degen, start_after, limit)?;
 
             to_json_binary(&response)?
         }
@@ -590,6 +2700,46 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary> {
 
             to_json_binary(&response)?
         }
+
+        QueryMsg::PresaleParticipants {
+            denom,
+            start_after,
+            limit,
+        } => {
+            let response = presale_participants(deps, denom, start_after, limit)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::Claimable { denom, degen } => {
+            let response = claimable(deps, denom, degen)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::SimulateLaunch { denom } => {
+            let response = simulate_launch(deps, denom)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::VestingStatus { denom } => {
+            let response = vesting_status(deps, &env, denom)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::PauseStatus { denom } => {
+            let response = pause_status(deps, denom)?;
+
+            to_json_binary(&response)?
+        }
+
+        QueryMsg::ReferrerStats { address } => {
+            let response = referrer_stats(deps, address)?;
+
+            to_json_binary(&response)?
+        }
     };
 
     Ok(binary)
@@ -0,0 +1,40 @@
+//! Merkle proofs for presale allowlists.
+//!
+//! Leaves are the sha256 hash of an address and every parent is the sha256 hash of its children
+//! in ascending order, so a proof is just the siblings on the way up to the root.
+
+use cosmwasm_std::HexBinary;
+use sha2::{Digest, Sha256};
+
+pub const HASH_LENGTH: usize = 32;
+
+pub type Hash = [u8; HASH_LENGTH];
+
+pub fn leaf(address: &str) -> Hash {
+    Sha256::digest(address.as_bytes()).into()
+}
+
+pub fn parent(a: &Hash, b: &Hash) -> Hash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+
+    Sha256::new()
+        .chain_update(first)
+        .chain_update(second)
+        .finalize()
+        .into()
+}
+
+/// Whether `proof` leads from the leaf of `address` up to `root`.
+pub fn verify(root: &[u8], address: &str, proof: &[HexBinary]) -> bool {
+    let mut hash = leaf(address);
+
+    for sibling in proof {
+        let Ok(sibling) = Hash::try_from(sibling.as_slice()) else {
+            return false;
+        };
+
+        hash = parent(&hash, &sibling);
+    }
+
+    root == hash.as_slice()
+}
@@ -0,0 +1,413 @@
+use cosmwasm_std::{DepsMut, Env, Storage};
+
+use crate::{
+    error::ContractError,
+    msg::{Allocation, Dex, DexFactory, FeeDestination, FeeRecipient, LpPolicy},
+    state, Result,
+};
+
+/// `(major, minor, patch)`, ordered the same way semver orders release versions.
+pub type Version = (u64, u64, u64);
+
+type Migration = fn(DepsMut, &Env) -> Result<()>;
+
+/// The version assumed for deployments that predate the stored contract version.
+pub const UNVERSIONED: &str = "0.1.0";
+
+/// Withdraw penalty given to deployments that predate early withdrawals.
+const DEFAULT_WITHDRAW_PENALTY_RATE: u32 = 1_000;
+
+/// Presale length bounds given to deployments that predate creator chosen presale lengths,
+/// widened where needed to include the configured presale length.
+const DEFAULT_MIN_PRESALE_LENGTH: u64 = 60 * 60;
+const DEFAULT_MAX_PRESALE_LENGTH: u64 = 60 * 60 * 24 * 7;
+
+/// Allocation bounds given to deployments that predate configurable allocations.
+const DEFAULT_MIN_LP_ALLOCATION: u32 = 3_000;
+const DEFAULT_MAX_CREATOR_ALLOCATION: u32 = 1_000;
+
+/// Creator vesting given to deployments that predate vesting.
+const DEFAULT_CREATOR_VESTING_CLIFF: u64 = 60 * 60 * 24 * 30;
+const DEFAULT_CREATOR_VESTING_DURATION: u64 = 60 * 60 * 24 * 180;
+
+/// The even split of presale fees between the creator and the platform used before the creator
+/// share was configurable.
+const LEGACY_CREATOR_FEE_SHARE: u32 = 5_000;
+
+/// The fixed split every shitcoin used before allocations were configurable.
+const LEGACY_ALLOCATION: Allocation = Allocation {
+    lp: 5_000,
+    presale: 5_000,
+    creator: 0,
+};
+
+/// Storage migrations keyed by the contract version that introduced them, in ascending order, each
+/// named after what it fills in.
+///
+/// Every migration whose version is newer than the stored version and no newer than the version
+/// being migrated to is applied, in the order listed. Each only touches config and a few keys per
+/// shitcoin, anything that grows with the submissions is left to [`backfill_indexes`]. Shitcoins
+/// launched before keep their denom admin, so admin_renounced stays unset for them.
+const MIGRATIONS: &[(&str, &str, Migration)] = &[
+    ("0.2.0", "admin", admin),
+    ("0.2.0", "ticker_index", ticker_index),
+    ("0.2.0", "withdraw_penalty", withdraw_penalty),
+    ("0.2.0", "presale_lengths", presale_lengths),
+    ("0.2.0", "allocations", allocations),
+    ("0.2.0", "creator_vesting", creator_vesting),
+    ("0.2.0", "lp_policies", lp_policies),
+    (
+        "0.2.0",
+        "denom_admin_burn_address",
+        denom_admin_burn_address,
+    ),
+    ("0.2.0", "dexes", dexes),
+    ("0.2.0", "presale_denoms", presale_denoms),
+    ("0.2.0", "fee_recipients", fee_recipients),
+    ("0.2.0", "referral_fee_share", referral_fee_share),
+    ("0.2.0", "shitcoin_indexes", shitcoin_indexes),
+    ("0.2.0", "participant_index", participant_index),
+];
+
+pub fn parse_version(version: &str) -> Result<Version> {
+    let invalid = || ContractError::InvalidContractVersion {
+        version: version.to_owned(),
+    };
+
+    let mut parts = version.splitn(3, '.').map(|part| {
+        // ignore any pre-release or build metadata on the patch version
+        let digits = part
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+            .unwrap_or_default();
+
+        digits.parse::<u64>().map_err(|_| invalid())
+    });
+
+    let (Some(major), Some(minor), Some(patch)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+
+    Ok((major?, minor?, patch?))
+}
+
+/// Applies all migrations in the `(from, to]` version range, returning the names of those applied.
+pub fn run(mut deps: DepsMut, env: &Env, from: Version, to: Version) -> Result<Vec<&'static str>> {
+    let mut applied = vec![];
+
+    for (version, name, migration) in MIGRATIONS {
+        let migration_version = parse_version(version)?;
+
+        if migration_version <= from || migration_version > to {
+            continue;
+        }
+
+        migration(deps.branch(), env)?;
+
+        applied.push(*name);
+    }
+
+    Ok(applied)
+}
+
+/// The garden admin defaults to the wasm admin of the contract.
+fn admin(deps: DepsMut, env: &Env) -> Result<()> {
+    if state::get_string(deps.storage, state::ADMIN).is_some() {
+        return Ok(());
+    }
+
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.as_str())?;
+
+    let admin = contract_info.admin.unwrap_or(contract_info.creator);
+
+    state::set_admin(deps.storage, &admin);
+
+    Ok(())
+}
+
+/// The ticker index rejects duplicate tickers, so it has to cover every existing shitcoin.
+fn ticker_index(deps: DepsMut, _env: &Env) -> Result<()> {
+    for denom in shitcoin_denoms(deps.storage) {
+        let ticker = state::shitcoin_ticker(deps.storage, &denom).expect("set during create");
+
+        state::set_ticker_denom(deps.storage, &ticker.to_lowercase(), &denom);
+    }
+
+    Ok(())
+}
+
+fn withdraw_penalty(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_u32(deps.storage, state::PRESALE_WITHDRAW_PENALTY_RATE).is_none() {
+        state::set_presale_withdraw_penalty_rate(deps.storage, DEFAULT_WITHDRAW_PENALTY_RATE);
+    }
+
+    Ok(())
+}
+
+/// Existing shitcoins keep the configured presale length, which has to stay within the bounds.
+fn presale_lengths(deps: DepsMut, _env: &Env) -> Result<()> {
+    let presale_length = state::presale_length(deps.storage);
+
+    if state::get_u64(deps.storage, state::MIN_PRESALE_LENGTH).is_none() {
+        state::set_min_presale_length(deps.storage, DEFAULT_MIN_PRESALE_LENGTH.min(presale_length));
+    }
+
+    if state::get_u64(deps.storage, state::MAX_PRESALE_LENGTH).is_none() {
+        state::set_max_presale_length(deps.storage, DEFAULT_MAX_PRESALE_LENGTH.max(presale_length));
+    }
+
+    for denom in shitcoin_denoms(deps.storage) {
+        if state::shitcoin_presale_length(deps.storage, &denom).is_none() {
+            state::set_shitcoin_presale_length(deps.storage, &denom, presale_length);
+        }
+    }
+
+    Ok(())
+}
+
+/// Existing shitcoins keep the original half and half split.
+fn allocations(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_u32(deps.storage, state::MIN_LP_ALLOCATION).is_none() {
+        state::set_min_lp_allocation(deps.storage, DEFAULT_MIN_LP_ALLOCATION);
+    }
+
+    if state::get_u32(deps.storage, state::MAX_CREATOR_ALLOCATION).is_none() {
+        state::set_max_creator_allocation(deps.storage, DEFAULT_MAX_CREATOR_ALLOCATION);
+    }
+
+    for denom in shitcoin_denoms(deps.storage) {
+        if state::shitcoin_allocation(deps.storage, &denom).is_none() {
+            state::set_shitcoin_allocation(deps.storage, &denom, &LEGACY_ALLOCATION);
+        }
+    }
+
+    Ok(())
+}
+
+/// Existing shitcoins have no creator allocation, so there is nothing for them to vest.
+fn creator_vesting(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_u64(deps.storage, state::CREATOR_VESTING_CLIFF).is_none() {
+        state::set_creator_vesting_cliff(deps.storage, DEFAULT_CREATOR_VESTING_CLIFF);
+    }
+
+    if state::get_u64(deps.storage, state::CREATOR_VESTING_DURATION).is_none() {
+        state::set_creator_vesting_duration(deps.storage, DEFAULT_CREATOR_VESTING_DURATION);
+    }
+
+    let vesting_cliff = state::creator_vesting_cliff(deps.storage);
+    let vesting_duration = state::creator_vesting_duration(deps.storage);
+
+    for denom in shitcoin_denoms(deps.storage) {
+        if state::vesting_duration(deps.storage, &denom).is_none() {
+            state::set_vesting_cliff(deps.storage, &denom, vesting_cliff);
+            state::set_vesting_duration(deps.storage, &denom, vesting_duration);
+        }
+    }
+
+    Ok(())
+}
+
+/// Liquidity of existing shitcoins stays locked in the garden as before.
+fn lp_policies(deps: DepsMut, _env: &Env) -> Result<()> {
+    for denom in shitcoin_denoms(deps.storage) {
+        if state::shitcoin_lp_policy(deps.storage, &denom).is_none() {
+            state::set_shitcoin_lp_policy(deps.storage, &denom, &LpPolicy::Lock {});
+        }
+    }
+
+    Ok(())
+}
+
+/// Launches renounce denom admins to the chain's all-zero address.
+fn denom_admin_burn_address(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_string(deps.storage, state::DENOM_ADMIN_BURN_ADDRESS).is_none() {
+        let address = crate::zero_address(deps.api)?;
+
+        state::set_denom_admin_burn_address(deps.storage, address.as_str());
+    }
+
+    Ok(())
+}
+
+/// Astroport xyk was the only dex, and its pool factory the only one.
+fn dexes(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_string(deps.storage, state::DEX).is_none() {
+        state::set_dex(deps.storage, Dex::AstroportXyk {});
+    }
+
+    if state::get_u64(deps.storage, state::DEX_FACTORY_COUNT).is_none() {
+        let address =
+            state::get_string(deps.storage, state::POOL_FACTORY).expect("set during init");
+
+        state::set_dex_factories(
+            deps.storage,
+            &[DexFactory {
+                dex: Dex::AstroportXyk {},
+                address,
+            }],
+        );
+
+        deps.storage.remove(state::POOL_FACTORY.as_bytes());
+    }
+
+    let legacy_pool_factory = state::dex_factory(deps.storage, Dex::AstroportXyk {});
+
+    for denom in shitcoin_denoms(deps.storage) {
+        if state::shitcoin_dex(deps.storage, &denom).is_none() {
+            state::set_shitcoin_dex(deps.storage, &denom, Dex::AstroportXyk {});
+        }
+
+        if let (None, Some(pool_factory)) = (
+            state::shitcoin_pool_factory(deps.storage, &denom),
+            &legacy_pool_factory,
+        ) {
+            state::set_shitcoin_pool_factory(deps.storage, &denom, pool_factory);
+        }
+    }
+
+    Ok(())
+}
+
+/// The single presale denom becomes the only accepted one, and the one of every existing shitcoin.
+fn presale_denoms(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_u64(deps.storage, state::PRESALE_DENOM_COUNT).is_none() {
+        let presale_denom =
+            state::get_string(deps.storage, state::PRESALE_DENOM).expect("set during init");
+
+        state::set_presale_denoms(deps.storage, &[presale_denom]);
+
+        deps.storage.remove(state::PRESALE_DENOM.as_bytes());
+    }
+
+    // the allowlist only has the legacy presale denom until the admin updates it
+    let legacy_presale_denom = state::presale_denoms(deps.storage).swap_remove(0);
+
+    for denom in shitcoin_denoms(deps.storage) {
+        if state::shitcoin_presale_denom(deps.storage, &denom).is_none() {
+            state::set_shitcoin_presale_denom(deps.storage, &denom, &legacy_presale_denom);
+        }
+    }
+
+    Ok(())
+}
+
+/// The single fee recipient still splits presale fees evenly with creators.
+fn fee_recipients(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_u64(deps.storage, state::FEE_RECIPIENT_COUNT).is_none() {
+        let address = state::get_string(deps.storage, state::PLATFORM_FEE_RECIPIENT)
+            .expect("set during init");
+
+        state::set_fee_recipients(
+            deps.storage,
+            &[FeeRecipient {
+                destination: FeeDestination::Address { address },
+                weight: 1,
+            }],
+        );
+
+        deps.storage
+            .remove(state::PLATFORM_FEE_RECIPIENT.as_bytes());
+    }
+
+    if state::get_u32(deps.storage, state::CREATOR_FEE_SHARE).is_none() {
+        state::set_creator_fee_share(deps.storage, LEGACY_CREATOR_FEE_SHARE);
+    }
+
+    Ok(())
+}
+
+/// Referrals pay nothing until the admin sets a share.
+fn referral_fee_share(deps: DepsMut, _env: &Env) -> Result<()> {
+    if state::get_u32(deps.storage, state::REFERRAL_FEE_SHARE).is_none() {
+        state::set_referral_fee_share(deps.storage, 0);
+    }
+
+    Ok(())
+}
+
+/// The shitcoins by creator index is filled in right away, the presales by degen index from the
+/// submissions by [`backfill_indexes`].
+fn shitcoin_indexes(deps: DepsMut, _env: &Env) -> Result<()> {
+    for denom in shitcoin_denoms(deps.storage) {
+        let creator = state::shitcoin_creator(deps.storage, &denom).expect("set during create");
+
+        state::add_creator_shitcoin(deps.storage, &creator, &denom);
+    }
+
+    start_index_backfill(deps.storage);
+
+    Ok(())
+}
+
+/// The participants index is filled in from the submissions by [`backfill_indexes`].
+fn participant_index(deps: DepsMut, _env: &Env) -> Result<()> {
+    start_index_backfill(deps.storage);
+
+    Ok(())
+}
+
+fn shitcoin_denoms(storage: &dyn Storage) -> Vec<String> {
+    (0..state::shitcoin_count(storage))
+        .map(|idx| state::shitcoin_denom(storage, idx).expect("valid index"))
+        .collect()
+}
+
+fn start_index_backfill(storage: &mut dyn Storage) {
+    let shitcoin_count = state::shitcoin_count(storage);
+
+    // shitcoins created from here on are indexed as degens enter
+    if shitcoin_count > 0 {
+        state::start_index_backfill(storage, shitcoin_count);
+    }
+}
+
+/// Indexes up to `limit` submissions to shitcoins created before the indexes, picking up where
+/// the last call left off, and returns whether every one of them is now indexed.
+///
+/// Moving on to the next shitcoin counts towards the limit too, so presales nobody entered cannot
+/// make a call unbounded. Participants are indexed in address order, as the order of entry is lost.
+pub fn backfill_indexes(storage: &mut dyn Storage, limit: u64) -> Result<bool> {
+    let end = state::index_backfill_end(storage).ok_or(ContractError::NoPendingBackfill)?;
+
+    let (mut idx, mut start_after) = state::index_backfill_cursor(storage);
+
+    let mut budget = limit;
+
+    while idx < end && budget > 0 {
+        let denom = state::shitcoin_denom(storage, idx).expect("valid index");
+
+        let degens =
+            state::presale_degens(storage, &denom, start_after.as_deref(), budget as usize);
+
+        for degen in &degens {
+            let submission = state::presale_submission(storage, &denom, degen);
+
+            // degens who withdrew in full have no position left
+            if submission.is_some_and(|submission| !submission.is_zero()) {
+                state::add_degen_presale(storage, degen, &denom);
+                state::add_presale_participant(storage, &denom, degen);
+            }
+        }
+
+        if (degens.len() as u64) < budget {
+            idx += 1;
+            start_after = None;
+            budget -= degens.len() as u64 + 1;
+        } else {
+            start_after = degens.last().cloned();
+            budget = 0;
+        }
+    }
+
+    if idx >= end {
+        state::finish_index_backfill(storage);
+
+        return Ok(true);
+    }
+
+    state::set_index_backfill_cursor(storage, idx, start_after.as_deref());
+
+    Ok(false)
+}
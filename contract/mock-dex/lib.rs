@@ -1,35 +1,267 @@
-use astroport::{asset::PairInfo, factory::PairType};
+//! A minimal DEX for localnet, speaking the mock pool protocol of the garden's `Mock` adapter.
+//!
+//! Instantiated without denoms it is a factory, which instantiates a copy of itself for every
+//! pool. A pool's reserves are its own bank balances, so tokens sent straight to it count too,
+//! and it doubles as its own LP token.
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    entry_point, from_json, to_json_binary, to_json_vec, Binary, Coin, Deps, DepsMut, Env,
+    MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
-use serde_cw_value::Value;
+
+const DENOMS: &str = "DENOMS";
+const TOTAL_SHARE: &str = "TOTAL_SHARE";
+const SHARE: &str = "SHARE";
+
+const INSTANTIATE_POOL_REPLY_ID: u64 = 1;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub denoms: Option<Vec<String>>, // none for the factory
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Sent to the factory, reports `pool` and `lp_token` attributes.
+    CreatePool {
+        denoms: Vec<String>,
+    },
+    /// Sent to the pool with the liquidity as funds, reports a `share` attribute.
+    ProvideLiquidity {},
+    Transfer {
+        recipient: String,
+        amount: Uint128,
+    },
+    Burn {
+        amount: Uint128,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ReservesResponse)]
+    Reserves {},
+    #[returns(BalanceResponse)]
+    Balance { address: String },
+}
+
+#[cw_serde]
+pub struct ReservesResponse {
+    pub reserves: Vec<Coin>,
+    pub total_share: Uint128,
+}
+
+#[cw_serde]
+pub struct BalanceResponse {
+    pub balance: Uint128,
+}
+
+fn share_key(address: &str) -> String {
+    format!("{SHARE}:{address}")
+}
+
+fn get_u128(storage: &dyn Storage, key: &str) -> StdResult<Uint128> {
+    storage
+        .get(key.as_bytes())
+        .map_or(Ok(Uint128::zero()), |bytes| from_json(bytes))
+}
+
+fn set_u128(storage: &mut dyn Storage, key: &str, value: Uint128) -> StdResult<()> {
+    storage.set(key.as_bytes(), &to_json_vec(&value)?);
+
+    Ok(())
+}
+
+fn denoms(storage: &dyn Storage) -> StdResult<Vec<String>> {
+    let bytes = storage
+        .get(DENOMS.as_bytes())
+        .ok_or_else(|| StdError::generic_err("not a pool"))?;
+
+    from_json(bytes)
+}
+
+fn reserves(deps: Deps, env: &Env) -> StdResult<Vec<Coin>> {
+    denoms(deps.storage)?
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+        .collect()
+}
 
 #[entry_point]
 pub fn instantiate(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
-    _msg: Value,
-) -> Result<Response, StdError> {
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    if let Some(denoms) = msg.denoms {
+        if denoms.len() != 2 || denoms[0] == denoms[1] {
+            return Err(StdError::generic_err("a pool needs two distinct denoms"));
+        }
+
+        deps.storage.set(DENOMS.as_bytes(), &to_json_vec(&denoms)?);
+    }
+
     Ok(Response::default())
 }
 
 #[entry_point]
-pub fn execute(
-    _deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    _msg: Value,
-) -> Result<Response, StdError> {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::CreatePool { denoms } => create_pool(deps, env, denoms),
+        ExecuteMsg::ProvideLiquidity {} => provide_liquidity(deps, env, info),
+        ExecuteMsg::Transfer { recipient, amount } => {
+            transfer(deps, info.sender.as_str(), &recipient, amount)
+        }
+        ExecuteMsg::Burn { amount } => burn(deps, info.sender.as_str(), amount),
+    }
+}
+
+fn create_pool(deps: DepsMut, env: Env, denoms: Vec<String>) -> StdResult<Response> {
+    if deps.storage.get(DENOMS.as_bytes()).is_some() {
+        return Err(StdError::generic_err("pools cannot create pools"));
+    }
+
+    // every pool is a fresh copy of this contract
+    let code_id = deps
+        .querier
+        .query_wasm_contract_info(&env.contract.address)?
+        .code_id;
+
+    let instantiate_msg = WasmMsg::Instantiate {
+        admin: None,
+        code_id,
+        label: format!("mock pool {}", denoms.join("-")),
+        msg: to_json_binary(&InstantiateMsg {
+            denoms: Some(denoms),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::default().add_submessage(SubMsg::reply_on_success(
+        instantiate_msg,
+        INSTANTIATE_POOL_REPLY_ID,
+    )))
+}
+
+fn provide_liquidity(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let denoms = denoms(deps.storage)?;
+
+    let deposits = denoms
+        .iter()
+        .map(|denom| {
+            info.funds
+                .iter()
+                .find(|coin| &coin.denom == denom)
+                .map(|coin| coin.amount)
+                .filter(|amount| !amount.is_zero())
+                .ok_or_else(|| StdError::generic_err(format!("missing deposit: {denom}")))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if info.funds.len() != denoms.len() {
+        return Err(StdError::generic_err("unexpected funds"));
+    }
+
+    let total_share = get_u128(deps.storage, TOTAL_SHARE)?;
+
+    // the funds are already in the pool's balances by the time it executes
+    let share = if total_share.is_zero() {
+        deposits[0]
+    } else {
+        let reserves = reserves(deps.as_ref(), &env)?;
+
+        deposits
+            .iter()
+            .zip(&reserves)
+            .map(|(deposit, reserve)| {
+                deposit
+                    .checked_multiply_ratio(total_share, reserve.amount - *deposit)
+                    .map_err(|err| StdError::generic_err(err.to_string()))
+            })
+            .collect::<StdResult<Vec<_>>>()?
+            .into_iter()
+            .min()
+            .unwrap_or_default()
+    };
+
+    set_u128(deps.storage, TOTAL_SHARE, total_share + share)?;
+
+    let key = share_key(info.sender.as_str());
+
+    let balance = get_u128(deps.storage, &key)?;
+
+    set_u128(deps.storage, &key, balance + share)?;
+
+    Ok(Response::default().add_attribute("share", share))
+}
+
+fn transfer(deps: DepsMut, sender: &str, recipient: &str, amount: Uint128) -> StdResult<Response> {
+    let sender_key = share_key(sender);
+
+    let sender_balance = get_u128(deps.storage, &sender_key)?.checked_sub(amount)?;
+
+    set_u128(deps.storage, &sender_key, sender_balance)?;
+
+    let recipient_key = share_key(recipient);
+
+    let recipient_balance = get_u128(deps.storage, &recipient_key)?;
+
+    set_u128(deps.storage, &recipient_key, recipient_balance + amount)?;
+
     Ok(Response::default())
 }
 
+fn burn(deps: DepsMut, sender: &str, amount: Uint128) -> StdResult<Response> {
+    let key = share_key(sender);
+
+    let balance = get_u128(deps.storage, &key)?.checked_sub(amount)?;
+
+    set_u128(deps.storage, &key, balance)?;
+
+    let total_share = get_u128(deps.storage, TOTAL_SHARE)?;
+
+    set_u128(deps.storage, TOTAL_SHARE, total_share - amount)?;
+
+    Ok(Response::default())
+}
+
+#[entry_point]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    if msg.id != INSTANTIATE_POOL_REPLY_ID {
+        return Err(StdError::generic_err(format!(
+            "unknown reply id: {}",
+            msg.id
+        )));
+    }
+
+    let response = msg.result.into_result().map_err(StdError::generic_err)?;
+
+    let pool = response
+        .events
+        .iter()
+        .filter(|event| event.ty == "instantiate")
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "_contract_address")
+        .map(|attr| attr.value.clone())
+        .ok_or_else(|| StdError::generic_err("missing pool address"))?;
+
+    Ok(Response::default()
+        .add_attribute("pool", &pool)
+        .add_attribute("lp_token", pool))
+}
+
 #[entry_point]
-pub fn query(_deps: Deps, env: Env, _msg: Value) -> Result<Binary, StdError> {
-    to_json_binary(&PairInfo {
-        asset_infos: vec![],
-        contract_addr: env.contract.address.clone(),
-        liquidity_token: env.contract.address.clone(),
-        pair_type: PairType::Xyk {},
-    })
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Reserves {} => to_json_binary(&ReservesResponse {
+            reserves: reserves(deps, &env)?,
+            total_share: get_u128(deps.storage, TOTAL_SHARE)?,
+        }),
+        QueryMsg::Balance { address } => to_json_binary(&BalanceResponse {
+            balance: get_u128(deps.storage, &share_key(&address))?,
+        }),
+    }
 }
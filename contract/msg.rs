@@ -1,15 +1,92 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Coin, Decimal, HexBinary, Uint128};
 
 #[cw_serde]
 pub struct InstantiateMsg {
-    pub pool_factory_address: String,
-    pub fee_recipient: String,
+    pub admin: Option<String>,             // defaults to the instantiator
+    pub dex_factories: Vec<DexFactory>,    // the DEXes shitcoins can launch on
+    pub dex: Dex,                          // default for new shitcoins, has to be enabled
+    pub fee_recipients: Vec<FeeRecipient>, // split the create fee and the platform's presale fees
+    pub creator_fee_share: u32,            // bps of presale fees going to the creator
+    pub referral_fee_share: u32,           // bps of referred entry fees going to the referrer
     pub create_fee_denom: String,
-    pub create_fee: Uint128, // fixed
-    pub presale_denom: String,
-    pub presale_length: u64,
-    pub presale_fee_rate: u32, // bps
+    pub create_fee: Uint128,                      // fixed
+    pub presale_denoms: Vec<String>,              // accepted quote denoms, the first is the default
+    pub presale_length: u64,                      // default, seconds
+    pub min_presale_length: u64,                  // creator chosen lengths are bounded
+    pub max_presale_length: u64,                  // by these, inclusive
+    pub presale_fee_rate: u32,                    // bps
+    pub presale_withdraw_penalty_rate: u32,       // bps
+    pub min_contribution: Option<Uint128>,        // default per degen, after fees
+    pub max_contribution: Option<Uint128>,        // default per degen, after fees
+    pub min_lp_allocation: u32,                   // bps
+    pub max_creator_allocation: u32,              // bps
+    pub creator_vesting_cliff: u64,               // seconds after launch
+    pub creator_vesting_duration: u64,            // seconds after launch
+    pub denom_admin_burn_address: Option<String>, // defaults to the chain's all-zero address
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+/// How a shitcoin's supply is split at launch, in bps adding up to 10000.
+#[cw_serde]
+pub struct Allocation {
+    pub lp: u32,
+    pub presale: u32,
+    pub creator: u32,
+}
+
+/// A private round at the start of a presale, only open to the addresses in a Merkle tree.
+#[cw_serde]
+pub struct Allowlist {
+    pub root: HexBinary, // sha256, see the merkle module for how the tree is built
+    pub length: u64,     // seconds, has to end before the presale does
+}
+
+/// What happens to the LP tokens received when a shitcoin launches.
+#[cw_serde]
+pub enum LpPolicy {
+    Burn {},
+    Lock {},                    // held by the garden forever
+    TimeLock { duration: u64 }, // claimable by the creator this many seconds after launch
+}
+
+/// Where a platform fee recipient's share goes.
+#[cw_serde]
+pub enum FeeDestination {
+    Address { address: String },
+    Burn {},
+}
+
+/// A recipient of platform fees, paid in proportion to its weight among all recipients.
+#[cw_serde]
+pub struct FeeRecipient {
+    pub destination: FeeDestination,
+    pub weight: u32,
+}
+
+/// The DEX a shitcoin's liquidity is provided to at launch, through its configured pool factory.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Dex {
+    AstroportXyk {},
+    AstroportPcl {}, // the pool is only created at launch, priced at the presale price
+    Mock {},
+}
+
+/// A DEX shitcoins can pick, and the pool factory its adapter sends pool creations to.
+#[cw_serde]
+pub struct DexFactory {
+    pub dex: Dex,
+    pub address: String,
+}
+
+/// The subset of the cw20 interface used to burn or hand over LP tokens.
+#[cw_serde]
+pub enum Cw20ExecuteMsg {
+    Burn { amount: Uint128 },
+    Transfer { recipient: String, amount: Uint128 },
 }
 
 #[cw_serde]
@@ -18,9 +95,25 @@ pub enum ExecuteMsg {
         ticker: String,
         name: String,
         supply: Uint128,
+        soft_cap: Option<Uint128>, // presales ending below this can be refunded
+        hard_cap: Option<Uint128>, // presales stop accepting funds at this raise
+        presale_length: Option<u64>, // defaults to the configured presale length
+        allocation: Option<Allocation>, // defaults to half liquidity, half presale
+        lp_policy: Option<LpPolicy>, // defaults to locking the liquidity forever
+        dex: Option<Dex>,          // defaults to the configured dex, has to be enabled
+        presale_denom: Option<String>, // defaults to the first accepted presale denom
+        allowlist: Option<Allowlist>, // defaults to a public presale from the start
+        min_contribution: Option<Uint128>, // defaults to the configured minimum
+        max_contribution: Option<Uint128>, // defaults to the configured maximum
     },
     EnterPresale {
         denom: String,
+        referrer: Option<String>, // paid a share of the entry fee
+        allowlist_proof: Option<Vec<HexBinary>>, // required during the allowlist phase
+    },
+    WithdrawPresale {
+        denom: String,
+        amount: Uint128,
     },
     ExtendPresale {
         denom: String,
@@ -31,21 +124,112 @@ pub enum ExecuteMsg {
     ClaimShitcoin {
         denom: String,
     },
+    RefundPresale {
+        denom: String,
+    },
+    ClaimVested {
+        denom: String,
+    },
+    ClaimLp {
+        denom: String,
+    },
     SetUrl {
         denom: String,
         url: String,
     },
+    ModerateShitcoin {
+        denom: String,
+        moderation: Moderation,
+    },
+    UpdateConfig {
+        dex_factories: Option<Vec<DexFactory>>, // existing shitcoins keep their factory
+        dex: Option<Dex>,
+        fee_recipients: Option<Vec<FeeRecipient>>,
+        creator_fee_share: Option<u32>,
+        referral_fee_share: Option<u32>,
+        create_fee_denom: Option<String>,
+        create_fee: Option<Uint128>,
+        presale_denoms: Option<Vec<String>>,
+        presale_length: Option<u64>,
+        min_presale_length: Option<u64>,
+        max_presale_length: Option<u64>,
+        presale_fee_rate: Option<u32>,
+        presale_withdraw_penalty_rate: Option<u32>,
+        min_contribution: Option<Uint128>, // zero removes the default minimum
+        max_contribution: Option<Uint128>, // zero removes the default maximum
+        min_lp_allocation: Option<u32>,
+        max_creator_allocation: Option<u32>,
+        creator_vesting_cliff: Option<u64>,
+        creator_vesting_duration: Option<u64>,
+    },
+    ProposeAdmin {
+        admin: String,
+    },
+    AcceptAdmin {},
+    SetGuardian {
+        guardian: Option<String>, // none removes the guardian
+    },
+    Pause {
+        operation: Operation,
+        denom: Option<String>, // defaults to every shitcoin
+    },
+    Unpause {
+        operation: Operation,
+        denom: Option<String>, // defaults to every shitcoin
+    },
+    BackfillIndexes {
+        limit: Option<u64>, // submissions to index, keep calling until done is true
+    },
+}
+
+/// How the garden admin wants a shitcoin shown, for names and urls nobody should have to see.
+/// Queries still return moderated shitcoins with their moderation, it is up to UIs to leave them
+/// out, as the garden's own does.
+#[cw_serde]
+pub enum Moderation {
+    Visible {},
+    Hidden {},                   // otherwise untouched
+    Delisted { reason: String }, // hidden and closed to new presale entries
+}
+
+/// The operations the admin or guardian can pause, everywhere or for one shitcoin. Withdrawals and
+/// refunds cannot be paused, so degens can always get their funds back out.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Operation {
+    CreateShitcoin {}, // only everywhere
+    EnterPresale {},
+    ExtendPresale {},
+    LaunchShitcoin {},
+    ClaimShitcoin {},
+    SetUrl {},
 }
 
 #[cw_serde]
 pub struct Config {
-    pub pool_factory_address: String,
-    pub fee_recipient: String,
+    pub admin: String,
+    pub pending_admin: Option<String>,
+    pub guardian: Option<String>, // can pause operations alongside the admin
+    pub dex_factories: Vec<DexFactory>,
+    pub dex: Dex,
+    pub fee_recipients: Vec<FeeRecipient>,
+    pub creator_fee_share: u32,
+    pub referral_fee_share: u32,
     pub create_fee_denom: String,
     pub create_fee: Uint128,
-    pub presale_denom: String,
+    pub presale_denoms: Vec<String>,
     pub presale_length: u64,
+    pub min_presale_length: u64,
+    pub max_presale_length: u64,
     pub presale_fee_rate: u32,
+    pub presale_withdraw_penalty_rate: u32,
+    pub min_contribution: Option<Uint128>,
+    pub max_contribution: Option<Uint128>,
+    pub min_lp_allocation: u32,
+    pub max_creator_allocation: u32,
+    pub creator_vesting_cliff: u64,
+    pub creator_vesting_duration: u64,
+    pub denom_admin_burn_address: String, // denom admins are renounced to it at launch
 }
 
 #[cw_serde]
@@ -55,25 +239,141 @@ pub struct ShitcoinMetadata {
     pub ticker: String,
     pub name: String,
     pub url: String,
+    pub presale_denom: String, // what to send when entering the presale
+    pub presale_length: u64,
     pub presale_end: u64,
+    pub allowlist_root: Option<HexBinary>,
+    pub allowlist_end: Option<u64>, // the public presale starts here
     pub presale_raise: Uint128,
+    pub participants: u64, // degens with a submission left
     pub supply: Uint128,
     pub ended: bool,
     pub launched: bool,
+    pub soft_cap: Option<Uint128>,
+    pub hard_cap: Option<Uint128>,
+    pub min_contribution: Option<Uint128>, // per degen, after fees
+    pub max_contribution: Option<Uint128>, // per degen, after fees
+    pub allocation: Allocation,
+    pub lp_policy: LpPolicy,
+    pub dex: Dex,
+    pub pair: Option<String>,       // set once the pool is created
+    pub lp_token: Option<String>,   // set once the pool is created
+    pub lp_amount: Option<Uint128>, // set at launch
+    pub lp_unlocks_at: Option<u64>, // set at launch for time-locked liquidity
+    pub lp_claimed: bool,
+    pub admin_renounced: bool, // no more minting or metadata changes
+    pub moderation: Moderation,
 }
 
 #[cw_serde]
 pub struct DegenMetadata {
     pub presale_submission: Uint128,
     pub shitcoins_claimed: bool,
+    pub presale_refunded: bool,
+    pub min_contribution: Option<Uint128>,
+    pub max_contribution: Option<Uint128>,
+    pub remaining_contribution: Option<Uint128>, // after fees, none when nothing limits it
+}
+
+/// The shitcoins a degen's presale submission is worth, exactly as claiming would send them.
+#[cw_serde]
+pub struct Claimable {
+    pub denom: String,
+    pub degen: String,
+    pub amount: Uint128, // at the current raise until launched, zero once claimed or refunded
+    pub claimable: bool, // launched and not yet claimed
+}
+
+/// What launching a shitcoin puts in its pool, at the current raise until it is launched.
+#[cw_serde]
+pub struct LaunchSimulation {
+    pub denom: String,
+    pub lp_shitcoin_amount: Uint128,
+    pub lp_presale_amount: Uint128, // the whole raise
+    pub price: Decimal,             // presale denom per shitcoin
+    pub market_cap: Uint128,        // the supply at the initial price, in the presale denom
+}
+
+#[cw_serde]
+pub struct VestingStatus {
+    pub creator: String,
+    pub total: Uint128,
+    pub locked: Uint128,
+    pub released: Uint128,
+    pub claimed: Uint128,
+    pub start: Option<u64>, // set at launch
+    pub cliff: u64,
+    pub duration: u64,
+}
+
+#[cw_serde]
+pub struct ReferrerStats {
+    pub address: String,
+    pub referrals: u64,      // referred presale entries
+    pub earnings: Vec<Coin>, // one coin per presale denom earned in
+}
+
+#[cw_serde]
+pub struct PauseStatus {
+    pub paused: Vec<Operation>,       // everywhere
+    pub denom_paused: Vec<Operation>, // for the queried shitcoin only, empty without one
+}
+
+/// Where a shitcoin is in its life, as derived from its presale.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum ShitcoinStatus {
+    Active {},    // presale still running
+    Ended {},     // presale over, waiting to launch
+    Launched {},  // liquidity provided
+    Abandoned {}, // presale over and it can never launch, refunds are open
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum SortOrder {
+    Ascending {}, // oldest first
+    Descending {},
 }
 
 #[cw_serde]
 pub struct ShitcoinPage {
-    pub page: u64,
-    pub limit: u64,
-    pub total: u64,
+    pub total: u64, // every shitcoin the query covers, regardless of the status filter
     pub shitcoins: Vec<ShitcoinMetadata>,
+    pub next: Option<u64>, // start_after for the next page, none on the last page
+}
+
+/// A degen's stake in one presale.
+#[cw_serde]
+pub struct Position {
+    pub denom: String,
+    pub ticker: String,
+    pub presale_submission: Uint128,
+    pub shitcoins_claimed: bool,
+    pub presale_refunded: bool,
+    pub claimable: Uint128, // shitcoins, only once launched and until claimed
+}
+
+#[cw_serde]
+pub struct PositionPage {
+    pub total: u64, // every presale the degen has a submission in
+    pub positions: Vec<Position>,
+    pub next: Option<u64>, // start_after for the next page, none on the last page
+}
+
+/// A degen's stake in the presale being listed.
+#[cw_serde]
+pub struct Participant {
+    pub address: String,
+    pub presale_submission: Uint128,
+    pub shitcoins_claimed: bool,
+}
+
+#[cw_serde]
+pub struct ParticipantPage {
+    pub total: u64, // the same as the shitcoin's participants
+    pub participants: Vec<Participant>,
+    pub next: Option<u64>, // start_after for the next page, none on the last page
 }
 
 #[cw_serde]
@@ -83,11 +383,43 @@ pub enum QueryMsg {
     Config {},
     #[returns(ShitcoinMetadata)]
     ShitcoinMetadata { denom: String },
+    #[returns(ShitcoinMetadata)]
+    ShitcoinByTicker { ticker: String },
     #[returns(ShitcoinPage)]
     Shitcoins {
-        page: Option<u64>,
-        limit: Option<u64>,
+        start_after: Option<u64>, // index of the last shitcoin of the previous page
+        limit: Option<u64>,       // defaults to 10, at most 30
+        order: Option<SortOrder>, // defaults to ascending
+        status: Option<ShitcoinStatus>, // pages can come back short, keep going until no next
+    },
+    #[returns(ShitcoinPage)]
+    ShitcoinsByCreator {
+        creator: String,
+        start_after: Option<u64>, // oldest first, index among the creator's shitcoins
+        limit: Option<u64>,       // defaults to 10, at most 30
     },
     #[returns(DegenMetadata)]
     DegenMetadata { denom: String, degen: String },
+    #[returns(ParticipantPage)]
+    PresaleParticipants {
+        denom: String,
+        start_after: Option<u64>, // in order of entry, index among the participants
+        limit: Option<u64>,       // defaults to 10, at most 30
+    },
+    #[returns(Claimable)]
+    Claimable { denom: String, degen: String },
+    #[returns(LaunchSimulation)]
+    SimulateLaunch { denom: String },
+    #[returns(PositionPage)]
+    PositionsByDegen {
+        degen: String,
+        start_after: Option<u64>, // oldest first, index among the degen's presales
+        limit: Option<u64>,       // defaults to 10, at most 30
+    },
+    #[returns(VestingStatus)]
+    VestingStatus { denom: String },
+    #[returns(ReferrerStats)]
+    ReferrerStats { address: String },
+    #[returns(PauseStatus)]
+    PauseStatus { denom: Option<String> },
 }
@@ -1,10 +1,19 @@
-use cosmwasm_schema::write_api;
-use shitcoin_garden::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use cosmwasm_schema::{export_schema, schema_for, write_api};
+use shitcoin_garden::{
+    error::ErrorCode,
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
-        query: QueryMsg
+        query: QueryMsg,
+        migrate: MigrateMsg
     }
+
+    let mut out_dir = std::env::current_dir().unwrap();
+    out_dir.push("schema");
+
+    export_schema(&schema_for!(ErrorCode), &out_dir);
 }
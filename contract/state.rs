@@ -1,18 +1,63 @@
-use cosmwasm_std::{Storage, Uint128};
+use cosmwasm_std::{HexBinary, Order, Storage, Uint128};
+
+use crate::msg::{Allocation, Dex, DexFactory, FeeDestination, FeeRecipient, LpPolicy, Moderation};
+
+pub const CONTRACT_NAME: &str = "CONTRACT_NAME";
+pub const CONTRACT_VERSION: &str = "CONTRACT_VERSION";
+pub const INDEX_BACKFILL_END: &str = "INDEX_BACKFILL_END";
+pub const INDEX_BACKFILL_SHITCOIN: &str = "INDEX_BACKFILL_SHITCOIN";
+pub const INDEX_BACKFILL_DEGEN: &str = "INDEX_BACKFILL_DEGEN";
+
+pub const ADMIN: &str = "ADMIN";
+pub const PENDING_ADMIN: &str = "PENDING_ADMIN";
+pub const GUARDIAN: &str = "GUARDIAN";
+pub const PAUSED: &str = "PAUSED";
 
 pub const POOL_FACTORY: &str = "POOL_FACTORY";
+pub const DEX: &str = "DEX";
+pub const DEX_FACTORY_COUNT: &str = "DEX_FACTORY_COUNT";
+pub const DEX_FACTORY_DEX: &str = "DEX_FACTORY_DEX";
+pub const DEX_FACTORY_ADDRESS: &str = "DEX_FACTORY_ADDRESS";
 pub const PLATFORM_FEE_RECIPIENT: &str = "PLATFORM_FEE_RECIPIENT";
+pub const FEE_RECIPIENT_COUNT: &str = "FEE_RECIPIENT_COUNT";
+pub const FEE_RECIPIENT_ADDRESS: &str = "FEE_RECIPIENT_ADDRESS";
+pub const FEE_RECIPIENT_WEIGHT: &str = "FEE_RECIPIENT_WEIGHT";
+pub const CREATOR_FEE_SHARE: &str = "CREATOR_FEE_SHARE";
+pub const REFERRAL_FEE_SHARE: &str = "REFERRAL_FEE_SHARE";
 
 pub const CREATE_FEE_DENOM: &str = "CREATE_FEE_DENOM";
 pub const CREATE_FEE: &str = "CREATE_FEE";
 
+pub const MIN_LP_ALLOCATION: &str = "MIN_LP_ALLOCATION";
+pub const MAX_CREATOR_ALLOCATION: &str = "MAX_CREATOR_ALLOCATION";
+
+pub const CREATOR_VESTING_CLIFF: &str = "CREATOR_VESTING_CLIFF";
+pub const CREATOR_VESTING_DURATION: &str = "CREATOR_VESTING_DURATION";
+
+pub const DENOM_ADMIN_BURN_ADDRESS: &str = "DENOM_ADMIN_BURN_ADDRESS";
+
 pub const PRESALE_DENOM: &str = "PRESALE_DENOM";
+pub const PRESALE_DENOMS: &str = "PRESALE_DENOMS";
+pub const PRESALE_DENOM_COUNT: &str = "PRESALE_DENOM_COUNT";
 pub const PRESALE_LENGTH: &str = "PRESALE_LENGTH";
+pub const MIN_PRESALE_LENGTH: &str = "MIN_PRESALE_LENGTH";
+pub const MAX_PRESALE_LENGTH: &str = "MAX_PRESALE_LENGTH";
 pub const PRESALE_FEE_RATE: &str = "PRESALE_FEE_RATE";
+pub const PRESALE_WITHDRAW_PENALTY_RATE: &str = "PRESALE_WITHDRAW_PENALTY_RATE";
+pub const MIN_CONTRIBUTION: &str = "MIN_CONTRIBUTION";
+pub const MAX_CONTRIBUTION: &str = "MAX_CONTRIBUTION";
 pub const PRESALE_END: &str = "PRESALE_END";
 pub const PRESALE_RAISE: &str = "PRESALE_RAISE";
 pub const PRESALE_SUBMISSION: &str = "PRESALE_SUBMISSION";
 pub const PRESALE_CLAIMED: &str = "PRESALE_CLAIMED";
+pub const PRESALE_REFUNDED: &str = "PRESALE_REFUNDED";
+pub const PRESALE_SOFT_CAP: &str = "PRESALE_SOFT_CAP";
+pub const PRESALE_HARD_CAP: &str = "PRESALE_HARD_CAP";
+pub const PRESALE_MIN_CONTRIBUTION: &str = "PRESALE_MIN_CONTRIBUTION";
+pub const PRESALE_MAX_CONTRIBUTION: &str = "PRESALE_MAX_CONTRIBUTION";
+pub const PRESALE_ABORTED: &str = "PRESALE_ABORTED";
+pub const PRESALE_ALLOWLIST_ROOT: &str = "PRESALE_ALLOWLIST_ROOT";
+pub const PRESALE_ALLOWLIST_END: &str = "PRESALE_ALLOWLIST_END";
 
 pub const SHITCOIN_COUNT: &str = "SHITCOIN_COUNT";
 pub const SHITCOIN_DENOM: &str = "SHITCOIN_DENOM";
@@ -20,8 +65,51 @@ pub const SHITCOIN_CREATOR: &str = "SHITCOIN_CREATOR";
 pub const SHITCOIN_TICKER: &str = "SHITCOIN_TICKER";
 pub const SHITCOIN_NAME: &str = "SHITCOIN_NAME";
 pub const SHITCOIN_URL: &str = "SHITCOIN_URL";
+pub const SHITCOIN_HIDDEN: &str = "SHITCOIN_HIDDEN";
+pub const SHITCOIN_DELIST_REASON: &str = "SHITCOIN_DELIST_REASON";
 pub const SHITCOIN_SUPPLY: &str = "SHITCOIN_SUPPLY";
 pub const SHITCOIN_LAUNCHED: &str = "SHITCOIN_LAUNCHED";
+pub const SHITCOIN_ADMIN_RENOUNCED: &str = "SHITCOIN_ADMIN_RENOUNCED";
+pub const SHITCOIN_LP_ALLOCATION: &str = "SHITCOIN_LP_ALLOCATION";
+pub const SHITCOIN_PRESALE_ALLOCATION: &str = "SHITCOIN_PRESALE_ALLOCATION";
+pub const SHITCOIN_CREATOR_ALLOCATION: &str = "SHITCOIN_CREATOR_ALLOCATION";
+
+pub const VESTING_START: &str = "VESTING_START";
+pub const VESTING_CLIFF: &str = "VESTING_CLIFF";
+pub const VESTING_DURATION: &str = "VESTING_DURATION";
+pub const VESTING_CLAIMED: &str = "VESTING_CLAIMED";
+
+pub const SHITCOIN_LP_POLICY: &str = "SHITCOIN_LP_POLICY";
+pub const SHITCOIN_LP_LOCK_DURATION: &str = "SHITCOIN_LP_LOCK_DURATION";
+pub const SHITCOIN_DEX: &str = "SHITCOIN_DEX";
+pub const SHITCOIN_POOL_FACTORY: &str = "SHITCOIN_POOL_FACTORY";
+pub const SHITCOIN_PAIR: &str = "SHITCOIN_PAIR";
+pub const SHITCOIN_LP_TOKEN: &str = "SHITCOIN_LP_TOKEN";
+pub const SHITCOIN_LP_AMOUNT: &str = "SHITCOIN_LP_AMOUNT";
+pub const SHITCOIN_LP_UNLOCK: &str = "SHITCOIN_LP_UNLOCK";
+pub const SHITCOIN_LP_CLAIMED: &str = "SHITCOIN_LP_CLAIMED";
+
+pub const PENDING_LAUNCH: &str = "PENDING_LAUNCH";
+pub const PENDING_PAIR: &str = "PENDING_PAIR";
+
+pub const TICKER_DENOM: &str = "TICKER_DENOM";
+
+pub const CREATOR_SHITCOIN_COUNT: &str = "CREATOR_SHITCOIN_COUNT";
+pub const CREATOR_SHITCOIN: &str = "CREATOR_SHITCOIN";
+pub const CREATOR_SHITCOIN_INDEX: &str = "CREATOR_SHITCOIN_INDEX";
+pub const DEGEN_PRESALE_COUNT: &str = "DEGEN_PRESALE_COUNT";
+pub const DEGEN_PRESALE: &str = "DEGEN_PRESALE";
+pub const DEGEN_PRESALE_INDEX: &str = "DEGEN_PRESALE_INDEX";
+pub const DEGEN_PRESALES: &str = "DEGEN_PRESALES";
+pub const PRESALE_PARTICIPANT_COUNT: &str = "PRESALE_PARTICIPANT_COUNT";
+pub const PRESALE_PARTICIPANT: &str = "PRESALE_PARTICIPANT";
+pub const PRESALE_PARTICIPANT_INDEX: &str = "PRESALE_PARTICIPANT_INDEX";
+pub const PRESALE_PARTICIPANTS: &str = "PRESALE_PARTICIPANTS";
+
+pub const REFERRER_REFERRALS: &str = "REFERRER_REFERRALS";
+pub const REFERRER_DENOM_COUNT: &str = "REFERRER_DENOM_COUNT";
+pub const REFERRER_DENOM: &str = "REFERRER_DENOM";
+pub const REFERRER_EARNINGS: &str = "REFERRER_EARNINGS";
 
 pub fn compose_key(parts: &[&dyn ToString]) -> String {
     let mut key: String = parts
@@ -106,20 +194,241 @@ pub fn get_bool(storage: &dyn Storage, key: &str) -> Option<bool> {
     })
 }
 
-pub fn set_pool_factory_address(storage: &mut dyn Storage, daddress: &str) {
-    set_string(storage, POOL_FACTORY, daddress)
+pub fn set_contract_name(storage: &mut dyn Storage, contract_name: &str) {
+    set_string(storage, CONTRACT_NAME, contract_name)
+}
+
+pub fn contract_name(storage: &dyn Storage) -> Option<String> {
+    get_string(storage, CONTRACT_NAME)
+}
+
+pub fn set_contract_version(storage: &mut dyn Storage, contract_version: &str) {
+    set_string(storage, CONTRACT_VERSION, contract_version)
+}
+
+pub fn contract_version(storage: &dyn Storage) -> Option<String> {
+    get_string(storage, CONTRACT_VERSION)
+}
+
+/// Has the index backfill walk the first `end` shitcoins, unless one is already under way.
+pub fn start_index_backfill(storage: &mut dyn Storage, end: u64) {
+    if index_backfill_end(storage).is_some() {
+        return;
+    }
+
+    set_u64(storage, INDEX_BACKFILL_END, end);
+    set_u64(storage, INDEX_BACKFILL_SHITCOIN, 0);
+}
+
+/// The number of shitcoins the pending index backfill walks, none once it is done.
+pub fn index_backfill_end(storage: &dyn Storage) -> Option<u64> {
+    get_u64(storage, INDEX_BACKFILL_END)
+}
+
+/// Where the index backfill picks up, the shitcoin index and the last degen done in it.
+pub fn set_index_backfill_cursor(storage: &mut dyn Storage, shitcoin: u64, degen: Option<&str>) {
+    set_u64(storage, INDEX_BACKFILL_SHITCOIN, shitcoin);
+
+    match degen {
+        Some(degen) => set_string(storage, INDEX_BACKFILL_DEGEN, degen),
+        None => storage.remove(INDEX_BACKFILL_DEGEN.as_bytes()),
+    }
 }
 
-pub fn pool_factory_address(storage: &dyn Storage) -> String {
-    get_string(storage, POOL_FACTORY).expect("set during init")
+pub fn index_backfill_cursor(storage: &dyn Storage) -> (u64, Option<String>) {
+    (
+        get_u64(storage, INDEX_BACKFILL_SHITCOIN).unwrap_or_default(),
+        get_string(storage, INDEX_BACKFILL_DEGEN),
+    )
+}
+
+pub fn finish_index_backfill(storage: &mut dyn Storage) {
+    storage.remove(INDEX_BACKFILL_END.as_bytes());
+    storage.remove(INDEX_BACKFILL_SHITCOIN.as_bytes());
+    storage.remove(INDEX_BACKFILL_DEGEN.as_bytes());
+}
+
+pub fn set_admin(storage: &mut dyn Storage, admin: &str) {
+    set_string(storage, ADMIN, admin)
+}
+
+pub fn admin(storage: &dyn Storage) -> String {
+    get_string(storage, ADMIN).expect("set during init")
+}
+
+pub fn set_pending_admin(storage: &mut dyn Storage, pending_admin: &str) {
+    set_string(storage, PENDING_ADMIN, pending_admin)
+}
+
+pub fn pending_admin(storage: &dyn Storage) -> Option<String> {
+    get_string(storage, PENDING_ADMIN)
+}
+
+pub fn clear_pending_admin(storage: &mut dyn Storage) {
+    storage.remove(PENDING_ADMIN.as_bytes())
+}
+
+pub fn set_guardian(storage: &mut dyn Storage, guardian: &str) {
+    set_string(storage, GUARDIAN, guardian)
+}
+
+pub fn guardian(storage: &dyn Storage) -> Option<String> {
+    get_string(storage, GUARDIAN)
+}
+
+pub fn clear_guardian(storage: &mut dyn Storage) {
+    storage.remove(GUARDIAN.as_bytes())
+}
+
+fn paused_key(operation: &str, denom: Option<&str>) -> String {
+    match denom {
+        Some(denom) => compose_key(&[&PAUSED, &operation, &denom]),
+        None => compose_key(&[&PAUSED, &operation]),
+    }
+}
+
+/// Pauses `operation` for `denom`, or for every shitcoin without one.
+pub fn set_paused(storage: &mut dyn Storage, operation: &str, denom: Option<&str>) {
+    set_bool(storage, &paused_key(operation, denom), true)
+}
+
+pub fn clear_paused(storage: &mut dyn Storage, operation: &str, denom: Option<&str>) {
+    storage.remove(paused_key(operation, denom).as_bytes())
+}
+
+pub fn paused(storage: &dyn Storage, operation: &str, denom: Option<&str>) -> bool {
+    get_bool(storage, &paused_key(operation, denom)).unwrap_or_default()
+}
+
+const DEX_ASTROPORT_XYK: &str = "astroport_xyk";
+const DEX_ASTROPORT_PCL: &str = "astroport_pcl";
+const DEX_MOCK: &str = "mock";
+
+pub fn dex_name(dex: Dex) -> &'static str {
+    match dex {
+        Dex::AstroportXyk {} => DEX_ASTROPORT_XYK,
+        Dex::AstroportPcl {} => DEX_ASTROPORT_PCL,
+        Dex::Mock {} => DEX_MOCK,
+    }
+}
+
+fn parse_dex(name: &str) -> Dex {
+    match name {
+        DEX_ASTROPORT_XYK => Dex::AstroportXyk {},
+        DEX_ASTROPORT_PCL => Dex::AstroportPcl {},
+        DEX_MOCK => Dex::Mock {},
+        name => panic!("unknown dex: {name}"),
+    }
+}
+
+pub fn set_dex(storage: &mut dyn Storage, dex: Dex) {
+    set_string(storage, DEX, dex_name(dex))
+}
+
+pub fn dex(storage: &dyn Storage) -> Dex {
+    parse_dex(&get_string(storage, DEX).expect("set during init"))
+}
+
+pub fn set_dex_factories(storage: &mut dyn Storage, dex_factories: &[DexFactory]) {
+    let count = dex_factories.len() as u64;
+
+    // drop any entries left over from a longer list
+    for idx in count..get_u64(storage, DEX_FACTORY_COUNT).unwrap_or_default() {
+        storage.remove(key![DEX_FACTORY_DEX, idx].as_bytes());
+        storage.remove(key![DEX_FACTORY_ADDRESS, idx].as_bytes());
+    }
+
+    for (idx, dex_factory) in dex_factories.iter().enumerate() {
+        set_string(
+            storage,
+            key![DEX_FACTORY_DEX, idx],
+            dex_name(dex_factory.dex),
+        );
+        set_string(
+            storage,
+            key![DEX_FACTORY_ADDRESS, idx],
+            &dex_factory.address,
+        );
+    }
+
+    set_u64(storage, DEX_FACTORY_COUNT, count)
+}
+
+pub fn dex_factories(storage: &dyn Storage) -> Vec<DexFactory> {
+    let count = get_u64(storage, DEX_FACTORY_COUNT).expect("set during init");
+
+    (0..count)
+        .map(|idx| DexFactory {
+            dex: parse_dex(&get_string(storage, key![DEX_FACTORY_DEX, idx]).expect("valid index")),
+            address: get_string(storage, key![DEX_FACTORY_ADDRESS, idx]).expect("valid index"),
+        })
+        .collect()
+}
+
+/// The pool factory of `dex`, unless the admin has not enabled it.
+pub fn dex_factory(storage: &dyn Storage, dex: Dex) -> Option<String> {
+    dex_factories(storage)
+        .into_iter()
+        .find(|dex_factory| dex_factory.dex == dex)
+        .map(|dex_factory| dex_factory.address)
+}
+
+pub fn set_fee_recipients(storage: &mut dyn Storage, fee_recipients: &[FeeRecipient]) {
+    let count = fee_recipients.len() as u64;
+
+    // drop any entries left over from a longer list
+    for idx in count..get_u64(storage, FEE_RECIPIENT_COUNT).unwrap_or_default() {
+        storage.remove(key![FEE_RECIPIENT_ADDRESS, idx].as_bytes());
+        storage.remove(key![FEE_RECIPIENT_WEIGHT, idx].as_bytes());
+    }
+
+    for (idx, fee_recipient) in fee_recipients.iter().enumerate() {
+        // recipients without an address burn their share
+        match &fee_recipient.destination {
+            FeeDestination::Address { address } => {
+                set_string(storage, key![FEE_RECIPIENT_ADDRESS, idx], address)
+            }
+            FeeDestination::Burn {} => storage.remove(key![FEE_RECIPIENT_ADDRESS, idx].as_bytes()),
+        }
+
+        set_u32(
+            storage,
+            key![FEE_RECIPIENT_WEIGHT, idx],
+            fee_recipient.weight,
+        );
+    }
+
+    set_u64(storage, FEE_RECIPIENT_COUNT, count)
+}
+
+pub fn fee_recipients(storage: &dyn Storage) -> Vec<FeeRecipient> {
+    let count = get_u64(storage, FEE_RECIPIENT_COUNT).expect("set during init");
+
+    (0..count)
+        .map(|idx| FeeRecipient {
+            destination: match get_string(storage, key![FEE_RECIPIENT_ADDRESS, idx]) {
+                Some(address) => FeeDestination::Address { address },
+                None => FeeDestination::Burn {},
+            },
+            weight: get_u32(storage, key![FEE_RECIPIENT_WEIGHT, idx]).expect("valid index"),
+        })
+        .collect()
 }
 
-pub fn set_platform_fee_recipient(storage: &mut dyn Storage, create_fee_recipient: &str) {
-    set_string(storage, PLATFORM_FEE_RECIPIENT, create_fee_recipient)
+pub fn set_creator_fee_share(storage: &mut dyn Storage, creator_fee_share: u32) {
+    set_u32(storage, CREATOR_FEE_SHARE, creator_fee_share)
 }
 
-pub fn platform_fee_recipient(storage: &dyn Storage) -> String {
-    get_string(storage, PLATFORM_FEE_RECIPIENT).expect("set during init")
+pub fn creator_fee_share(storage: &dyn Storage) -> u32 {
+    get_u32(storage, CREATOR_FEE_SHARE).expect("set during init")
+}
+
+pub fn set_referral_fee_share(storage: &mut dyn Storage, referral_fee_share: u32) {
+    set_u32(storage, REFERRAL_FEE_SHARE, referral_fee_share)
+}
+
+pub fn referral_fee_share(storage: &dyn Storage) -> u32 {
+    get_u32(storage, REFERRAL_FEE_SHARE).expect("set during init")
 }
 
 pub fn set_create_fee_denom(storage: &mut dyn Storage, create_fee_denom: &str) {
@@ -140,12 +449,75 @@ pub fn create_fee(storage: &dyn Storage) -> Uint128 {
         .into()
 }
 
-pub fn set_presale_denom(storage: &mut dyn Storage, presale_denom: &str) {
-    set_string(storage, PRESALE_DENOM, presale_denom)
+pub fn set_min_lp_allocation(storage: &mut dyn Storage, min_lp_allocation: u32) {
+    set_u32(storage, MIN_LP_ALLOCATION, min_lp_allocation)
+}
+
+pub fn min_lp_allocation(storage: &dyn Storage) -> u32 {
+    get_u32(storage, MIN_LP_ALLOCATION).expect("set during init")
 }
 
-pub fn presale_denom(storage: &dyn Storage) -> String {
-    get_string(storage, PRESALE_DENOM).expect("set during init")
+pub fn set_max_creator_allocation(storage: &mut dyn Storage, max_creator_allocation: u32) {
+    set_u32(storage, MAX_CREATOR_ALLOCATION, max_creator_allocation)
+}
+
+pub fn max_creator_allocation(storage: &dyn Storage) -> u32 {
+    get_u32(storage, MAX_CREATOR_ALLOCATION).expect("set during init")
+}
+
+pub fn set_creator_vesting_cliff(storage: &mut dyn Storage, cliff: u64) {
+    set_u64(storage, CREATOR_VESTING_CLIFF, cliff)
+}
+
+pub fn creator_vesting_cliff(storage: &dyn Storage) -> u64 {
+    get_u64(storage, CREATOR_VESTING_CLIFF).expect("set during init")
+}
+
+pub fn set_creator_vesting_duration(storage: &mut dyn Storage, duration: u64) {
+    set_u64(storage, CREATOR_VESTING_DURATION, duration)
+}
+
+pub fn creator_vesting_duration(storage: &dyn Storage) -> u64 {
+    get_u64(storage, CREATOR_VESTING_DURATION).expect("set during init")
+}
+
+pub fn set_denom_admin_burn_address(storage: &mut dyn Storage, address: &str) {
+    set_string(storage, DENOM_ADMIN_BURN_ADDRESS, address)
+}
+
+pub fn denom_admin_burn_address(storage: &dyn Storage) -> String {
+    get_string(storage, DENOM_ADMIN_BURN_ADDRESS).expect("set during init")
+}
+
+pub fn set_presale_denoms(storage: &mut dyn Storage, presale_denoms: &[String]) {
+    let count = presale_denoms.len() as u64;
+
+    // drop any entries left over from a longer allowlist
+    for idx in count..get_u64(storage, PRESALE_DENOM_COUNT).unwrap_or_default() {
+        storage.remove(key![PRESALE_DENOMS, idx].as_bytes());
+    }
+
+    for (idx, presale_denom) in presale_denoms.iter().enumerate() {
+        set_string(storage, key![PRESALE_DENOMS, idx], presale_denom);
+    }
+
+    set_u64(storage, PRESALE_DENOM_COUNT, count)
+}
+
+pub fn presale_denoms(storage: &dyn Storage) -> Vec<String> {
+    let count = get_u64(storage, PRESALE_DENOM_COUNT).expect("set during init");
+
+    (0..count)
+        .map(|idx| get_string(storage, key![PRESALE_DENOMS, idx]).expect("valid index"))
+        .collect()
+}
+
+pub fn set_shitcoin_presale_denom(storage: &mut dyn Storage, denom: &str, presale_denom: &str) {
+    set_string(storage, key![PRESALE_DENOM, denom], presale_denom)
+}
+
+pub fn shitcoin_presale_denom(storage: &dyn Storage, denom: &str) -> Option<String> {
+    get_string(storage, key![PRESALE_DENOM, denom])
 }
 
 pub fn set_presale_length(storage: &mut dyn Storage, presale_length: u64) {
@@ -156,6 +528,22 @@ pub fn presale_length(storage: &dyn Storage) -> u64 {
     get_u64(storage, PRESALE_LENGTH).expect("set during init")
 }
 
+pub fn set_min_presale_length(storage: &mut dyn Storage, min_presale_length: u64) {
+    set_u64(storage, MIN_PRESALE_LENGTH, min_presale_length)
+}
+
+pub fn min_presale_length(storage: &dyn Storage) -> u64 {
+    get_u64(storage, MIN_PRESALE_LENGTH).expect("set during init")
+}
+
+pub fn set_max_presale_length(storage: &mut dyn Storage, max_presale_length: u64) {
+    set_u64(storage, MAX_PRESALE_LENGTH, max_presale_length)
+}
+
+pub fn max_presale_length(storage: &dyn Storage) -> u64 {
+    get_u64(storage, MAX_PRESALE_LENGTH).expect("set during init")
+}
+
 pub fn set_presale_fee_rate(storage: &mut dyn Storage, presale_fee_rate: u32) {
     set_u32(storage, PRESALE_FEE_RATE, presale_fee_rate)
 }
@@ -164,6 +552,38 @@ pub fn presale_fee_rate(storage: &dyn Storage) -> u32 {
     get_u32(storage, PRESALE_FEE_RATE).expect("set during init")
 }
 
+pub fn set_presale_withdraw_penalty_rate(storage: &mut dyn Storage, penalty_rate: u32) {
+    set_u32(storage, PRESALE_WITHDRAW_PENALTY_RATE, penalty_rate)
+}
+
+pub fn presale_withdraw_penalty_rate(storage: &dyn Storage) -> u32 {
+    get_u32(storage, PRESALE_WITHDRAW_PENALTY_RATE).expect("set during init")
+}
+
+pub fn set_min_contribution(storage: &mut dyn Storage, min_contribution: Uint128) {
+    set_u128(storage, MIN_CONTRIBUTION, min_contribution.u128())
+}
+
+pub fn min_contribution(storage: &dyn Storage) -> Option<Uint128> {
+    get_u128(storage, MIN_CONTRIBUTION).map(Uint128::new)
+}
+
+pub fn clear_min_contribution(storage: &mut dyn Storage) {
+    storage.remove(MIN_CONTRIBUTION.as_bytes())
+}
+
+pub fn set_max_contribution(storage: &mut dyn Storage, max_contribution: Uint128) {
+    set_u128(storage, MAX_CONTRIBUTION, max_contribution.u128())
+}
+
+pub fn max_contribution(storage: &dyn Storage) -> Option<Uint128> {
+    get_u128(storage, MAX_CONTRIBUTION).map(Uint128::new)
+}
+
+pub fn clear_max_contribution(storage: &mut dyn Storage) {
+    storage.remove(MAX_CONTRIBUTION.as_bytes())
+}
+
 pub fn set_presale_end(storage: &mut dyn Storage, denom: &str, presale_end: u64) {
     set_u64(storage, key![PRESALE_END, denom], presale_end)
 }
@@ -172,6 +592,14 @@ pub fn presale_end(storage: &dyn Storage, denom: &str) -> Option<u64> {
     get_u64(storage, key![PRESALE_END, denom])
 }
 
+pub fn set_shitcoin_presale_length(storage: &mut dyn Storage, denom: &str, presale_length: u64) {
+    set_u64(storage, key![PRESALE_LENGTH, denom], presale_length)
+}
+
+pub fn shitcoin_presale_length(storage: &dyn Storage, denom: &str) -> Option<u64> {
+    get_u64(storage, key![PRESALE_LENGTH, denom])
+}
+
 pub fn set_presale_raise(storage: &mut dyn Storage, denom: &str, presale_raise: Uint128) {
     set_u128(storage, key![PRESALE_RAISE, denom], presale_raise.u128())
 }
@@ -197,6 +625,35 @@ pub fn presale_submission(storage: &dyn Storage, denom: &str, degen: &str) -> Op
     get_u128(storage, key![PRESALE_SUBMISSION, denom, degen]).map(Uint128::new)
 }
 
+/// Up to `limit` degens with a submission to the presale of `denom` after `start_after`, in address
+/// order, found by scanning the submission keys. Only meant for migrations, queries and
+/// transactions should use the degen index.
+pub fn presale_degens(
+    storage: &dyn Storage,
+    denom: &str,
+    start_after: Option<&str>,
+    limit: usize,
+) -> Vec<String> {
+    let prefix = format!("{PRESALE_SUBMISSION}:{denom}:");
+    let end = format!("{PRESALE_SUBMISSION}:{denom};"); // ';' sorts right after ':'
+
+    // a trailing nul sorts right after the degen itself
+    let start = match start_after {
+        Some(degen) => format!("{prefix}{degen}\0"),
+        None => prefix.clone(),
+    };
+
+    storage
+        .range(
+            Some(start.as_bytes()),
+            Some(end.as_bytes()),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|(key, _)| String::from_utf8(key[prefix.len()..].to_vec()).expect("valid utf-8"))
+        .collect()
+}
+
 pub fn set_presale_claimed(storage: &mut dyn Storage, denom: &str, degen: &str, claimed: bool) {
     set_bool(storage, key![PRESALE_CLAIMED, denom, degen], claimed)
 }
@@ -205,6 +662,71 @@ pub fn presale_claimed(storage: &dyn Storage, denom: &str, degen: &str) -> Optio
     get_bool(storage, key![PRESALE_CLAIMED, denom, degen])
 }
 
+pub fn set_presale_refunded(storage: &mut dyn Storage, denom: &str, degen: &str, refunded: bool) {
+    set_bool(storage, key![PRESALE_REFUNDED, denom, degen], refunded)
+}
+
+pub fn presale_refunded(storage: &dyn Storage, denom: &str, degen: &str) -> Option<bool> {
+    get_bool(storage, key![PRESALE_REFUNDED, denom, degen])
+}
+
+pub fn set_presale_aborted(storage: &mut dyn Storage, denom: &str, aborted: bool) {
+    set_bool(storage, key![PRESALE_ABORTED, denom], aborted)
+}
+
+pub fn presale_aborted(storage: &dyn Storage, denom: &str) -> Option<bool> {
+    get_bool(storage, key![PRESALE_ABORTED, denom])
+}
+
+pub fn set_presale_allowlist_root(storage: &mut dyn Storage, denom: &str, root: &HexBinary) {
+    set_string(storage, key![PRESALE_ALLOWLIST_ROOT, denom], &root.to_hex())
+}
+
+pub fn presale_allowlist_root(storage: &dyn Storage, denom: &str) -> Option<HexBinary> {
+    get_string(storage, key![PRESALE_ALLOWLIST_ROOT, denom])
+        .map(|root| HexBinary::from_hex(&root).expect("valid hex"))
+}
+
+pub fn set_presale_allowlist_end(storage: &mut dyn Storage, denom: &str, allowlist_end: u64) {
+    set_u64(storage, key![PRESALE_ALLOWLIST_END, denom], allowlist_end)
+}
+
+pub fn presale_allowlist_end(storage: &dyn Storage, denom: &str) -> Option<u64> {
+    get_u64(storage, key![PRESALE_ALLOWLIST_END, denom])
+}
+
+pub fn set_presale_soft_cap(storage: &mut dyn Storage, denom: &str, soft_cap: Uint128) {
+    set_u128(storage, key![PRESALE_SOFT_CAP, denom], soft_cap.u128())
+}
+
+pub fn presale_soft_cap(storage: &dyn Storage, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![PRESALE_SOFT_CAP, denom]).map(Uint128::new)
+}
+
+pub fn set_presale_hard_cap(storage: &mut dyn Storage, denom: &str, hard_cap: Uint128) {
+    set_u128(storage, key![PRESALE_HARD_CAP, denom], hard_cap.u128())
+}
+
+pub fn presale_hard_cap(storage: &dyn Storage, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![PRESALE_HARD_CAP, denom]).map(Uint128::new)
+}
+
+pub fn set_presale_min_contribution(storage: &mut dyn Storage, denom: &str, min: Uint128) {
+    set_u128(storage, key![PRESALE_MIN_CONTRIBUTION, denom], min.u128())
+}
+
+pub fn presale_min_contribution(storage: &dyn Storage, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![PRESALE_MIN_CONTRIBUTION, denom]).map(Uint128::new)
+}
+
+pub fn set_presale_max_contribution(storage: &mut dyn Storage, denom: &str, max: Uint128) {
+    set_u128(storage, key![PRESALE_MAX_CONTRIBUTION, denom], max.u128())
+}
+
+pub fn presale_max_contribution(storage: &dyn Storage, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![PRESALE_MAX_CONTRIBUTION, denom]).map(Uint128::new)
+}
+
 pub fn set_shitcoin_count(storage: &mut dyn Storage, count: u64) {
     set_u64(storage, SHITCOIN_COUNT, count)
 }
@@ -253,6 +775,32 @@ pub fn shitcoin_url(storage: &dyn Storage, denom: &str) -> Option<String> {
     get_string(storage, key![SHITCOIN_URL, denom])
 }
 
+/// Visible shitcoins have neither key, delisted ones only keep their reason.
+pub fn set_shitcoin_moderation(storage: &mut dyn Storage, denom: &str, moderation: &Moderation) {
+    storage.remove(key![SHITCOIN_HIDDEN, denom].as_bytes());
+    storage.remove(key![SHITCOIN_DELIST_REASON, denom].as_bytes());
+
+    match moderation {
+        Moderation::Visible {} => {}
+        Moderation::Hidden {} => set_bool(storage, key![SHITCOIN_HIDDEN, denom], true),
+        Moderation::Delisted { reason } => {
+            set_string(storage, key![SHITCOIN_DELIST_REASON, denom], reason)
+        }
+    }
+}
+
+pub fn shitcoin_moderation(storage: &dyn Storage, denom: &str) -> Moderation {
+    if let Some(reason) = get_string(storage, key![SHITCOIN_DELIST_REASON, denom]) {
+        return Moderation::Delisted { reason };
+    }
+
+    if get_bool(storage, key![SHITCOIN_HIDDEN, denom]).unwrap_or_default() {
+        return Moderation::Hidden {};
+    }
+
+    Moderation::Visible {}
+}
+
 pub fn set_shitcoin_supply(storage: &mut dyn Storage, denom: &str, shitcoin_supply: Uint128) {
     set_u128(
         storage,
@@ -272,3 +820,350 @@ pub fn set_shitcoin_launched(storage: &mut dyn Storage, denom: &str, launched: b
 pub fn shitcoin_launched(storage: &dyn Storage, denom: &str) -> Option<bool> {
     get_bool(storage, key![SHITCOIN_LAUNCHED, denom])
 }
+
+pub fn set_shitcoin_admin_renounced(storage: &mut dyn Storage, denom: &str, renounced: bool) {
+    set_bool(storage, key![SHITCOIN_ADMIN_RENOUNCED, denom], renounced)
+}
+
+pub fn shitcoin_admin_renounced(storage: &dyn Storage, denom: &str) -> Option<bool> {
+    get_bool(storage, key![SHITCOIN_ADMIN_RENOUNCED, denom])
+}
+
+pub fn set_shitcoin_allocation(storage: &mut dyn Storage, denom: &str, allocation: &Allocation) {
+    set_u32(storage, key![SHITCOIN_LP_ALLOCATION, denom], allocation.lp);
+    set_u32(
+        storage,
+        key![SHITCOIN_PRESALE_ALLOCATION, denom],
+        allocation.presale,
+    );
+    set_u32(
+        storage,
+        key![SHITCOIN_CREATOR_ALLOCATION, denom],
+        allocation.creator,
+    );
+}
+
+pub fn shitcoin_allocation(storage: &dyn Storage, denom: &str) -> Option<Allocation> {
+    Some(Allocation {
+        lp: get_u32(storage, key![SHITCOIN_LP_ALLOCATION, denom])?,
+        presale: get_u32(storage, key![SHITCOIN_PRESALE_ALLOCATION, denom])?,
+        creator: get_u32(storage, key![SHITCOIN_CREATOR_ALLOCATION, denom])?,
+    })
+}
+
+pub fn set_vesting_start(storage: &mut dyn Storage, denom: &str, start: u64) {
+    set_u64(storage, key![VESTING_START, denom], start)
+}
+
+pub fn vesting_start(storage: &dyn Storage, denom: &str) -> Option<u64> {
+    get_u64(storage, key![VESTING_START, denom])
+}
+
+pub fn set_vesting_cliff(storage: &mut dyn Storage, denom: &str, cliff: u64) {
+    set_u64(storage, key![VESTING_CLIFF, denom], cliff)
+}
+
+pub fn vesting_cliff(storage: &dyn Storage, denom: &str) -> Option<u64> {
+    get_u64(storage, key![VESTING_CLIFF, denom])
+}
+
+pub fn set_vesting_duration(storage: &mut dyn Storage, denom: &str, duration: u64) {
+    set_u64(storage, key![VESTING_DURATION, denom], duration)
+}
+
+pub fn vesting_duration(storage: &dyn Storage, denom: &str) -> Option<u64> {
+    get_u64(storage, key![VESTING_DURATION, denom])
+}
+
+pub fn set_vesting_claimed(storage: &mut dyn Storage, denom: &str, claimed: Uint128) {
+    set_u128(storage, key![VESTING_CLAIMED, denom], claimed.u128())
+}
+
+pub fn vesting_claimed(storage: &dyn Storage, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![VESTING_CLAIMED, denom]).map(Uint128::new)
+}
+
+const LP_BURN: &str = "burn";
+const LP_LOCK: &str = "lock";
+const LP_TIME_LOCK: &str = "time_lock";
+
+pub fn set_shitcoin_lp_policy(storage: &mut dyn Storage, denom: &str, lp_policy: &LpPolicy) {
+    let kind = match lp_policy {
+        LpPolicy::Burn {} => LP_BURN,
+        LpPolicy::Lock {} => LP_LOCK,
+        LpPolicy::TimeLock { duration } => {
+            set_u64(storage, key![SHITCOIN_LP_LOCK_DURATION, denom], *duration);
+            LP_TIME_LOCK
+        }
+    };
+
+    set_string(storage, key![SHITCOIN_LP_POLICY, denom], kind)
+}
+
+pub fn shitcoin_lp_policy(storage: &dyn Storage, denom: &str) -> Option<LpPolicy> {
+    let lp_policy = match get_string(storage, key![SHITCOIN_LP_POLICY, denom])?.as_str() {
+        LP_BURN => LpPolicy::Burn {},
+        LP_LOCK => LpPolicy::Lock {},
+        LP_TIME_LOCK => LpPolicy::TimeLock {
+            duration: get_u64(storage, key![SHITCOIN_LP_LOCK_DURATION, denom])
+                .expect("set with the policy"),
+        },
+        kind => panic!("unknown lp policy: {kind}"),
+    };
+
+    Some(lp_policy)
+}
+
+pub fn set_shitcoin_dex(storage: &mut dyn Storage, denom: &str, dex: Dex) {
+    set_string(storage, key![SHITCOIN_DEX, denom], dex_name(dex))
+}
+
+pub fn shitcoin_dex(storage: &dyn Storage, denom: &str) -> Option<Dex> {
+    get_string(storage, key![SHITCOIN_DEX, denom]).map(|name| parse_dex(&name))
+}
+
+pub fn set_shitcoin_pool_factory(storage: &mut dyn Storage, denom: &str, pool_factory: &str) {
+    set_string(storage, key![SHITCOIN_POOL_FACTORY, denom], pool_factory)
+}
+
+pub fn shitcoin_pool_factory(storage: &dyn Storage, denom: &str) -> Option<String> {
+    get_string(storage, key![SHITCOIN_POOL_FACTORY, denom])
+}
+
+pub fn set_shitcoin_pair(storage: &mut dyn Storage, denom: &str, pair: &str) {
+    set_string(storage, key![SHITCOIN_PAIR, denom], pair)
+}
+
+pub fn shitcoin_pair(storage: &dyn Storage, denom: &str) -> Option<String> {
+    get_string(storage, key![SHITCOIN_PAIR, denom])
+}
+
+pub fn set_shitcoin_lp_token(storage: &mut dyn Storage, denom: &str, lp_token: &str) {
+    set_string(storage, key![SHITCOIN_LP_TOKEN, denom], lp_token)
+}
+
+pub fn shitcoin_lp_token(storage: &dyn Storage, denom: &str) -> Option<String> {
+    get_string(storage, key![SHITCOIN_LP_TOKEN, denom])
+}
+
+pub fn set_shitcoin_lp_amount(storage: &mut dyn Storage, denom: &str, lp_amount: Uint128) {
+    set_u128(storage, key![SHITCOIN_LP_AMOUNT, denom], lp_amount.u128())
+}
+
+pub fn shitcoin_lp_amount(storage: &dyn Storage, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![SHITCOIN_LP_AMOUNT, denom]).map(Uint128::new)
+}
+
+pub fn set_shitcoin_lp_unlock(storage: &mut dyn Storage, denom: &str, unlocks_at: u64) {
+    set_u64(storage, key![SHITCOIN_LP_UNLOCK, denom], unlocks_at)
+}
+
+pub fn shitcoin_lp_unlock(storage: &dyn Storage, denom: &str) -> Option<u64> {
+    get_u64(storage, key![SHITCOIN_LP_UNLOCK, denom])
+}
+
+pub fn set_shitcoin_lp_claimed(storage: &mut dyn Storage, denom: &str, claimed: bool) {
+    set_bool(storage, key![SHITCOIN_LP_CLAIMED, denom], claimed)
+}
+
+pub fn shitcoin_lp_claimed(storage: &dyn Storage, denom: &str) -> Option<bool> {
+    get_bool(storage, key![SHITCOIN_LP_CLAIMED, denom])
+}
+
+pub fn set_pending_launch(storage: &mut dyn Storage, denom: &str) {
+    set_string(storage, PENDING_LAUNCH, denom)
+}
+
+pub fn pending_launch(storage: &dyn Storage) -> Option<String> {
+    get_string(storage, PENDING_LAUNCH)
+}
+
+pub fn clear_pending_launch(storage: &mut dyn Storage) {
+    storage.remove(PENDING_LAUNCH.as_bytes())
+}
+
+pub fn set_pending_pair(storage: &mut dyn Storage, denom: &str) {
+    set_string(storage, PENDING_PAIR, denom)
+}
+
+pub fn pending_pair(storage: &dyn Storage) -> Option<String> {
+    get_string(storage, PENDING_PAIR)
+}
+
+pub fn clear_pending_pair(storage: &mut dyn Storage) {
+    storage.remove(PENDING_PAIR.as_bytes())
+}
+
+pub fn set_ticker_denom(storage: &mut dyn Storage, subdenom: &str, denom: &str) {
+    set_string(storage, key![TICKER_DENOM, subdenom], denom)
+}
+
+pub fn ticker_denom(storage: &dyn Storage, subdenom: &str) -> Option<String> {
+    get_string(storage, key![TICKER_DENOM, subdenom])
+}
+
+pub fn set_referrer_referrals(storage: &mut dyn Storage, referrer: &str, referrals: u64) {
+    set_u64(storage, key![REFERRER_REFERRALS, referrer], referrals)
+}
+
+pub fn referrer_referrals(storage: &dyn Storage, referrer: &str) -> Option<u64> {
+    get_u64(storage, key![REFERRER_REFERRALS, referrer])
+}
+
+/// Adds to what a referrer has earned in `denom`, keeping track of every denom they earned in.
+pub fn add_referrer_earnings(
+    storage: &mut dyn Storage,
+    referrer: &str,
+    denom: &str,
+    amount: Uint128,
+) {
+    let earnings = referrer_earnings(storage, referrer, denom);
+
+    if earnings.is_none() {
+        let count = get_u64(storage, key![REFERRER_DENOM_COUNT, referrer]).unwrap_or_default();
+
+        set_string(storage, key![REFERRER_DENOM, referrer, count], denom);
+        set_u64(storage, key![REFERRER_DENOM_COUNT, referrer], count + 1);
+    }
+
+    set_u128(
+        storage,
+        key![REFERRER_EARNINGS, referrer, denom],
+        (earnings.unwrap_or_default() + amount).u128(),
+    )
+}
+
+pub fn referrer_earnings(storage: &dyn Storage, referrer: &str, denom: &str) -> Option<Uint128> {
+    get_u128(storage, key![REFERRER_EARNINGS, referrer, denom]).map(Uint128::new)
+}
+
+pub fn referrer_denoms(storage: &dyn Storage, referrer: &str) -> Vec<String> {
+    let count = get_u64(storage, key![REFERRER_DENOM_COUNT, referrer]).unwrap_or_default();
+
+    (0..count)
+        .map(|idx| get_string(storage, key![REFERRER_DENOM, referrer, idx]).expect("valid index"))
+        .collect()
+}
+
+/// Appends `denom` to the shitcoins created by `creator`, unless it is already there.
+pub fn add_creator_shitcoin(storage: &mut dyn Storage, creator: &str, denom: &str) {
+    if get_u64(storage, key![CREATOR_SHITCOIN_INDEX, creator, denom]).is_some() {
+        return;
+    }
+
+    let count = creator_shitcoin_count(storage, creator);
+
+    set_string(storage, key![CREATOR_SHITCOIN, creator, count], denom);
+    set_u64(storage, key![CREATOR_SHITCOIN_INDEX, creator, denom], count);
+    set_u64(storage, key![CREATOR_SHITCOIN_COUNT, creator], count + 1);
+}
+
+pub fn creator_shitcoin_count(storage: &dyn Storage, creator: &str) -> u64 {
+    get_u64(storage, key![CREATOR_SHITCOIN_COUNT, creator]).unwrap_or_default()
+}
+
+pub fn creator_shitcoin(storage: &dyn Storage, creator: &str, idx: u64) -> Option<String> {
+    get_string(storage, key![CREATOR_SHITCOIN, creator, idx])
+}
+
+/// Appends `denom` to the presales entered by `degen`, unless it is already there.
+pub fn add_degen_presale(storage: &mut dyn Storage, degen: &str, denom: &str) {
+    if get_u64(storage, key![DEGEN_PRESALE_INDEX, degen, denom]).is_some() {
+        return;
+    }
+
+    let count = degen_presale_count(storage, degen);
+
+    set_string(storage, key![DEGEN_PRESALE, degen, count], denom);
+    set_u64(storage, key![DEGEN_PRESALE_INDEX, degen, denom], count);
+    set_u64(storage, key![DEGEN_PRESALE_COUNT, degen], count + 1);
+    set_u64(
+        storage,
+        key![DEGEN_PRESALES, degen],
+        degen_presales(storage, degen) + 1,
+    );
+}
+
+/// Drops `denom` from the presales entered by `degen`, leaving a gap so the others keep their
+/// index. Entering again appends it anew.
+pub fn remove_degen_presale(storage: &mut dyn Storage, degen: &str, denom: &str) {
+    let Some(idx) = get_u64(storage, key![DEGEN_PRESALE_INDEX, degen, denom]) else {
+        return;
+    };
+
+    storage.remove(key![DEGEN_PRESALE, degen, idx].as_bytes());
+    storage.remove(key![DEGEN_PRESALE_INDEX, degen, denom].as_bytes());
+    set_u64(
+        storage,
+        key![DEGEN_PRESALES, degen],
+        degen_presales(storage, degen) - 1,
+    );
+}
+
+/// Every slot in the presales entered by `degen`, including the gaps left by removals.
+pub fn degen_presale_count(storage: &dyn Storage, degen: &str) -> u64 {
+    get_u64(storage, key![DEGEN_PRESALE_COUNT, degen]).unwrap_or_default()
+}
+
+/// The presales `degen` currently has a submission in.
+pub fn degen_presales(storage: &dyn Storage, degen: &str) -> u64 {
+    get_u64(storage, key![DEGEN_PRESALES, degen]).unwrap_or_default()
+}
+
+/// None for the gap a removed presale left behind.
+pub fn degen_presale(storage: &dyn Storage, degen: &str, idx: u64) -> Option<String> {
+    get_string(storage, key![DEGEN_PRESALE, degen, idx])
+}
+
+/// Appends `degen` to the participants of the presale of `denom`, unless it is already there.
+pub fn add_presale_participant(storage: &mut dyn Storage, denom: &str, degen: &str) {
+    if get_u64(storage, key![PRESALE_PARTICIPANT_INDEX, denom, degen]).is_some() {
+        return;
+    }
+
+    let count = presale_participant_count(storage, denom);
+
+    set_string(storage, key![PRESALE_PARTICIPANT, denom, count], degen);
+    set_u64(
+        storage,
+        key![PRESALE_PARTICIPANT_INDEX, denom, degen],
+        count,
+    );
+    set_u64(storage, key![PRESALE_PARTICIPANT_COUNT, denom], count + 1);
+    set_u64(
+        storage,
+        key![PRESALE_PARTICIPANTS, denom],
+        presale_participants(storage, denom) + 1,
+    );
+}
+
+/// Drops `degen` from the participants of the presale of `denom`, leaving a gap so the others
+/// keep their index. Entering again appends them anew.
+pub fn remove_presale_participant(storage: &mut dyn Storage, denom: &str, degen: &str) {
+    let Some(idx) = get_u64(storage, key![PRESALE_PARTICIPANT_INDEX, denom, degen]) else {
+        return;
+    };
+
+    storage.remove(key![PRESALE_PARTICIPANT, denom, idx].as_bytes());
+    storage.remove(key![PRESALE_PARTICIPANT_INDEX, denom, degen].as_bytes());
+    set_u64(
+        storage,
+        key![PRESALE_PARTICIPANTS, denom],
+        presale_participants(storage, denom) - 1,
+    );
+}
+
+/// Every slot in the participants of the presale, including the gaps left by removals.
+pub fn presale_participant_count(storage: &dyn Storage, denom: &str) -> u64 {
+    get_u64(storage, key![PRESALE_PARTICIPANT_COUNT, denom]).unwrap_or_default()
+}
+
+/// The degens currently in the presale, those with a submission left.
+pub fn presale_participants(storage: &dyn Storage, denom: &str) -> u64 {
+    get_u64(storage, key![PRESALE_PARTICIPANTS, denom]).unwrap_or_default()
+}
+
+/// None for the gap a removed participant left behind.
+pub fn presale_participant(storage: &dyn Storage, denom: &str, idx: u64) -> Option<String> {
+    get_string(storage, key![PRESALE_PARTICIPANT, denom, idx])
+}
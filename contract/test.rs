@@ -1,42 +1,61 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use anyhow::{bail, Error, Result};
 use astroport::{
     asset::{Asset, AssetInfo, PairInfo},
     factory::{ExecuteMsg as PoolFactoryMsg, PairType, QueryMsg as PoolFactoryQuery},
-    pair::ExecuteMsg as PairMsg,
+    pair::{ExecuteMsg as PairMsg, PoolResponse, QueryMsg as PairQuery},
 };
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
     coin, from_json,
     testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
-    to_json_binary, Addr, BankMsg, Coin, ContractResult, CosmosMsg, DenomUnit, Empty, OwnedDeps,
-    SystemResult, WasmMsg, WasmQuery,
+    to_json_binary, to_json_string, Addr, BankMsg, Binary, Coin, ContractResult, CosmosMsg,
+    DenomUnit, Empty, Event, OwnedDeps, Reply, ReplyOn, StdError, SubMsg, SubMsgResponse,
+    SubMsgResult, SystemResult, Uint128, WasmMsg, WasmQuery,
 };
 use neutron_sdk::bindings::msg::NeutronMsg;
 
 use crate::{
-    msg::{Config, ExecuteMsg, InstantiateMsg, QueryMsg, ShitcoinPage},
+    dex::mock::{ExecuteMsg as MockDexMsg, QueryMsg as MockDexQuery, ReservesResponse},
+    error::ContractError,
+    msg::{
+        Allocation, Allowlist, Config, Cw20ExecuteMsg, Dex, DexFactory, ExecuteMsg, FeeDestination,
+        FeeRecipient, InstantiateMsg, QueryMsg, ShitcoinMetadata, ShitcoinPage,
+    },
     Response,
 };
 
-use super::{denom, execute, instantiate, query};
+use super::{denom, execute, instantiate, query, reply, CREATE_PAIR_REPLY_ID};
 
 type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>;
 
 #[derive(Debug, PartialEq)]
-enum AstroportMsg {
+enum DexMsg {
     CreatePool {
         contract: String,
         asset_infos: Vec<AssetInfo>,
+        pair_type: Option<PairType>, // none for the mock dex
+        init_params: Option<Binary>,
     },
     SeedPool {
         contract: String,
         assets: Vec<Asset>,
     },
+    BurnLp {
+        contract: String,
+        amount: Uint128,
+    },
+    TransferLp {
+        contract: String,
+        recipient: String,
+        amount: Uint128,
+    },
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
 struct Token {
+    admin: String,
     supply: u128,
     description: String,
     denom_units: Vec<DenomUnit>,
@@ -46,11 +65,16 @@ struct Token {
     symbol: String,
 }
 
+/// Pool reserves keyed by pair address, shared with the querier so launches see real reserves.
+type Pools = Rc<RefCell<HashMap<String, PoolResponse>>>;
+
 #[derive(Debug, Default)]
 struct External {
     balances: HashMap<(String, String), u128>,
     tokens: HashMap<String, Token>,
-    astroport_msgs: Vec<AstroportMsg>,
+    burned: HashMap<String, u128>,
+    pools: Pools,
+    dex_msgs: Vec<DexMsg>,
 }
 
 struct Ctx {
@@ -69,20 +93,118 @@ impl std::fmt::Debug for Ctx {
     }
 }
 
+#[cw_serde]
+struct WasmContractInfo {
+    code_id: u64,
+    creator: String,
+    admin: Option<String>,
+    pinned: bool,
+    ibc_port: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ShitcoinParams {
+    soft_cap: Option<u128>,
+    hard_cap: Option<u128>,
+    presale_length: Option<u64>,
+    allocation: Option<Allocation>,
+    lp_policy: Option<LpPolicy>,
+    dex: Option<Dex>,
+    presale_denom: Option<String>,
+    allowlist: Option<Allowlist>,
+    min_contribution: Option<u128>,
+    max_contribution: Option<u128>,
+}
+
+impl ShitcoinParams {
+    fn into_msg(self, ticker: &str, name: &str, supply: u128) -> ExecuteMsg {
+        ExecuteMsg::CreateShitcoin {
+            ticker: ticker.to_owned(),
+            name: name.to_owned(),
+            supply: supply.into(),
+            soft_cap: self.soft_cap.map(Into::into),
+            hard_cap: self.hard_cap.map(Into::into),
+            presale_length: self.presale_length,
+            allocation: self.allocation,
+            lp_policy: self.lp_policy,
+            dex: self.dex,
+            presale_denom: self.presale_denom,
+            allowlist: self.allowlist,
+            min_contribution: self.min_contribution.map(Into::into),
+            max_contribution: self.max_contribution.map(Into::into),
+        }
+    }
+}
+
 fn pool_address(shitcoin_denom: &str, presale_denom: &str) -> String {
     format!("{shitcoin_denom}-{presale_denom}-pool")
 }
 
+fn lp_token_address(pool_address: &str) -> String {
+    format!("{pool_address}-lp")
+}
+
 fn initialized_contract_ctx() -> Result<Ctx> {
     let external = External::default();
 
+    let pools = external.pools.clone();
+
     let mut deps = mock_dependencies();
 
-    deps.querier.update_wasm(|query| {
-        let WasmQuery::Smart { msg, .. } = query else {
-            panic!("unexpected wasm query: {query:?}");
+    deps.querier.update_wasm(move |query| {
+        let (contract_addr, msg) = match query {
+            WasmQuery::Smart { contract_addr, msg } => (contract_addr, msg),
+
+            WasmQuery::ContractInfo { .. } => {
+                let contract_info = WasmContractInfo {
+                    code_id: 1,
+                    creator: "contract_deployer".to_owned(),
+                    admin: Some("wasm_admin".to_owned()),
+                    pinned: false,
+                    ibc_port: None,
+                };
+
+                let binary = to_json_binary(&contract_info).unwrap();
+
+                return SystemResult::Ok(ContractResult::Ok(binary));
+            }
+
+            _ => panic!("unexpected wasm query: {query:?}"),
         };
 
+        if let Ok(PairQuery::Pool {}) = from_json(msg) {
+            let pool = pools
+                .borrow()
+                .get(contract_addr)
+                .cloned()
+                .expect("pool exists");
+
+            let binary = to_json_binary(&pool).unwrap();
+
+            return SystemResult::Ok(ContractResult::Ok(binary));
+        }
+
+        if let Ok(MockDexQuery::Reserves {}) = from_json(msg) {
+            let pool = pools
+                .borrow()
+                .get(contract_addr)
+                .cloned()
+                .expect("pool exists");
+
+            let reserves = ReservesResponse {
+                reserves: pool
+                    .assets
+                    .into_iter()
+                    .map(|asset| coin(asset.amount.u128(), asset.info.to_string()))
+                    .collect(),
+                total_share: pool.total_share,
+            };
+
+            let binary = to_json_binary(&reserves).unwrap();
+
+            return SystemResult::Ok(ContractResult::Ok(binary));
+        }
+
         let Ok(PoolFactoryQuery::Pair { asset_infos }) = from_json(msg) else {
             panic!("unexpected wasm smart query: {msg}");
         };
@@ -100,8 +222,8 @@ fn initialized_contract_ctx() -> Result<Ctx> {
 
         let pair_info = PairInfo {
             asset_infos,
+            liquidity_token: Addr::unchecked(lp_token_address(&pool_address)),
             contract_addr: Addr::unchecked(pool_address),
-            liquidity_token: Addr::unchecked("LP token"),
             pair_type: PairType::Xyk {},
         };
 
@@ -113,13 +235,48 @@ fn initialized_contract_ctx() -> Result<Ctx> {
     let info = mock_info("contract_deployer", &[]);
 
     let config = Config {
-        pool_factory_address: "pool_factory".to_owned(),
-        fee_recipient: "fee_recipient".to_owned(),
+        admin: "contract_deployer".to_owned(),
+        pending_admin: None,
+        guardian: None,
+        dex_factories: vec![
+            DexFactory {
+                dex: Dex::AstroportXyk {},
+                address: "xyk_factory".to_owned(),
+            },
+            DexFactory {
+                dex: Dex::AstroportPcl {},
+                address: "pcl_factory".to_owned(),
+            },
+            DexFactory {
+                dex: Dex::Mock {},
+                address: "mock_factory".to_owned(),
+            },
+        ],
+        dex: Dex::AstroportXyk {},
+        fee_recipients: vec![FeeRecipient {
+            destination: FeeDestination::Address {
+                address: "fee_recipient".to_owned(),
+            },
+            weight: 1,
+        }],
+        creator_fee_share: 5_000,
+        referral_fee_share: 1_000,
         create_fee_denom: "untrn".to_owned(),
         create_fee: 1_000_000u128.into(),
-        presale_denom: "uatom".to_owned(),
+        presale_denoms: vec!["uatom".to_owned(), "uusdc".to_owned()],
         presale_length: 60 * 60 * 24 * 7,
+        min_presale_length: 60,
+        max_presale_length: 60 * 60 * 24 * 14,
         presale_fee_rate: 50,
+        presale_withdraw_penalty_rate: 1_000,
+        min_contribution: None,
+        max_contribution: None,
+        min_lp_allocation: 3_000,
+        max_creator_allocation: 1_000,
+        creator_vesting_cliff: 60 * 60 * 24 * 30,
+        creator_vesting_duration: 60 * 60 * 24 * 180,
+        // the mock api cannot humanize the all-zero address, so the one on neutron is given
+        denom_admin_burn_address: "neutron1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhufaa6".to_owned(),
     };
 
     instantiate(
@@ -127,13 +284,27 @@ fn initialized_contract_ctx() -> Result<Ctx> {
         mock_env(),
         info,
         InstantiateMsg {
-            pool_factory_address: config.pool_factory_address.clone(),
-            fee_recipient: config.fee_recipient.clone(),
+            admin: None,
+            dex_factories: config.dex_factories.clone(),
+            dex: config.dex,
+            fee_recipients: config.fee_recipients.clone(),
+            creator_fee_share: config.creator_fee_share,
+            referral_fee_share: config.referral_fee_share,
             create_fee_denom: config.create_fee_denom.clone(),
             create_fee: config.create_fee,
-            presale_denom: config.presale_denom.clone(),
+            presale_denoms: config.presale_denoms.clone(),
             presale_length: config.presale_length,
+            min_presale_length: config.min_presale_length,
+            max_presale_length: config.max_presale_length,
             presale_fee_rate: config.presale_fee_rate,
+            presale_withdraw_penalty_rate: config.presale_withdraw_penalty_rate,
+            min_contribution: config.min_contribution,
+            max_contribution: config.max_contribution,
+            min_lp_allocation: config.min_lp_allocation,
+            max_creator_allocation: config.max_creator_allocation,
+            creator_vesting_cliff: config.creator_vesting_cliff,
+            creator_vesting_duration: config.creator_vesting_duration,
+            denom_admin_burn_address: Some(config.denom_admin_burn_address.clone()),
         },
     )?;
 
@@ -144,7 +315,7 @@ fn initialized_contract_ctx() -> Result<Ctx> {
     })
 }
 
-impl TryFrom<WasmMsg> for AstroportMsg {
+impl TryFrom<WasmMsg> for DexMsg {
     type Error = Error;
 
     fn try_from(value: WasmMsg) -> Result<Self> {
@@ -157,28 +328,268 @@ impl TryFrom<WasmMsg> for AstroportMsg {
             bail!("invalid wasm msg type");
         };
 
-        if let Ok(PoolFactoryMsg::CreatePair { asset_infos, .. }) = from_json(&msg) {
-            return Ok(AstroportMsg::CreatePool {
+        if let Ok(PoolFactoryMsg::CreatePair {
+            pair_type,
+            asset_infos,
+            init_params,
+        }) = from_json(&msg)
+        {
+            return Ok(DexMsg::CreatePool {
                 contract: contract_addr,
                 asset_infos,
+                pair_type: Some(pair_type),
+                init_params,
             });
         }
 
         if let Ok(PairMsg::ProvideLiquidity { assets, .. }) = from_json(&msg) {
-            return Ok(AstroportMsg::SeedPool {
+            return Ok(DexMsg::SeedPool {
                 contract: contract_addr,
                 assets,
             });
         }
 
+        if let Ok(MockDexMsg::CreatePool { denoms }) = from_json(&msg) {
+            return Ok(DexMsg::CreatePool {
+                contract: contract_addr,
+                asset_infos: denoms.iter().map(AssetInfo::native).collect(),
+                pair_type: None,
+                init_params: None,
+            });
+        }
+
+        if let Ok(MockDexMsg::ProvideLiquidity {}) = from_json(&msg) {
+            return Ok(DexMsg::SeedPool {
+                contract: contract_addr,
+                assets: funds
+                    .into_iter()
+                    .map(|Coin { denom, amount }| Asset {
+                        info: AssetInfo::native(denom),
+                        amount,
+                    })
+                    .collect(),
+            });
+        }
+
+        if let Ok(Cw20ExecuteMsg::Burn { amount }) = from_json(&msg) {
+            return Ok(DexMsg::BurnLp {
+                contract: contract_addr,
+                amount,
+            });
+        }
+
+        if let Ok(Cw20ExecuteMsg::Transfer { recipient, amount }) = from_json(&msg) {
+            return Ok(DexMsg::TransferLp {
+                contract: contract_addr,
+                recipient,
+                amount,
+            });
+        }
+
         bail!("unexpected msg: {contract_addr} - {msg} - {funds:?}")
     }
 }
 
 impl Ctx {
-    fn handle_astroport_msg(&mut self, msg: WasmMsg) {
-        let astroport_msg = AstroportMsg::try_from(msg).expect("valid astroport msg");
-        self.external.astroport_msgs.push(astroport_msg);
+    /// The presale denom shitcoins get when their creator does not pick one.
+    fn presale_denom(&self) -> String {
+        self.config.presale_denoms[0].clone()
+    }
+
+    fn shitcoin_presale_denom(&self, denom: &str) -> String {
+        crate::state::shitcoin_presale_denom(&self.deps.storage, denom).expect("shitcoin exists")
+    }
+
+    /// Returns the events the dex would emit, the factory reports the new pair and LP token
+    /// addresses and pools mint an LP share equal to the shitcoins provided into an empty pool,
+    /// or proportional to the reserves otherwise.
+    fn handle_dex_msg(&mut self, msg: WasmMsg) -> Vec<Event> {
+        let dex_msg = DexMsg::try_from(msg).expect("valid dex msg");
+
+        let garden = mock_env().contract.address.to_string();
+
+        let mut events = vec![];
+
+        match &dex_msg {
+            DexMsg::CreatePool {
+                asset_infos,
+                pair_type,
+                ..
+            } => {
+                let [AssetInfo::NativeToken {
+                    denom: shitcoin_denom,
+                }, AssetInfo::NativeToken {
+                    denom: presale_denom,
+                }] = &asset_infos[..]
+                else {
+                    panic!("unexpected assets: {asset_infos:?}");
+                };
+
+                let pool_address = pool_address(shitcoin_denom, presale_denom);
+
+                let pool = PoolResponse {
+                    assets: asset_infos
+                        .iter()
+                        .map(|info| Asset {
+                            info: info.clone(),
+                            amount: Uint128::zero(),
+                        })
+                        .collect(),
+                    total_share: Uint128::zero(),
+                };
+
+                self.external
+                    .pools
+                    .borrow_mut()
+                    .insert(pool_address.clone(), pool);
+
+                if pair_type.is_none() {
+                    events.push(
+                        Event::new("wasm")
+                            .add_attribute("pool", &pool_address)
+                            .add_attribute("lp_token", lp_token_address(&pool_address)),
+                    );
+                } else {
+                    events.push(
+                        Event::new("wasm")
+                            .add_attribute("action", "register")
+                            .add_attribute("pair_contract_addr", &pool_address),
+                    );
+
+                    events.push(
+                        Event::new("wasm")
+                            .add_attribute("liquidity_token_addr", lp_token_address(&pool_address)),
+                    );
+                }
+            }
+
+            DexMsg::SeedPool { contract, assets } => {
+                let share = self.provide_liquidity(contract, assets);
+
+                *self
+                    .external
+                    .balances
+                    .entry((garden, lp_token_address(contract)))
+                    .or_default() += share.u128();
+
+                events.push(Event::new("wasm").add_attribute("share", share));
+            }
+
+            DexMsg::BurnLp { contract, amount } => {
+                *self
+                    .external
+                    .balances
+                    .get_mut(&(garden, contract.clone()))
+                    .unwrap() -= amount.u128();
+            }
+
+            DexMsg::TransferLp {
+                contract,
+                recipient,
+                amount,
+            } => {
+                *self
+                    .external
+                    .balances
+                    .get_mut(&(garden, contract.clone()))
+                    .unwrap() -= amount.u128();
+
+                *self
+                    .external
+                    .balances
+                    .entry((recipient.clone(), contract.clone()))
+                    .or_default() += amount.u128();
+            }
+        }
+
+        self.external.dex_msgs.push(dex_msg);
+
+        events
+    }
+
+    /// Adds the assets to the pool reserves, returning the LP share minted for them.
+    fn provide_liquidity(&mut self, pair: &str, assets: &[Asset]) -> Uint128 {
+        let mut pools = self.external.pools.borrow_mut();
+
+        let pool = pools.get_mut(pair).expect("pool exists");
+
+        let share = if pool.total_share.is_zero() {
+            assets[0].amount
+        } else {
+            assets
+                .iter()
+                .map(|asset| {
+                    let reserve = pool
+                        .assets
+                        .iter()
+                        .find(|reserve| reserve.info == asset.info)
+                        .expect("pool asset");
+
+                    asset
+                        .amount
+                        .multiply_ratio(pool.total_share, reserve.amount)
+                })
+                .min()
+                .unwrap()
+        };
+
+        for asset in assets {
+            pool.assets
+                .iter_mut()
+                .find(|reserve| reserve.info == asset.info)
+                .expect("pool asset")
+                .amount += asset.amount;
+        }
+
+        pool.total_share += share;
+
+        share
+    }
+
+    /// Somebody else provides liquidity to a shitcoin's pool ahead of its launch.
+    fn preseed_pool(&mut self, denom: &str, shitcoin_amount: u128, presale_amount: u128) {
+        let presale_denom = self.shitcoin_presale_denom(denom);
+
+        let pair = pool_address(denom, &presale_denom);
+
+        self.provide_liquidity(
+            &pair,
+            &[
+                Asset {
+                    info: AssetInfo::native(denom),
+                    amount: shitcoin_amount.into(),
+                },
+                Asset {
+                    info: AssetInfo::native(presale_denom),
+                    amount: presale_amount.into(),
+                },
+            ],
+        );
+    }
+
+    /// Somebody sends presale funds straight to a shitcoin's pool ahead of its launch, adding to
+    /// its reserves without minting an LP share.
+    fn donate_to_pool(&mut self, denom: &str, presale_amount: u128) {
+        let presale_denom = self.shitcoin_presale_denom(denom);
+
+        let pair = pool_address(denom, &presale_denom);
+
+        self.external
+            .pools
+            .borrow_mut()
+            .get_mut(&pair)
+            .expect("pool exists")
+            .assets
+            .iter_mut()
+            .find(|reserve| reserve.info == AssetInfo::native(&presale_denom))
+            .expect("pool asset")
+            .amount += Uint128::new(presale_amount);
+    }
+
+    fn pool(&self, denom: &str) -> PoolResponse {
+        let pair = pool_address(denom, &self.shitcoin_presale_denom(denom));
+
+        self.external.pools.borrow()[&pair].clone()
     }
 
     fn handle_ntrn_msg(&mut self, msg: NeutronMsg) {
@@ -186,11 +597,12 @@ impl Ctx {
             NeutronMsg::CreateDenom { subdenom } => {
                 let denom = denom(&mock_env(), &subdenom);
 
-                assert!(self
-                    .external
-                    .tokens
-                    .insert(denom, Token::default())
-                    .is_none());
+                let token = Token {
+                    admin: mock_env().contract.address.to_string(),
+                    ..Default::default()
+                };
+
+                assert!(self.external.tokens.insert(denom, token).is_none());
             }
 
             NeutronMsg::MintTokens {
@@ -198,10 +610,11 @@ impl Ctx {
                 amount,
                 mint_to_address,
             } => {
-                self.external
-                    .tokens
-                    .entry(denom.clone())
-                    .and_modify(|token| token.supply += amount.u128());
+                let token = self.external.tokens.get_mut(&denom).unwrap();
+
+                assert_eq!(token.admin, mock_env().contract.address.as_str());
+
+                token.supply += amount.u128();
 
                 *self
                     .external
@@ -219,24 +632,40 @@ impl Ctx {
                 symbol,
                 ..
             } => {
-                self.external
-                    .tokens
-                    .entry(base.clone())
-                    .and_modify(|token| {
-                        token.description = description;
-                        token.denom_units = denom_units;
-                        token.base = base;
-                        token.display = display;
-                        token.name = name;
-                        token.symbol = symbol;
-                    });
+                let token = self.external.tokens.get_mut(&base).unwrap();
+
+                assert_eq!(token.admin, mock_env().contract.address.as_str());
+
+                token.description = description;
+                token.denom_units = denom_units;
+                token.base = base;
+                token.display = display;
+                token.name = name;
+                token.symbol = symbol;
+            }
+
+            NeutronMsg::ChangeAdmin {
+                denom,
+                new_admin_address,
+            } => {
+                let token = self.external.tokens.get_mut(&denom).unwrap();
+
+                assert_eq!(token.admin, mock_env().contract.address.as_str());
+
+                // the tokenfactory parses the new admin as an address
+                assert!(
+                    !new_admin_address.is_empty(),
+                    "tokenfactory rejects an empty admin"
+                );
+
+                token.admin = new_admin_address;
             }
 
             _ => panic!("unexpected msg: {msg:?}"),
         }
     }
 
-    fn handle_cosmos_msg(&mut self, msg: CosmosMsg<NeutronMsg>) {
+    fn handle_cosmos_msg(&mut self, msg: CosmosMsg<NeutronMsg>) -> Vec<Event> {
         match msg {
             CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
                 let Coin { denom, amount } = amount.into_iter().next().unwrap();
@@ -246,25 +675,64 @@ impl Ctx {
                     .balances
                     .entry((to_address, denom))
                     .or_default() += amount.u128();
+
+                vec![]
+            }
+            CosmosMsg::Bank(BankMsg::Burn { amount }) => {
+                let Coin { denom, amount } = amount.into_iter().next().unwrap();
+
+                *self.external.burned.entry(denom).or_default() += amount.u128();
+
+                vec![]
+            }
+            CosmosMsg::Custom(ntrn_msg) => {
+                self.handle_ntrn_msg(ntrn_msg);
+
+                vec![]
             }
-            CosmosMsg::Custom(ntrn_msg) => self.handle_ntrn_msg(ntrn_msg),
-            CosmosMsg::Wasm(wasm_msg) => self.handle_astroport_msg(wasm_msg),
+            CosmosMsg::Wasm(wasm_msg) => self.handle_dex_msg(wasm_msg),
             _ => panic!("unexpected msg: {msg:?}"),
         }
     }
 
     fn handle_response(&mut self, response: Response) {
-        for msg in response.messages {
-            self.handle_cosmos_msg(msg.msg);
+        for SubMsg {
+            id, msg, reply_on, ..
+        } in response.messages
+        {
+            let events = self.handle_cosmos_msg(msg);
+
+            if let ReplyOn::Success | ReplyOn::Always = reply_on {
+                let reply_msg = Reply {
+                    id,
+                    result: SubMsgResult::Ok(SubMsgResponse { events, data: None }),
+                };
+
+                let response =
+                    reply(self.deps.as_mut(), mock_env(), reply_msg).expect("reply succeeds");
+
+                self.handle_response(response);
+            }
         }
     }
 
     fn create_shitcoin(
+        self,
+        creator: &str,
+        ticker: &str,
+        name: &str,
+        supply: u128,
+    ) -> Result<Self> {
+        self.create_shitcoin_with(creator, ticker, name, supply, ShitcoinParams::default())
+    }
+
+    fn create_shitcoin_with(
         mut self,
         creator: &str,
         ticker: &str,
         name: &str,
         supply: u128,
+        params: ShitcoinParams,
     ) -> Result<Self> {
         let response = execute(
             self.deps.as_mut(),
@@ -276,11 +744,7 @@ impl Ctx {
                     &self.config.create_fee_denom,
                 )],
             ),
-            ExecuteMsg::CreateShitcoin {
-                ticker: ticker.to_owned(),
-                name: name.to_owned(),
-                supply: supply.into(),
-            },
+            params.into_msg(ticker, name, supply),
         )?;
 
         self.handle_response(response);
@@ -288,13 +752,41 @@ impl Ctx {
         Ok(self)
     }
 
-    fn enter_presale(mut self, degen: &str, denom: &str, amount: u128) -> Result<Self> {
+    fn enter_presale(self, degen: &str, denom: &str, amount: u128) -> Result<Self> {
+        self.enter_presale_with(degen, denom, amount, None)
+    }
+
+    fn enter_presale_with(
+        mut self,
+        degen: &str,
+        denom: &str,
+        amount: u128,
+        referrer: Option<&str>,
+    ) -> Result<Self> {
         let response = execute(
             self.deps.as_mut(),
             mock_env(),
-            mock_info(degen, &[coin(amount, &self.config.presale_denom)]),
+            mock_info(degen, &[coin(amount, self.shitcoin_presale_denom(denom))]),
             ExecuteMsg::EnterPresale {
                 denom: denom.to_owned(),
+                referrer: referrer.map(str::to_owned),
+                allowlist_proof: None,
+            },
+        )?;
+
+        self.handle_response(response);
+
+        Ok(self)
+    }
+
+    fn withdraw_presale(mut self, degen: &str, denom: &str, amount: u128) -> Result<Self> {
+        let response = execute(
+            self.deps.as_mut(),
+            mock_env(),
+            mock_info(degen, &[]),
+            ExecuteMsg::WithdrawPresale {
+                denom: denom.to_owned(),
+                amount: amount.into(),
             },
         )?;
 
@@ -359,6 +851,67 @@ impl Ctx {
 
         Ok(self)
     }
+
+    fn refund_presale(mut self, degen: &str, denom: &str) -> Result<Self> {
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(self.config.presale_length + 1);
+
+        let response = execute(
+            self.deps.as_mut(),
+            env,
+            mock_info(degen, &[]),
+            ExecuteMsg::RefundPresale {
+                denom: denom.to_owned(),
+            },
+        )?;
+
+        self.handle_response(response);
+
+        Ok(self)
+    }
+}
+
+fn query_config(ctx: &Ctx) -> Result<Config> {
+    let query_response = query(ctx.deps.as_ref(), mock_env(), QueryMsg::Config {})?;
+
+    Ok(from_json(query_response)?)
+}
+
+fn query_shitcoin(ctx: &Ctx, denom: &str) -> Result<ShitcoinMetadata> {
+    let query_response = query(
+        ctx.deps.as_ref(),
+        mock_env(),
+        QueryMsg::ShitcoinMetadata {
+            denom: denom.to_owned(),
+        },
+    )?;
+
+    Ok(from_json(query_response)?)
+}
+
+fn tickers(page: &ShitcoinPage) -> Vec<String> {
+    page.shitcoins
+        .iter()
+        .map(|shitcoin| shitcoin.ticker.clone())
+        .collect()
+}
+
+fn balance(ctx: &Ctx, address: &str, denom: &str) -> u128 {
+    ctx.external
+        .balances
+        .get(&(address.to_owned(), denom.to_owned()))
+        .copied()
+        .unwrap_or_default()
+}
+
+const PRESALE_BUY_AMOUNT: u128 = 1_000_000_000;
+
+/// A MEME presale a degen has entered.
+fn presale_ctx() -> Result<Ctx> {
+    initialized_contract_ctx()?
+        .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+        .enter_presale("degen", &denom(&mock_env(), "meme"), PRESALE_BUY_AMOUNT)
 }
 
 #[test]
@@ -374,431 +927,3797 @@ fn initialize() -> Result<()> {
     Ok(())
 }
 
-mod create_shitcoin {
-    use crate::msg::ShitcoinPage;
-
+mod update_config {
     use super::*;
 
+    fn update_config_msg() -> ExecuteMsg {
+        ExecuteMsg::UpdateConfig {
+            dex_factories: None,
+            dex: None,
+            fee_recipients: None,
+            creator_fee_share: None,
+            referral_fee_share: None,
+            create_fee_denom: None,
+            create_fee: None,
+            presale_denoms: None,
+            presale_length: None,
+            min_presale_length: None,
+            max_presale_length: None,
+            presale_fee_rate: None,
+            presale_withdraw_penalty_rate: None,
+            min_contribution: None,
+            max_contribution: None,
+            min_lp_allocation: None,
+            max_creator_allocation: None,
+            creator_vesting_cliff: None,
+            creator_vesting_duration: None,
+        }
+    }
+
     #[test]
     fn happy_path() -> Result<()> {
-        let ctx = initialized_contract_ctx()?
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+        let mut ctx = initialized_contract_ctx()?;
 
-        let query_response = query(
-            ctx.deps.as_ref(),
+        execute(
+            ctx.deps.as_mut(),
             mock_env(),
-            QueryMsg::Shitcoins {
-                page: None,
-                limit: None,
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: Some(Dex::Mock {}),
+                fee_recipients: Some(vec![FeeRecipient {
+                    destination: FeeDestination::Burn {},
+                    weight: 1,
+                }]),
+                creator_fee_share: Some(2_500),
+                referral_fee_share: Some(2_000),
+                create_fee_denom: None,
+                create_fee: Some(42u128.into()),
+                presale_denoms: None,
+                presale_length: Some(60),
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
             },
         )?;
 
-        let page: ShitcoinPage = from_json(query_response)?;
+        let expected_config = Config {
+            dex: Dex::Mock {},
+            fee_recipients: vec![FeeRecipient {
+                destination: FeeDestination::Burn {},
+                weight: 1,
+            }],
+            creator_fee_share: 2_500,
+            referral_fee_share: 2_000,
+            create_fee: 42u128.into(),
+            presale_length: 60,
+            ..ctx.config.clone()
+        };
 
-        assert_eq!(page.shitcoins.len(), 1);
-        assert_eq!(page.total, 1);
-        assert_eq!(page.page, 0);
-        assert_eq!(page.limit, 1);
+        assert_eq!(query_config(&ctx)?, expected_config);
 
-        let shitcoin = page.shitcoins.into_iter().next().unwrap();
+        Ok(())
+    }
 
-        let denom = denom(&mock_env(), "meme");
+    #[test]
+    fn not_admin_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("griff", &[]),
+            update_config_msg(),
+        )
+        .unwrap_err();
 
-        assert_eq!(&shitcoin.denom, &denom);
-        assert_eq!(shitcoin.creator, "creator");
         assert_eq!(
-            shitcoin.presale_end,
-            mock_env().block.time.seconds() + ctx.config.presale_length
+            err.to_string(),
+            "[not_admin] only the garden admin can do that"
         );
-        assert_eq!(shitcoin.presale_raise.u128(), 0);
-        assert_eq!(shitcoin.supply.u128(), 1_000_000_000_000);
-        assert!(!shitcoin.launched);
+    }
 
-        let token = ctx.external.tokens.get(&denom).unwrap();
+    #[test]
+    fn invalid_presale_fee_rate_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: Some(100),
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )
+        .unwrap_err();
 
         assert_eq!(
-            token,
-            &Token {
-                supply: 1_000_000_000_000,
-                description: "shitcoin".to_owned(),
-                denom_units: vec![
-                    DenomUnit {
-                        denom: denom.clone(),
-                        exponent: 0,
-                        aliases: vec![],
-                    },
-                    DenomUnit {
-                        denom: "MEME".to_owned(),
-                        exponent: 6,
-                        aliases: vec![],
-                    },
-                ],
-                base: denom.clone(),
-                display: "MEME".to_owned(),
-                name: "memecoin".to_owned(),
-                symbol: "MEME".to_owned()
-            }
-        );
-
-        let shitcoin_garden_balance = ctx
-            .external
-            .balances
-            .get(&(mock_env().contract.address.to_string(), denom.clone()))
-            .unwrap();
-
-        assert_eq!(*shitcoin_garden_balance, 1_000_000_000_000);
-
-        assert_eq!(ctx.external.astroport_msgs.len(), 1);
-
-        assert_eq!(
-            ctx.external.astroport_msgs[0],
-            AstroportMsg::CreatePool {
-                contract: ctx.config.pool_factory_address,
-                asset_infos: vec![
-                    AssetInfo::native(denom),
-                    AssetInfo::native(ctx.config.presale_denom)
-                ]
-            }
+            err.to_string(),
+            "[invalid_presale_fee_rate] presale fee rate has to be less than 100 bps"
         );
-
-        Ok(())
     }
 
     #[test]
-    fn without_paying_correct_fee_amount_fails() {
+    fn zero_presale_length_fails() {
         let mut ctx = initialized_contract_ctx().unwrap();
 
         let err = execute(
             ctx.deps.as_mut(),
             mock_env(),
-            mock_info(
-                "creator",
-                &[coin(
-                    ctx.config.create_fee.u128() - 1,
-                    &ctx.config.create_fee_denom,
-                )],
-            ),
-            ExecuteMsg::CreateShitcoin {
-                ticker: "MEME".to_owned(),
-                name: "memecoin".to_owned(),
-                supply: 1_000u128.into(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: Some(0),
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
             },
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "you must pay 1000000 untrn to create a shitcoin"
+            "[invalid_presale_length] presale length has to be greater than zero"
         );
     }
 
     #[test]
-    fn without_paying_correct_fee_denom_fails() {
+    fn presale_length_outside_new_bounds_fails() {
         let mut ctx = initialized_contract_ctx().unwrap();
 
         let err = execute(
             ctx.deps.as_mut(),
             mock_env(),
-            mock_info("creator", &[coin(ctx.config.create_fee.u128(), "uatom")]),
-            ExecuteMsg::CreateShitcoin {
-                ticker: "MEME".to_owned(),
-                name: "memecoin".to_owned(),
-                supply: 1_000u128.into(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: Some(60 * 60 * 24 * 8),
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
             },
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "you must also send untrn to create a shitcoin"
+            "[presale_length_out_of_bounds] presale length must be between 691200 and 1209600 \
+             seconds"
         );
     }
 }
 
-mod enter_presale {
-    use crate::msg::{DegenMetadata, ShitcoinMetadata};
-    use crate::HUNDRED_PERCENT_BPS;
-
+mod admin_handover {
     use super::*;
 
     #[test]
     fn happy_path() -> Result<()> {
-        const PRESALE_BUY_AMOUNT: u128 = 1_000_000_000;
-        let denom = denom(&mock_env(), "meme");
-
-        let ctx = initialized_contract_ctx()?
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
-            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
-            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
-            .enter_presale("degen2", &denom, PRESALE_BUY_AMOUNT)?;
-
-        let single_fee =
-            (PRESALE_BUY_AMOUNT * ctx.config.presale_fee_rate as u128) / HUNDRED_PERCENT_BPS.u128();
+        let mut ctx = initialized_contract_ctx()?;
 
-        let query_response = query(
-            ctx.deps.as_ref(),
+        execute(
+            ctx.deps.as_mut(),
             mock_env(),
-            QueryMsg::ShitcoinMetadata {
-                denom: denom.clone(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::ProposeAdmin {
+                admin: "new_admin".to_owned(),
             },
         )?;
 
-        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
-
-        assert_eq!(
-            shitcoin.presale_raise.u128(),
-            (3 * PRESALE_BUY_AMOUNT) - (3 * single_fee)
-        );
-
-        let query_response = query(
-            ctx.deps.as_ref(),
-            mock_env(),
-            QueryMsg::DegenMetadata {
-                denom: denom.clone(),
-                degen: "degen1".to_owned(),
-            },
-        )?;
+        let config = query_config(&ctx)?;
 
-        let degen1: DegenMetadata = from_json(query_response)?;
+        assert_eq!(config.admin, "contract_deployer");
+        assert_eq!(config.pending_admin.as_deref(), Some("new_admin"));
 
-        let query_response = query(
-            ctx.deps.as_ref(),
+        execute(
+            ctx.deps.as_mut(),
             mock_env(),
-            QueryMsg::DegenMetadata {
-                denom: denom.clone(),
-                degen: "degen2".to_owned(),
-            },
+            mock_info("new_admin", &[]),
+            ExecuteMsg::AcceptAdmin {},
         )?;
 
-        let degen2: DegenMetadata = from_json(query_response)?;
-
-        assert_eq!(
-            degen1.presale_submission.u128(),
-            (2 * PRESALE_BUY_AMOUNT) - (2 * single_fee)
-        );
-        assert_eq!(
-            degen2.presale_submission.u128(),
-            PRESALE_BUY_AMOUNT - single_fee
-        );
-        assert!(!degen1.shitcoins_claimed);
-        assert!(!degen2.shitcoins_claimed);
-
-        let fee_recipient_balance = ctx
-            .external
-            .balances
-            .get(&(
-                ctx.config.fee_recipient.clone(),
-                ctx.config.presale_denom.clone(),
-            ))
-            .unwrap();
-
-        assert_eq!(*fee_recipient_balance, (single_fee * 3) / 2);
-
-        let creator_balance = ctx
-            .external
-            .balances
-            .get(&("creator".to_owned(), ctx.config.presale_denom.clone()))
-            .unwrap();
+        let config = query_config(&ctx)?;
 
-        assert_eq!(*creator_balance, (single_fee * 3) / 2);
+        assert_eq!(config.admin, "new_admin");
+        assert_eq!(config.pending_admin, None);
 
         Ok(())
     }
 
     #[test]
-    fn without_paying_correct_fee_denom_fails() {
-        let denom = denom(&mock_env(), "meme");
-
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap();
+    fn propose_not_admin_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
 
         let err = execute(
             ctx.deps.as_mut(),
             mock_env(),
-            mock_info("degen", &[coin(1_000_000, "untrn")]),
-            ExecuteMsg::EnterPresale {
-                denom: denom.to_owned(),
-            },
-        )
-        .unwrap_err();
-
-        assert_eq!(err.to_string(), "you must send uatom to enter the presale");
-    }
-
-    #[test]
-    fn presale_ended_fails() {
-        let denom = denom(&mock_env(), "meme");
-
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap();
-
-        let mut env = mock_env();
-
-        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
-
-        let err = execute(
-            ctx.deps.as_mut(),
-            env,
-            mock_info("degen", &[coin(1_000_000, &ctx.config.presale_denom)]),
-            ExecuteMsg::EnterPresale {
-                denom: denom.to_owned(),
+            mock_info("griff", &[]),
+            ExecuteMsg::ProposeAdmin {
+                admin: "griff".to_owned(),
             },
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "you're too late to enter this shitcoin's presale"
+            "[not_admin] only the garden admin can do that"
         );
     }
 
     #[test]
-    fn amount_too_small_for_fee_fails() {
-        let denom = denom(&mock_env(), "meme");
+    fn accept_not_pending_admin_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
 
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap();
+        execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::ProposeAdmin {
+                admin: "new_admin".to_owned(),
+            },
+        )
+        .unwrap();
 
         let err = execute(
             ctx.deps.as_mut(),
             mock_env(),
-            mock_info("degen", &[coin(99, &ctx.config.presale_denom)]),
-            ExecuteMsg::EnterPresale {
-                denom: denom.to_owned(),
-            },
+            mock_info("griff", &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
         .unwrap_err();
 
-        assert_eq!(err.to_string(), "bag too smol");
+        assert_eq!(
+            err.to_string(),
+            "[not_pending_admin] you have not been proposed as the garden admin"
+        );
     }
 }
 
-mod extend_presale {
-    use crate::msg::ShitcoinMetadata;
+mod migrate {
+    use cosmwasm_std::Storage;
+
+    use crate::{migrate::parse_version, msg::MigrateMsg, state, CONTRACT_VERSION};
 
     use super::*;
 
-    #[test]
-    fn happy_path() -> Result<()> {
-        let denom = denom(&mock_env(), "meme");
+    fn migrate(ctx: &mut Ctx) -> Result<Response> {
+        let response = crate::migrate(ctx.deps.as_mut(), mock_env(), MigrateMsg {})?;
 
-        let ctx = initialized_contract_ctx()?
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
-            .extend_presale(&denom)?;
+        Ok(response)
+    }
 
-        let query_response = query(
-            ctx.deps.as_ref(),
+    /// Returns whether the backfill is done.
+    fn backfill_indexes(ctx: &mut Ctx, sender: &str, limit: u64) -> Result<bool> {
+        let response = execute(
+            ctx.deps.as_mut(),
             mock_env(),
-            QueryMsg::ShitcoinMetadata {
-                denom: denom.clone(),
-            },
+            mock_info(sender, &[]),
+            ExecuteMsg::BackfillIndexes { limit: Some(limit) },
         )?;
 
-        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+        let done = response.events[0]
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "done")
+            .expect("done attribute");
+
+        Ok(done.value == "true")
+    }
+
+    #[test]
+    fn instantiate_stores_contract_version() -> Result<()> {
+        let ctx = initialized_contract_ctx()?;
 
         assert_eq!(
-            shitcoin.presale_end,
-            mock_env().block.time.seconds() + (2 * ctx.config.presale_length) + 1
+            state::contract_name(&ctx.deps.storage).as_deref(),
+            Some("shitcoin-garden")
+        );
+        assert_eq!(
+            state::contract_version(&ctx.deps.storage).as_deref(),
+            Some(CONTRACT_VERSION)
         );
 
         Ok(())
     }
 
     #[test]
-    fn presale_ongoing_fails() {
-        let denom = denom(&mock_env(), "meme");
+    fn same_version_is_a_no_op() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?;
 
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap();
+        migrate(&mut ctx)?;
 
-        let mut env = mock_env();
+        assert_eq!(query_config(&ctx)?, ctx.config);
 
-        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length - 1);
+        Ok(())
+    }
 
-        let err = execute(
-            ctx.deps.as_mut(),
-            env,
-            mock_info("extendoor", &[]),
-            ExecuteMsg::ExtendPresale {
-                denom: denom.to_owned(),
-            },
-        )
-        .unwrap_err();
+    #[test]
+    fn unversioned_deployment_is_migrated() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom(&mock_env(), "meme"), 1_000)?;
+
+        ctx.deps.storage.remove(state::CONTRACT_NAME.as_bytes());
+        ctx.deps.storage.remove(state::CONTRACT_VERSION.as_bytes());
+        ctx.deps.storage.remove(state::ADMIN.as_bytes());
+        ctx.deps.storage.remove(b"TICKER_DENOM:meme");
+        ctx.deps
+            .storage
+            .remove(state::MIN_PRESALE_LENGTH.as_bytes());
+        ctx.deps
+            .storage
+            .remove(state::MAX_PRESALE_LENGTH.as_bytes());
+        ctx.deps
+            .storage
+            .remove(format!("PRESALE_LENGTH:{}", denom(&mock_env(), "meme")).as_bytes());
+        ctx.deps.storage.remove(
+            format!("SHITCOIN_CREATOR_ALLOCATION:{}", denom(&mock_env(), "meme")).as_bytes(),
+        );
+        ctx.deps
+            .storage
+            .remove(format!("PRESALE_DENOM:{}", denom(&mock_env(), "meme")).as_bytes());
+        ctx.deps.storage.remove(b"PRESALE_DENOMS:1");
+        ctx.deps.storage.remove(b"PRESALE_DENOMS:0");
+        ctx.deps
+            .storage
+            .remove(state::PRESALE_DENOM_COUNT.as_bytes());
+        ctx.deps
+            .storage
+            .set(state::PRESALE_DENOM.as_bytes(), b"uatom");
+        ctx.deps.storage.remove(b"FEE_RECIPIENT_ADDRESS:0");
+        ctx.deps.storage.remove(b"FEE_RECIPIENT_WEIGHT:0");
+        ctx.deps
+            .storage
+            .remove(state::FEE_RECIPIENT_COUNT.as_bytes());
+        ctx.deps.storage.remove(state::CREATOR_FEE_SHARE.as_bytes());
+        ctx.deps
+            .storage
+            .remove(state::REFERRAL_FEE_SHARE.as_bytes());
+        ctx.deps
+            .storage
+            .set(state::PLATFORM_FEE_RECIPIENT.as_bytes(), b"fee_recipient");
+        ctx.deps.storage.remove(b"CREATOR_SHITCOIN_COUNT:creator");
+        ctx.deps.storage.remove(b"CREATOR_SHITCOIN:creator:0");
+        ctx.deps.storage.remove(
+            format!(
+                "CREATOR_SHITCOIN_INDEX:creator:{}",
+                denom(&mock_env(), "meme")
+            )
+            .as_bytes(),
+        );
+        ctx.deps.storage.remove(b"DEGEN_PRESALE_COUNT:degen");
+        ctx.deps.storage.remove(b"DEGEN_PRESALES:degen");
+        ctx.deps.storage.remove(b"DEGEN_PRESALE:degen:0");
+        ctx.deps
+            .storage
+            .remove(format!("DEGEN_PRESALE_INDEX:degen:{}", denom(&mock_env(), "meme")).as_bytes());
+        ctx.deps
+            .storage
+            .remove(format!("PRESALE_PARTICIPANT_COUNT:{}", denom(&mock_env(), "meme")).as_bytes());
+        ctx.deps
+            .storage
+            .remove(format!("PRESALE_PARTICIPANTS:{}", denom(&mock_env(), "meme")).as_bytes());
+        ctx.deps
+            .storage
+            .remove(format!("PRESALE_PARTICIPANT:{}:0", denom(&mock_env(), "meme")).as_bytes());
+        ctx.deps.storage.remove(
+            format!(
+                "PRESALE_PARTICIPANT_INDEX:{}:degen",
+                denom(&mock_env(), "meme")
+            )
+            .as_bytes(),
+        );
+        for idx in 0..3 {
+            ctx.deps
+                .storage
+                .remove(format!("DEX_FACTORY_DEX:{idx}").as_bytes());
+            ctx.deps
+                .storage
+                .remove(format!("DEX_FACTORY_ADDRESS:{idx}").as_bytes());
+        }
+        ctx.deps.storage.remove(state::DEX_FACTORY_COUNT.as_bytes());
+        ctx.deps
+            .storage
+            .set(state::POOL_FACTORY.as_bytes(), b"xyk_factory");
+        ctx.deps
+            .storage
+            .remove(format!("SHITCOIN_POOL_FACTORY:{}", denom(&mock_env(), "meme")).as_bytes());
+
+        let response = migrate(&mut ctx)?;
 
         assert_eq!(
-            err.to_string(),
-            "patience young grasshopper the presale is not over"
+            state::contract_version(&ctx.deps.storage).as_deref(),
+            Some(CONTRACT_VERSION)
         );
-    }
 
-    #[test]
-    fn non_zero_raise_fails() {
-        let denom = denom(&mock_env(), "meme");
+        let migrations = response.events[0]
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "migrations")
+            .expect("migrations attribute");
 
-        let err = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen1", &denom, 1_000_000)
-            .unwrap()
-            .extend_presale(&denom)
-            .unwrap_err();
+        assert_eq!(
+            migrations.value,
+            "admin,ticker_index,withdraw_penalty,presale_lengths,allocations,creator_vesting,\
+             lp_policies,denom_admin_burn_address,dexes,presale_denoms,fee_recipients,\
+             referral_fee_share,shitcoin_indexes,participant_index"
+        );
 
-        assert_eq!(err.to_string(), "shitcoin is primed and ready for launch");
-    }
-}
+        assert_eq!(query_config(&ctx)?.admin, "wasm_admin");
 
-mod launch_shitcoin {
-    use cosmwasm_std::Uint128;
+        // submissions are only indexed by the backfill
+        assert_eq!(state::degen_presales(&ctx.deps.storage, "degen"), 0);
 
-    use crate::msg::ShitcoinMetadata;
+        assert!(backfill_indexes(&mut ctx, "wasm_admin", 10)?);
 
-    use super::*;
+        assert_eq!(
+            state::ticker_denom(&ctx.deps.storage, "meme"),
+            Some(denom(&mock_env(), "meme"))
+        );
 
-    #[test]
-    fn happy_path() -> Result<()> {
-        let denom = denom(&mock_env(), "meme");
+        let config = query_config(&ctx)?;
 
-        let ctx = initialized_contract_ctx()?
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
-            .enter_presale("degen", &denom, 1_000_000_000)?
-            .launch_shitcoin(&denom)?;
+        assert_eq!(config.min_presale_length, 60 * 60);
+        assert_eq!(config.max_presale_length, ctx.config.presale_length);
+        assert_eq!(config.presale_denoms, vec!["uatom".to_owned()]);
+        assert_eq!(config.fee_recipients, ctx.config.fee_recipients);
+        assert_eq!(config.creator_fee_share, 5_000);
+        assert_eq!(config.referral_fee_share, 0);
+        assert_eq!(
+            config.dex_factories,
+            vec![DexFactory {
+                dex: Dex::AstroportXyk {},
+                address: "xyk_factory".to_owned(),
+            }]
+        );
 
-        let query_response = query(
-            ctx.deps.as_ref(),
-            mock_env(),
-            QueryMsg::ShitcoinMetadata {
-                denom: denom.clone(),
-            },
-        )?;
+        assert!(ctx
+            .deps
+            .storage
+            .get(state::POOL_FACTORY.as_bytes())
+            .is_none());
 
-        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+        assert!(ctx
+            .deps
+            .storage
+            .get(state::PRESALE_DENOM.as_bytes())
+            .is_none());
 
-        assert!(shitcoin.launched);
+        assert!(ctx
+            .deps
+            .storage
+            .get(state::PLATFORM_FEE_RECIPIENT.as_bytes())
+            .is_none());
 
-        assert_eq!(ctx.external.astroport_msgs.len(), 2);
+        assert_eq!(
+            state::shitcoin_presale_denom(&ctx.deps.storage, &denom(&mock_env(), "meme")),
+            Some("uatom".to_owned())
+        );
 
         assert_eq!(
-            ctx.external.astroport_msgs[1],
-            AstroportMsg::SeedPool {
-                contract: pool_address(&denom, &ctx.config.presale_denom),
-                assets: vec![
-                    Asset {
-                        info: AssetInfo::native(&denom),
-                        amount: shitcoin.supply / Uint128::new(2)
-                    },
-                    Asset {
-                        info: AssetInfo::native(ctx.config.presale_denom),
-                        amount: shitcoin.presale_raise
-                    }
+            state::shitcoin_pool_factory(&ctx.deps.storage, &denom(&mock_env(), "meme")),
+            Some("xyk_factory".to_owned())
+        );
+
+        assert_eq!(
+            state::shitcoin_presale_length(&ctx.deps.storage, &denom(&mock_env(), "meme")),
+            Some(ctx.config.presale_length)
+        );
+
+        assert_eq!(
+            state::shitcoin_allocation(&ctx.deps.storage, &denom(&mock_env(), "meme")),
+            Some(Allocation {
+                lp: 5_000,
+                presale: 5_000,
+                creator: 0,
+            })
+        );
+
+        assert_eq!(
+            state::creator_shitcoin_count(&ctx.deps.storage, "creator"),
+            1
+        );
+        assert_eq!(
+            state::creator_shitcoin(&ctx.deps.storage, "creator", 0),
+            Some(denom(&mock_env(), "meme"))
+        );
+        assert_eq!(state::degen_presale_count(&ctx.deps.storage, "degen"), 1);
+        assert_eq!(state::degen_presales(&ctx.deps.storage, "degen"), 1);
+        assert_eq!(
+            state::degen_presale(&ctx.deps.storage, "degen", 0),
+            Some(denom(&mock_env(), "meme"))
+        );
+        assert_eq!(
+            state::presale_participant(&ctx.deps.storage, &denom(&mock_env(), "meme"), 0),
+            Some("degen".to_owned())
+        );
+        assert_eq!(
+            state::presale_participant_count(&ctx.deps.storage, &denom(&mock_env(), "meme")),
+            1
+        );
+        assert_eq!(
+            state::presale_participants(&ctx.deps.storage, &denom(&mock_env(), "meme")),
+            1
+        );
+
+        Ok(())
+    }
+
+    /// Drops the indexes built from submissions, as if the presales predated them.
+    fn forget_submission_indexes(ctx: &mut Ctx) {
+        let keys = ctx
+            .deps
+            .storage
+            .range(None, None, cosmwasm_std::Order::Ascending)
+            .map(|(key, _)| key)
+            .filter(|key| {
+                key.starts_with(b"DEGEN_PRESALE") || key.starts_with(b"PRESALE_PARTICIPANT")
+            })
+            .collect::<Vec<_>>();
+
+        for key in keys {
+            ctx.deps.storage.remove(&key);
+        }
+    }
+
+    #[test]
+    fn backfill_resumes_where_it_left_off() -> Result<()> {
+        let meme = denom(&mock_env(), "meme");
+        let pepe = denom(&mock_env(), "pepe");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .create_shitcoin("creator", "PEPE", "pepecoin", 1_000_000)?
+            .enter_presale("degen1", &meme, 1_000_000)?
+            .enter_presale("degen2", &meme, 1_000_000)?
+            .enter_presale("degen3", &meme, 1_000_000)?
+            .enter_presale("degen4", &pepe, 1_000_000)?
+            .withdraw_presale("degen3", &meme, 995_000)?;
+
+        let admin = ctx.config.admin.clone();
+
+        forget_submission_indexes(&mut ctx);
+
+        state::set_contract_version(&mut ctx.deps.storage, "0.1.0");
+
+        migrate(&mut ctx)?;
+
+        // two of meme's submissions, then the last one and moving on, then pepe's
+        assert!(!backfill_indexes(&mut ctx, &admin, 2)?);
+        assert_eq!(state::presale_participants(&ctx.deps.storage, &meme), 2);
+        assert!(!backfill_indexes(&mut ctx, &admin, 2)?);
+        assert_eq!(state::presale_participants(&ctx.deps.storage, &pepe), 0);
+        assert!(backfill_indexes(&mut ctx, &admin, 2)?);
+
+        assert_eq!(
+            state::presale_participant(&ctx.deps.storage, &meme, 1),
+            Some("degen2".to_owned())
+        );
+        assert_eq!(state::presale_participants(&ctx.deps.storage, &meme), 2);
+        assert_eq!(state::presale_participants(&ctx.deps.storage, &pepe), 1);
+        assert_eq!(
+            state::degen_presale(&ctx.deps.storage, "degen4", 0),
+            Some(pepe)
+        );
+
+        // degen3 withdrew everything, so has no position to index
+        assert_eq!(state::degen_presales(&ctx.deps.storage, "degen3"), 0);
+
+        let err = backfill_indexes(&mut ctx, &admin, 2).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[no_pending_backfill] every index is already filled in"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn backfill_is_admin_only() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        state::set_contract_version(&mut ctx.deps.storage, "0.1.0");
+
+        migrate(&mut ctx)?;
+
+        let err = backfill_indexes(&mut ctx, "degen", 10).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_admin] only the garden admin can do that"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn downgrade_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        state::set_contract_version(&mut ctx.deps.storage, "999.0.0");
+
+        let err = migrate(&mut ctx).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("[contract_downgrade] cannot downgrade from 999.0.0 to {CONTRACT_VERSION}")
+        );
+    }
+
+    #[test]
+    fn different_contract_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        state::set_contract_name(&mut ctx.deps.storage, "some-other-contract");
+
+        let err = migrate(&mut ctx).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[contract_mismatch] cannot migrate some-other-contract to shitcoin-garden"
+        );
+    }
+
+    #[test]
+    fn versions_parse() -> Result<()> {
+        assert_eq!(parse_version("0.2.0")?, (0, 2, 0));
+        assert_eq!(parse_version("1.10.3-rc.1")?, (1, 10, 3));
+        assert!(parse_version("1.2").is_err());
+        assert!(parse_version("one.two.three").is_err());
+
+        Ok(())
+    }
+}
+
+#[test]
+fn error_codes_are_stable() -> Result<()> {
+    let codes = [
+        (ContractError::NotAdmin, "\"not_admin\""),
+        (ContractError::BagTooSmol, "\"bag_too_smol\""),
+        (ContractError::PresaleNotOver, "\"presale_not_over\""),
+        (ContractError::Std(StdError::not_found("meme")), "\"std\""),
+    ];
+
+    for (err, expected_code) in codes {
+        assert_eq!(to_json_string(&err.code())?, expected_code);
+        assert_eq!(format!("\"{}\"", err.code()), expected_code);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn error_messages_lead_with_their_code() -> Result<()> {
+    let mut ctx = initialized_contract_ctx()?;
+
+    // the message is all a client gets back from a failed tx
+    let err = execute(
+        ctx.deps.as_mut(),
+        mock_env(),
+        mock_info("degen", &[]),
+        ExecuteMsg::ProposeAdmin {
+            admin: "degen".to_owned(),
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "[not_admin] only the garden admin can do that"
+    );
+
+    let err = ContractError::from(StdError::not_found("meme"));
+
+    assert_eq!(err.to_string(), "[std] meme not found");
+
+    Ok(())
+}
+
+mod create_shitcoin {
+    use crate::msg::{ShitcoinMetadata, ShitcoinPage};
+
+    use super::*;
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::Shitcoins {
+                start_after: None,
+                limit: None,
+                order: None,
+                status: None,
+            },
+        )?;
+
+        let page: ShitcoinPage = from_json(query_response)?;
+
+        assert_eq!(page.shitcoins.len(), 1);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.next, None);
+
+        let shitcoin = page.shitcoins.into_iter().next().unwrap();
+
+        let denom = denom(&mock_env(), "meme");
+
+        assert_eq!(&shitcoin.denom, &denom);
+        assert_eq!(shitcoin.creator, "creator");
+        assert_eq!(
+            shitcoin.presale_end,
+            mock_env().block.time.seconds() + ctx.config.presale_length
+        );
+        assert_eq!(shitcoin.presale_raise.u128(), 0);
+        assert_eq!(shitcoin.supply.u128(), 1_000_000_000_000);
+        assert!(!shitcoin.launched);
+
+        let token = ctx.external.tokens.get(&denom).unwrap();
+
+        assert_eq!(
+            token,
+            &Token {
+                admin: mock_env().contract.address.to_string(),
+                supply: 1_000_000_000_000,
+                description: "shitcoin".to_owned(),
+                denom_units: vec![
+                    DenomUnit {
+                        denom: denom.clone(),
+                        exponent: 0,
+                        aliases: vec![],
+                    },
+                    DenomUnit {
+                        denom: "MEME".to_owned(),
+                        exponent: 6,
+                        aliases: vec![],
+                    },
+                ],
+                base: denom.clone(),
+                display: "MEME".to_owned(),
+                name: "memecoin".to_owned(),
+                symbol: "MEME".to_owned()
+            }
+        );
+
+        let shitcoin_garden_balance = ctx
+            .external
+            .balances
+            .get(&(mock_env().contract.address.to_string(), denom.clone()))
+            .unwrap();
+
+        assert_eq!(*shitcoin_garden_balance, 1_000_000_000_000);
+
+        assert_eq!(ctx.external.dex_msgs.len(), 1);
+
+        assert_eq!(
+            ctx.external.dex_msgs[0],
+            DexMsg::CreatePool {
+                contract: "xyk_factory".to_owned(),
+                asset_infos: vec![
+                    AssetInfo::native(denom),
+                    AssetInfo::native(ctx.presale_denom())
+                ],
+                pair_type: Some(PairType::Xyk {}),
+                init_params: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn without_paying_correct_fee_amount_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "creator",
+                &[coin(
+                    ctx.config.create_fee.u128() - 1,
+                    &ctx.config.create_fee_denom,
+                )],
+            ),
+            ShitcoinParams::default().into_msg("MEME", "memecoin", 1_000),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[insufficient_create_fee] you must pay 1000000 untrn to create a shitcoin"
+        );
+    }
+
+    #[test]
+    fn duplicate_ticker_fails() {
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .create_shitcoin("copycat", "meme", "memecoin 2", 1_000_000)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[duplicate_ticker] a shitcoin with the ticker meme already exists"
+        );
+    }
+
+    #[test]
+    fn invalid_ticker_fails() {
+        let cases = [
+            (
+                "ME",
+                "[invalid_ticker_length] ticker must be between 3 and 12 characters",
+            ),
+            (
+                "MEMEMEMEMEMEM",
+                "[invalid_ticker_length] ticker must be between 3 and 12 characters",
+            ),
+            (
+                "ME ME",
+                "[invalid_ticker_characters] ticker can only contain letters and numbers",
+            ),
+            (
+                "ME/ME",
+                "[invalid_ticker_characters] ticker can only contain letters and numbers",
+            ),
+            (
+                "MEMÉ",
+                "[invalid_ticker_characters] ticker can only contain letters and numbers",
+            ),
+            ("ATOM", "[reserved_ticker] ticker ATOM is reserved"),
+            ("usdc", "[reserved_ticker] ticker usdc is reserved"),
+        ];
+
+        for (ticker, expected_err) in cases {
+            let err = initialized_contract_ctx()
+                .unwrap()
+                .create_shitcoin("creator", ticker, "memecoin", 1_000_000)
+                .unwrap_err();
+
+            assert_eq!(err.to_string(), expected_err, "ticker: {ticker}");
+        }
+    }
+
+    #[test]
+    fn invalid_name_fails() {
+        let cases = [
+            (
+                "",
+                "[invalid_name_length] name must be between 1 and 32 characters",
+            ),
+            (
+                "a name that is far too long for a coin",
+                "[invalid_name_length] name must be between 1 and 32 characters",
+            ),
+            (
+                "meme\ncoin",
+                "[invalid_name_characters] name can only contain printable ascii characters",
+            ),
+            (
+                "mëmecoin",
+                "[invalid_name_characters] name can only contain printable ascii characters",
+            ),
+            (
+                " memecoin",
+                "[invalid_name_spacing] name cannot have leading, trailing or repeated spaces",
+            ),
+            (
+                "meme  coin",
+                "[invalid_name_spacing] name cannot have leading, trailing or repeated spaces",
+            ),
+        ];
+
+        for (name, expected_err) in cases {
+            let err = initialized_contract_ctx()
+                .unwrap()
+                .create_shitcoin("creator", "MEME", name, 1_000_000)
+                .unwrap_err();
+
+            assert_eq!(err.to_string(), expected_err, "name: {name}");
+        }
+    }
+
+    #[test]
+    fn query_by_ticker() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinByTicker {
+                ticker: "mEmE".to_owned(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert_eq!(shitcoin.denom, denom(&mock_env(), "meme"));
+        assert_eq!(shitcoin.ticker, "MEME");
+
+        Ok(())
+    }
+
+    #[test]
+    fn without_paying_correct_fee_denom_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[coin(ctx.config.create_fee.u128(), "uatom")]),
+            ShitcoinParams::default().into_msg("MEME", "memecoin", 1_000),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[missing_create_fee] you must also send untrn to create a shitcoin"
+        );
+    }
+
+    #[test]
+    fn pair_captured_from_reply() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        let pool_address = pool_address(&denom, &ctx.presale_denom());
+
+        assert_eq!(shitcoin.lp_token, Some(lp_token_address(&pool_address)));
+        assert_eq!(shitcoin.pair, Some(pool_address));
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_pair_creation_fails() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "creator",
+                &[coin(
+                    ctx.config.create_fee.u128(),
+                    &ctx.config.create_fee_denom,
+                )],
+            ),
+            ShitcoinParams::default().into_msg("MEME", "memecoin", 1_000_000),
+        )?;
+
+        let create_pair_msg = response
+            .messages
+            .iter()
+            .find(|msg| msg.id == CREATE_PAIR_REPLY_ID)
+            .expect("pool created at creation");
+
+        assert_eq!(create_pair_msg.reply_on, ReplyOn::Always);
+
+        let err = reply(
+            ctx.deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: CREATE_PAIR_REPLY_ID,
+                result: SubMsgResult::Err("pair already exists".to_owned()),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[pair_creation_failed] creating the pool failed: pair already exists"
+        );
+
+        // nothing is left waiting for a pool that will never be created
+        assert_eq!(crate::state::pending_pair(&ctx.deps.storage), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_pair_address_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "creator",
+                &[coin(
+                    ctx.config.create_fee.u128(),
+                    &ctx.config.create_fee_denom,
+                )],
+            ),
+            ShitcoinParams::default().into_msg("MEME", "memecoin", 1_000_000),
+        )
+        .unwrap();
+
+        let err = reply(
+            ctx.deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: CREATE_PAIR_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[missing_pair_address] pool creation did not report the pair and lp token addresses"
+        );
+    }
+}
+
+mod enter_presale {
+    use crate::error::ErrorCode;
+    use crate::msg::{DegenMetadata, ShitcoinMetadata};
+    use crate::HUNDRED_PERCENT_BPS;
+
+    use super::*;
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        const PRESALE_BUY_AMOUNT: u128 = 1_000_000_000;
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
+            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
+            .enter_presale("degen2", &denom, PRESALE_BUY_AMOUNT)?;
+
+        let single_fee =
+            (PRESALE_BUY_AMOUNT * ctx.config.presale_fee_rate as u128) / HUNDRED_PERCENT_BPS.u128();
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert_eq!(
+            shitcoin.presale_raise.u128(),
+            (3 * PRESALE_BUY_AMOUNT) - (3 * single_fee)
+        );
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom.clone(),
+                degen: "degen1".to_owned(),
+            },
+        )?;
+
+        let degen1: DegenMetadata = from_json(query_response)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom.clone(),
+                degen: "degen2".to_owned(),
+            },
+        )?;
+
+        let degen2: DegenMetadata = from_json(query_response)?;
+
+        assert_eq!(
+            degen1.presale_submission.u128(),
+            (2 * PRESALE_BUY_AMOUNT) - (2 * single_fee)
+        );
+        assert_eq!(
+            degen2.presale_submission.u128(),
+            PRESALE_BUY_AMOUNT - single_fee
+        );
+        assert!(!degen1.shitcoins_claimed);
+        assert!(!degen2.shitcoins_claimed);
+
+        let fee_recipient_balance = ctx
+            .external
+            .balances
+            .get(&("fee_recipient".to_owned(), ctx.presale_denom()))
+            .unwrap();
+
+        assert_eq!(*fee_recipient_balance, (single_fee * 3) / 2);
+
+        let creator_balance = ctx
+            .external
+            .balances
+            .get(&("creator".to_owned(), ctx.presale_denom()))
+            .unwrap();
+
+        assert_eq!(*creator_balance, (single_fee * 3) / 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn without_paying_correct_fee_denom_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("degen", &[coin(1_000_000, "untrn")]),
+            ExecuteMsg::EnterPresale {
+                denom: denom.to_owned(),
+                referrer: None,
+                allowlist_proof: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[missing_presale_funds] you must send uatom to enter the presale"
+        );
+    }
+
+    #[test]
+    fn presale_ended_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap();
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info("degen", &[coin(1_000_000, ctx.presale_denom())]),
+            ExecuteMsg::EnterPresale {
+                denom: denom.to_owned(),
+                referrer: None,
+                allowlist_proof: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_over] you're too late to enter this shitcoin's presale"
+        );
+    }
+
+    #[test]
+    fn amount_too_small_for_fee_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("degen", &[coin(99, ctx.presale_denom())]),
+            ExecuteMsg::EnterPresale {
+                denom: denom.to_owned(),
+                referrer: None,
+                allowlist_proof: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "[bag_too_smol] bag too smol");
+        assert_eq!(err.code(), ErrorCode::BagTooSmol);
+    }
+}
+
+mod withdraw_presale {
+    use crate::msg::{DegenMetadata, ShitcoinMetadata};
+
+    use super::*;
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        // 1_000_000_000 entered minus the 0.5% fee is a 995_000_000 submission
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .withdraw_presale("degen", &denom, 400_000_000)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert_eq!(shitcoin.presale_raise.u128(), 595_000_000);
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom.clone(),
+                degen: "degen".to_owned(),
+            },
+        )?;
+
+        let degen: DegenMetadata = from_json(query_response)?;
+
+        assert_eq!(degen.presale_submission.u128(), 595_000_000);
+
+        let balance = |address: &str| {
+            ctx.external
+                .balances
+                .get(&(address.to_owned(), ctx.presale_denom()))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        // 10% penalty of 40_000_000, split evenly on top of the 5_000_000 entry fee
+        assert_eq!(balance("degen"), 360_000_000);
+        assert_eq!(balance("creator"), 2_500_000 + 20_000_000);
+        assert_eq!(balance("fee_recipient"), 2_500_000 + 20_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn more_than_submission_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap()
+            .withdraw_presale("degen", &denom, 995_000_001)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_withdraw_amount] you can withdraw between 1 and 995000000"
+        );
+    }
+
+    #[test]
+    fn zero_amount_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap()
+            .withdraw_presale("degen", &denom, 0)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_withdraw_amount] you can withdraw between 1 and 995000000"
+        );
+    }
+
+    #[test]
+    fn presale_ended_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap();
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info("degen", &[]),
+            ExecuteMsg::WithdrawPresale {
+                denom: denom.clone(),
+                amount: 1u128.into(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[withdrawals_closed] the presale is over, withdrawals are closed"
+        );
+    }
+
+    #[test]
+    fn withdrawal_event_emitted() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap();
+
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("degen", &[]),
+            ExecuteMsg::WithdrawPresale {
+                denom: denom.clone(),
+                amount: 1_000u128.into(),
+            },
+        )
+        .unwrap();
+
+        let event = &response.events[0];
+
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "kind" && attr.value == "presale-withdrawn"));
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "penalty" && attr.value == "100"));
+    }
+}
+
+mod extend_presale {
+    use crate::msg::ShitcoinMetadata;
+
+    use super::*;
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .extend_presale(&denom)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert_eq!(
+            shitcoin.presale_end,
+            mock_env().block.time.seconds() + (2 * ctx.config.presale_length) + 1
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn presale_ongoing_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap();
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length - 1);
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info("extendoor", &[]),
+            ExecuteMsg::ExtendPresale {
+                denom: denom.to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_not_over] patience young grasshopper the presale is not over"
+        );
+    }
+
+    #[test]
+    fn non_zero_raise_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen1", &denom, 1_000_000)
+            .unwrap()
+            .extend_presale(&denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_raised] shitcoin is primed and ready for launch"
+        );
+    }
+}
+
+mod presale_length {
+    use super::*;
+
+    const FLASH: u64 = 60 * 60;
+
+    fn flash() -> ShitcoinParams {
+        ShitcoinParams {
+            presale_length: Some(FLASH),
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn defaults_to_config() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        assert_eq!(shitcoin.presale_length, ctx.config.presale_length);
+
+        Ok(())
+    }
+
+    #[test]
+    fn creator_chosen() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            flash(),
+        )?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        assert_eq!(shitcoin.presale_length, FLASH);
+        assert_eq!(
+            shitcoin.presale_end,
+            mock_env().block.time.seconds() + FLASH
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn extend_reuses_shitcoin_length() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            flash(),
+        )?;
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(FLASH + 1);
+
+        execute(
+            ctx.deps.as_mut(),
+            env.clone(),
+            mock_info("extendoor", &[]),
+            ExecuteMsg::ExtendPresale {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        assert_eq!(shitcoin.presale_end, env.block.time.seconds() + FLASH);
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_short_fails() {
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    presale_length: Some(59),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_length_out_of_bounds] presale length must be between 60 and 1209600 seconds"
+        );
+    }
+
+    #[test]
+    fn too_long_fails() {
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    presale_length: Some(60 * 60 * 24 * 14 + 1),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_length_out_of_bounds] presale length must be between 60 and 1209600 seconds"
+        );
+    }
+}
+
+mod allocation {
+    use cosmwasm_std::Uint128;
+
+    use super::*;
+
+    fn allocated(lp: u32, presale: u32, creator: u32) -> ShitcoinParams {
+        ShitcoinParams {
+            allocation: Some(Allocation {
+                lp,
+                presale,
+                creator,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn create_allocated(lp: u32, presale: u32, creator: u32) -> Result<Ctx> {
+        initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            allocated(lp, presale, creator),
+        )
+    }
+    #[test]
+    fn defaults_to_half_and_half() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        assert_eq!(
+            query_shitcoin(&ctx, &denom)?.allocation,
+            Allocation {
+                lp: 5_000,
+                presale: 5_000,
+                creator: 0,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn launch_and_claim_follow_allocation() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = create_allocated(6_000, 3_000, 1_000)?
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?
+            .claim_shitcoin("degen", &denom)?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        assert_eq!(
+            ctx.external.dex_msgs[1],
+            DexMsg::SeedPool {
+                contract: pool_address(&denom, &ctx.presale_denom()),
+                assets: vec![
+                    Asset {
+                        info: AssetInfo::native(&denom),
+                        amount: shitcoin.supply.multiply_ratio(6u128, 10u128)
+                    },
+                    Asset {
+                        info: AssetInfo::native(ctx.presale_denom()),
+                        amount: shitcoin.presale_raise
+                    }
+                ]
+            }
+        );
+
+        let balance = |address: &str| {
+            ctx.external
+                .balances
+                .get(&(address.to_owned(), denom.clone()))
+                .copied()
+                .map(Uint128::new)
+                .unwrap_or_default()
+        };
+
+        // the creator allocation vests rather than being paid out at launch
+        assert_eq!(balance("creator"), Uint128::zero());
+        assert_eq!(
+            balance("degen"),
+            shitcoin.supply.multiply_ratio(3u128, 10u128)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_total_fails() {
+        let err = create_allocated(5_000, 4_000, 0).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_allocation_total] allocation has to add up to 10000 bps"
+        );
+    }
+
+    #[test]
+    fn zero_presale_fails() {
+        let err = create_allocated(10_000, 0, 0).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[zero_presale_allocation] presale allocation has to be greater than zero"
+        );
+    }
+
+    #[test]
+    fn lp_below_minimum_fails() {
+        let err = create_allocated(2_999, 7_001, 0).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[lp_allocation_too_low] liquidity allocation has to be at least 3000 bps"
+        );
+    }
+
+    #[test]
+    fn creator_above_maximum_fails() {
+        let err = create_allocated(5_000, 3_999, 1_001).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[creator_allocation_too_high] creator allocation has to be at most 1000 bps"
+        );
+    }
+}
+
+mod vesting {
+    use cosmwasm_std::{Env, Uint128};
+
+    use crate::msg::VestingStatus;
+
+    use super::*;
+
+    const DAY: u64 = 60 * 60 * 24;
+
+    fn launched_ctx() -> Result<Ctx> {
+        let denom = denom(&mock_env(), "meme");
+
+        initialized_contract_ctx()?
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    allocation: Some(Allocation {
+                        lp: 6_000,
+                        presale: 3_000,
+                        creator: 1_000,
+                    }),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)
+    }
+
+    /// Launches happen just after the presale ends, so vesting starts there too.
+    fn after_launch(ctx: &Ctx, seconds: u64) -> Env {
+        let mut env = mock_env();
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(ctx.config.presale_length + 1 + seconds);
+
+        env
+    }
+
+    fn vesting_status(ctx: &Ctx, env: Env) -> Result<VestingStatus> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            env,
+            QueryMsg::VestingStatus {
+                denom: denom(&mock_env(), "meme"),
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+
+    fn claim_vested(ctx: &mut Ctx, env: Env, sender: &str) -> Result<()> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info(sender, &[]),
+            ExecuteMsg::ClaimVested {
+                denom: denom(&mock_env(), "meme"),
+            },
+        )?;
+
+        ctx.handle_response(response);
+
+        Ok(())
+    }
+
+    fn creator_balance(ctx: &Ctx) -> u128 {
+        ctx.external
+            .balances
+            .get(&("creator".to_owned(), denom(&mock_env(), "meme")))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn nothing_released_before_cliff() -> Result<()> {
+        let mut ctx = launched_ctx()?;
+
+        let env = after_launch(&ctx, 30 * DAY - 1);
+
+        let status = vesting_status(&ctx, env.clone())?;
+
+        assert_eq!(status.total, Uint128::new(100_000_000_000));
+        assert_eq!(status.released, Uint128::zero());
+        assert_eq!(status.locked, status.total);
+
+        let err = claim_vested(&mut ctx, env, "creator").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[nothing_vested] nothing has vested since your last claim ser"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn releases_linearly_after_cliff() -> Result<()> {
+        let mut ctx = launched_ctx()?;
+
+        let halfway = after_launch(&ctx, 90 * DAY);
+
+        let status = vesting_status(&ctx, halfway.clone())?;
+
+        assert_eq!(status.released, Uint128::new(50_000_000_000));
+        assert_eq!(status.locked, Uint128::new(50_000_000_000));
+
+        claim_vested(&mut ctx, halfway.clone(), "creator")?;
+
+        assert_eq!(creator_balance(&ctx), 50_000_000_000);
+        assert_eq!(
+            vesting_status(&ctx, halfway.clone())?.claimed,
+            Uint128::new(50_000_000_000)
+        );
+
+        let err = claim_vested(&mut ctx, halfway, "creator").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[nothing_vested] nothing has vested since your last claim ser"
+        );
+
+        let fully_vested = after_launch(&ctx, 180 * DAY);
+
+        claim_vested(&mut ctx, fully_vested, "creator")?;
+
+        assert_eq!(creator_balance(&ctx), 100_000_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_launched_reports_nothing_released() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let status = vesting_status(&ctx, mock_env())?;
+
+        assert_eq!(status.start, None);
+        assert_eq!(status.released, Uint128::zero());
+        assert_eq!(status.cliff, ctx.config.creator_vesting_cliff);
+        assert_eq!(status.duration, ctx.config.creator_vesting_duration);
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            ExecuteMsg::ClaimVested { denom },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_launched] shitcoin needs to be launched before claiming"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_creator_fails() -> Result<()> {
+        let mut ctx = launched_ctx()?;
+
+        let env = after_launch(&ctx, 180 * DAY);
+
+        let err = claim_vested(&mut ctx, env, "degen").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_creator] you are not the creator of this shitcoin"
+        );
+
+        Ok(())
+    }
+}
+
+mod lp_policy {
+    use super::*;
+
+    const LOCK: u64 = 60 * 60 * 24 * 365;
+
+    fn launched_with(lp_policy: LpPolicy) -> Result<Ctx> {
+        let denom = denom(&mock_env(), "meme");
+
+        initialized_contract_ctx()?
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    lp_policy: Some(lp_policy),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)
+    }
+    fn lp_token(ctx: &Ctx) -> String {
+        lp_token_address(&pool_address(
+            &denom(&mock_env(), "meme"),
+            &ctx.presale_denom(),
+        ))
+    }
+
+    fn lp_balance(ctx: &Ctx, holder: &str) -> u128 {
+        ctx.external
+            .balances
+            .get(&(holder.to_owned(), lp_token(ctx)))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn claim_lp(ctx: &mut Ctx, seconds_after_launch: u64, sender: &str) -> Result<()> {
+        let mut env = mock_env();
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(ctx.config.presale_length + 1 + seconds_after_launch);
+
+        let response = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info(sender, &[]),
+            ExecuteMsg::ClaimLp {
+                denom: denom(&mock_env(), "meme"),
+            },
+        )?;
+
+        ctx.handle_response(response);
+
+        Ok(())
+    }
+
+    #[test]
+    fn locked_by_default() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom(&mock_env(), "meme"), 1_000_000_000)?
+            .launch_shitcoin(&denom(&mock_env(), "meme"))?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?;
+
+        let lp_amount = shitcoin.supply / Uint128::new(2);
+
+        assert_eq!(shitcoin.lp_policy, LpPolicy::Lock {});
+        assert_eq!(shitcoin.lp_token, Some(lp_token(&ctx)));
+        assert_eq!(shitcoin.lp_amount, Some(lp_amount));
+        assert_eq!(shitcoin.lp_unlocks_at, None);
+
+        assert_eq!(
+            lp_balance(&ctx, mock_env().contract.address.as_str()),
+            lp_amount.u128()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn burned_at_launch() -> Result<()> {
+        let ctx = launched_with(LpPolicy::Burn {})?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?;
+
+        let lp_amount = shitcoin.lp_amount.unwrap();
+
+        assert_eq!(
+            ctx.external.dex_msgs.last(),
+            Some(&DexMsg::BurnLp {
+                contract: lp_token(&ctx),
+                amount: lp_amount,
+            })
+        );
+
+        assert_eq!(lp_balance(&ctx, mock_env().contract.address.as_str()), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn launch_event_records_lp() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    lp_policy: Some(LpPolicy::Burn {}),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale("degen", &denom, 1_000_000_000)?;
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
+
+        let response = execute(
+            ctx.deps.as_mut(),
+            env.clone(),
+            mock_info("launcher", &[]),
+            ExecuteMsg::LaunchShitcoin {
+                denom: denom.clone(),
+            },
+        )?;
+
+        assert_eq!(response.messages[0].reply_on, ReplyOn::Success);
+
+        let response = reply(
+            ctx.deps.as_mut(),
+            env,
+            Reply {
+                id: response.messages[0].id,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![Event::new("wasm").add_attribute("share", "42")],
+                    data: None,
+                }),
+            },
+        )?;
+
+        let event = &response.events[0];
+
+        let attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.as_str())
+        };
+
+        assert_eq!(attr("kind"), Some("shitcoin-launched"));
+        assert_eq!(attr("lp_token"), Some(lp_token(&ctx).as_str()));
+        assert_eq!(attr("lp_amount"), Some("42"));
+        assert_eq!(attr("lp_policy"), Some("burn"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_locked_to_creator() -> Result<()> {
+        let mut ctx = launched_with(LpPolicy::TimeLock { duration: LOCK })?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?;
+
+        let launched_at = mock_env().block.time.seconds() + ctx.config.presale_length + 1;
+
+        assert_eq!(shitcoin.lp_unlocks_at, Some(launched_at + LOCK));
+
+        let err = claim_lp(&mut ctx, LOCK - 1, "creator").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "[lp_still_locked] liquidity is locked until {}",
+                launched_at + LOCK
+            )
+        );
+
+        let err = claim_lp(&mut ctx, LOCK, "degen").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_creator] you are not the creator of this shitcoin"
+        );
+
+        claim_lp(&mut ctx, LOCK, "creator")?;
+
+        assert_eq!(
+            lp_balance(&ctx, "creator"),
+            shitcoin.lp_amount.unwrap().u128()
+        );
+        assert!(query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?.lp_claimed);
+
+        let err = claim_lp(&mut ctx, LOCK, "creator").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[lp_already_claimed] liquidity already claimed"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn claiming_locked_forever_fails() -> Result<()> {
+        let mut ctx = launched_with(LpPolicy::Lock {})?;
+
+        let err = claim_lp(&mut ctx, LOCK, "creator").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[lp_not_time_locked] this shitcoin's liquidity is not time-locked"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_lock_duration_fails() {
+        let err = launched_with(LpPolicy::TimeLock { duration: 0 }).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_lp_lock_duration] liquidity lock duration has to be greater than zero"
+        );
+    }
+}
+
+mod launch_shitcoin {
+    use cosmwasm_std::Uint128;
+
+    use crate::{msg::ShitcoinMetadata, state};
+
+    use super::*;
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert!(shitcoin.launched);
+
+        assert_eq!(ctx.external.dex_msgs.len(), 2);
+
+        assert_eq!(
+            ctx.external.dex_msgs[1],
+            DexMsg::SeedPool {
+                contract: pool_address(&denom, &ctx.presale_denom()),
+                assets: vec![
+                    Asset {
+                        info: AssetInfo::native(&denom),
+                        amount: shitcoin.supply / Uint128::new(2)
+                    },
+                    Asset {
+                        info: AssetInfo::native(ctx.presale_denom()),
+                        amount: shitcoin.presale_raise
+                    }
+                ]
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn presale_not_ended_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("launcher", &[]),
+            ExecuteMsg::LaunchShitcoin {
+                denom: denom.to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_not_over] patience young grasshopper the presale is not over"
+        );
+    }
+
+    #[test]
+    fn shitcoin_already_launched_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap()
+            .launch_shitcoin(&denom)
+            .unwrap();
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info("launcher", &[]),
+            ExecuteMsg::LaunchShitcoin {
+                denom: denom.to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[already_launched] shitcoin already launched"
+        );
+    }
+
+    #[test]
+    fn without_stored_pair_queries_factory() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_000_000_000)?;
+
+        // shitcoins created before the pair was captured at creation
+        ctx.deps
+            .storage
+            .remove(format!("SHITCOIN_PAIR:{denom}").as_bytes());
+        ctx.deps
+            .storage
+            .remove(format!("SHITCOIN_LP_TOKEN:{denom}").as_bytes());
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        let pool_address = pool_address(&denom, &ctx.presale_denom());
+
+        assert!(matches!(
+            &ctx.external.dex_msgs[1],
+            DexMsg::SeedPool { contract, .. } if contract == &pool_address
+        ));
+
+        assert_eq!(
+            state::shitcoin_lp_token(&ctx.deps.storage, &denom),
+            Some(lp_token_address(&pool_address))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn renounces_denom_admin() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_000_000_000)?;
+
+        assert_eq!(
+            ctx.external.tokens.get(&denom).unwrap().admin,
+            mock_env().contract.address.as_str()
+        );
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        assert_eq!(
+            ctx.external.tokens.get(&denom).unwrap().admin,
+            ctx.config.denom_admin_burn_address
+        );
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata { denom },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert!(shitcoin.admin_renounced);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_url_after_launch_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap();
+
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            ExecuteMsg::SetUrl {
+                denom: denom.clone(),
+                url: "https://meme.lol".to_owned(),
+            },
+        )
+        .unwrap();
+
+        ctx.handle_response(response);
+
+        let mut ctx = ctx.launch_shitcoin(&denom).unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            ExecuteMsg::SetUrl {
+                denom,
+                url: "https://rug.lol".to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[metadata_locked] shitcoin metadata is locked once the denom admin is renounced at \
+             launch"
+        );
+    }
+}
+
+mod claim_shitcoin {
+    use crate::msg::{DegenMetadata, ShitcoinMetadata};
+
+    use super::*;
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        const PRESALE_BUY_AMOUNT: u128 = 1_000_000_000;
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
+            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
+            .enter_presale("degen2", &denom, PRESALE_BUY_AMOUNT)?
+            .launch_shitcoin(&denom)?
+            .claim_shitcoin("degen1", &denom)?
+            .claim_shitcoin("degen2", &denom)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom.clone(),
+            },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        let claimable_supply = shitcoin.supply.u128() / 2;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom.clone(),
+                degen: "degen1".to_owned(),
+            },
+        )?;
+
+        let degen1: DegenMetadata = from_json(query_response)?;
+
+        assert!(degen1.shitcoins_claimed);
+
+        assert_eq!(
+            *ctx.external
+                .balances
+                .get(&("degen1".to_owned(), denom.clone()))
+                .unwrap(),
+            (claimable_supply * degen1.presale_submission.u128()) / shitcoin.presale_raise.u128()
+        );
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom.clone(),
+                degen: "degen2".to_owned(),
+            },
+        )?;
+
+        let degen2: DegenMetadata = from_json(query_response)?;
+
+        assert!(degen2.shitcoins_claimed);
+
+        assert_eq!(
+            *ctx.external
+                .balances
+                .get(&("degen2".to_owned(), denom.clone()))
+                .unwrap(),
+            (claimable_supply * degen2.presale_submission.u128()) / shitcoin.presale_raise.u128()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn presale_ongoing_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000)
+            .unwrap();
+
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length - 1);
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info("degen", &[]),
+            ExecuteMsg::ClaimShitcoin {
+                denom: denom.to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_not_over] patience young grasshopper the presale is not over"
+        );
+    }
+
+    #[test]
+    fn shitcoin_not_launched_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000)
+            .unwrap()
+            .claim_shitcoin("degen", &denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_launched] shitcoin needs to be launched before claiming"
+        );
+    }
+
+    #[test]
+    fn shitcoins_already_claimed_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000)
+            .unwrap()
+            .launch_shitcoin(&denom)
+            .unwrap()
+            .claim_shitcoin("degen", &denom)
+            .unwrap()
+            .claim_shitcoin("degen", &denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[already_claimed] shitcoins already claimed"
+        );
+    }
+
+    #[test]
+    fn did_not_enter_presale_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000)
+            .unwrap()
+            .launch_shitcoin(&denom)
+            .unwrap()
+            .claim_shitcoin("griff", &denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_in_presale] ser you did not enter this shitcoin presale"
+        );
+    }
+}
+
+mod presale_caps {
+    use crate::msg::ShitcoinMetadata;
+
+    use super::*;
+
+    fn capped(soft_cap: Option<u128>, hard_cap: Option<u128>) -> ShitcoinParams {
+        ShitcoinParams {
+            soft_cap,
+            hard_cap,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn caps_in_metadata() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            capped(Some(1_000), Some(2_000)),
+        )?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata { denom },
+        )?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query_response)?;
+
+        assert_eq!(shitcoin.soft_cap, Some(1_000u128.into()));
+        assert_eq!(shitcoin.hard_cap, Some(2_000u128.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn soft_cap_above_hard_cap_fails() {
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                capped(Some(2_000), Some(1_000)),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[soft_cap_above_hard_cap] soft cap cannot be greater than the hard cap"
+        );
+    }
+
+    #[test]
+    fn zero_cap_fails() {
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                capped(None, Some(0)),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[zero_presale_cap] presale caps have to be greater than zero"
+        );
+    }
+
+    #[test]
+    fn hard_cap_stops_entries() {
+        let denom = denom(&mock_env(), "meme");
+
+        // 1_000_000 entered minus the 0.5% fee is a 995_000 submission
+        let ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                capped(None, Some(1_990_000)),
+            )
+            .unwrap()
+            .enter_presale("degen1", &denom, 1_000_000)
+            .unwrap();
+
+        let err = ctx.enter_presale("degen2", &denom, 2_000_000).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[hard_cap_exceeded] this presale only has room for 995000 more"
+        );
+
+        let ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                capped(None, Some(1_990_000)),
+            )
+            .unwrap()
+            .enter_presale("degen1", &denom, 1_000_000)
+            .unwrap()
+            .enter_presale("degen2", &denom, 1_000_000)
+            .unwrap();
+
+        let err = ctx.enter_presale("degen3", &denom, 1_000_000).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_full] this presale is full, the hard cap has been reached"
+        );
+    }
+}
+
+mod refund_presale {
+    use crate::msg::DegenMetadata;
+
+    use super::*;
+
+    fn failed_presale_ctx() -> Result<Ctx> {
+        let denom = denom(&mock_env(), "meme");
+
+        initialized_contract_ctx()?
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    soft_cap: Some(1_000_000_000),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale("degen", &denom, 1_000_000)
+    }
+
+    #[test]
+    fn happy_path() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = failed_presale_ctx()?.refund_presale("degen", &denom)?;
+
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom.clone(),
+                degen: "degen".to_owned(),
+            },
+        )?;
+
+        let degen: DegenMetadata = from_json(query_response)?;
+
+        assert!(degen.presale_refunded);
+
+        assert_eq!(
+            *ctx.external
+                .balances
+                .get(&("degen".to_owned(), ctx.presale_denom()))
+                .unwrap(),
+            degen.presale_submission.u128()
+        );
+
+        let err = ctx.refund_presale("degen", &denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[already_refunded] presale already refunded"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_presale_cannot_launch() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = failed_presale_ctx()
+            .unwrap()
+            .launch_shitcoin(&denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[soft_cap_not_reached] the presale ended below its soft cap and cannot launch"
+        );
+    }
+
+    #[test]
+    fn presale_ongoing_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = failed_presale_ctx().unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("degen", &[]),
+            ExecuteMsg::RefundPresale {
+                denom: denom.to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_not_over] patience young grasshopper the presale is not over"
+        );
+    }
+
+    #[test]
+    fn soft_cap_reached_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000)
+            .unwrap()
+            .refund_presale("degen", &denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_not_failed] no refunds ser, the presale reached its soft cap"
+        );
+    }
+
+    #[test]
+    fn did_not_enter_presale_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = failed_presale_ctx()
+            .unwrap()
+            .refund_presale("griff", &denom)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_in_presale] ser you did not enter this shitcoin presale"
+        );
+    }
+}
+
+mod shitcoins_query {
+    use cosmwasm_std::Env;
+
+    use crate::{
+        msg::{ShitcoinStatus, SortOrder},
+        MAX_PAGE_SCAN,
+    };
+
+    use super::*;
+
+    fn garden_ctx(count: usize) -> Result<Ctx> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        for idx in 0..count {
+            let ticker = format!("COIN{idx}");
+
+            ctx = ctx.create_shitcoin("creator", &ticker, &ticker.to_lowercase(), 1_000_000)?;
+        }
+
+        Ok(ctx)
+    }
+
+    fn query_shitcoins(
+        ctx: &Ctx,
+        env: Env,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+        order: Option<SortOrder>,
+        status: Option<ShitcoinStatus>,
+    ) -> Result<ShitcoinPage> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            env,
+            QueryMsg::Shitcoins {
+                start_after,
+                limit,
+                order,
+                status,
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+    /// Follows the cursor until the last page, collecting every ticker on the way.
+    fn walk(ctx: &Ctx, limit: u64, order: SortOrder) -> Result<Vec<String>> {
+        let mut all = vec![];
+
+        let mut start_after = None;
+
+        loop {
+            let page =
+                query_shitcoins(ctx, mock_env(), start_after, Some(limit), Some(order), None)?;
+
+            assert!(page.shitcoins.len() <= limit as usize);
+
+            all.extend(tickers(&page));
+
+            let Some(next) = page.next else {
+                break;
+            };
+
+            start_after = Some(next);
+        }
+
+        Ok(all)
+    }
+
+    fn coin_tickers(range: impl Iterator<Item = usize>) -> Vec<String> {
+        range.map(|idx| format!("COIN{idx}")).collect()
+    }
+
+    #[test]
+    fn empty_garden() -> Result<()> {
+        let ctx = garden_ctx(0)?;
+
+        let page = query_shitcoins(&ctx, mock_env(), None, None, None, None)?;
+
+        assert_eq!(page.total, 0);
+        assert!(page.shitcoins.is_empty());
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_limit() -> Result<()> {
+        let ctx = garden_ctx(12)?;
+
+        let page = query_shitcoins(&ctx, mock_env(), None, None, None, None)?;
+
+        assert_eq!(page.total, 12);
+        assert_eq!(tickers(&page), coin_tickers(0..10));
+        assert_eq!(page.next, Some(9));
+
+        let page = query_shitcoins(&ctx, mock_env(), page.next, None, None, None)?;
+
+        assert_eq!(tickers(&page), coin_tickers(10..12));
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn limit_is_capped() -> Result<()> {
+        let ctx = garden_ctx(32)?;
+
+        let page = query_shitcoins(&ctx, mock_env(), None, Some(1_000), None, None)?;
+
+        assert_eq!(tickers(&page), coin_tickers(0..30));
+        assert_eq!(page.next, Some(29));
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_limit_returns_one() -> Result<()> {
+        let ctx = garden_ctx(2)?;
+
+        let page = query_shitcoins(&ctx, mock_env(), None, Some(0), None, None)?;
+
+        assert_eq!(tickers(&page), coin_tickers(0..1));
+        assert_eq!(page.next, Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn walks_every_page_ascending() -> Result<()> {
+        let ctx = garden_ctx(12)?;
+
+        for limit in [1, 5, 6, 11, 12, 13] {
+            assert_eq!(
+                walk(&ctx, limit, SortOrder::Ascending {})?,
+                coin_tickers(0..12)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn walks_every_page_descending() -> Result<()> {
+        let ctx = garden_ctx(12)?;
+
+        for limit in [1, 5, 6, 11, 12, 13] {
+            assert_eq!(
+                walk(&ctx, limit, SortOrder::Descending {})?,
+                coin_tickers((0..12).rev())
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn full_last_page_has_no_next() -> Result<()> {
+        let ctx = garden_ctx(10)?;
+
+        let page = query_shitcoins(&ctx, mock_env(), Some(4), Some(5), None, None)?;
+
+        assert_eq!(tickers(&page), coin_tickers(5..10));
+        assert_eq!(page.next, None);
+
+        let page = query_shitcoins(
+            &ctx,
+            mock_env(),
+            Some(5),
+            Some(5),
+            Some(SortOrder::Descending {}),
+            None,
+        )?;
+
+        assert_eq!(tickers(&page), coin_tickers((0..5).rev()));
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_past_the_end() -> Result<()> {
+        let ctx = garden_ctx(3)?;
+
+        for start_after in [2, 3, 100, u64::MAX - 1, u64::MAX] {
+            let page = query_shitcoins(&ctx, mock_env(), Some(start_after), None, None, None)?;
+
+            assert!(page.shitcoins.is_empty());
+            assert_eq!(page.next, None);
+        }
+
+        // descending from past the end starts at the newest shitcoin
+        let page = query_shitcoins(
+            &ctx,
+            mock_env(),
+            Some(100),
+            None,
+            Some(SortOrder::Descending {}),
+            None,
+        )?;
+
+        assert_eq!(tickers(&page), coin_tickers((0..3).rev()));
+
+        // and nothing comes before the oldest one
+        let page = query_shitcoins(
+            &ctx,
+            mock_env(),
+            Some(0),
+            None,
+            Some(SortOrder::Descending {}),
+            None,
+        )?;
+
+        assert!(page.shitcoins.is_empty());
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    fn statuses_ctx() -> Result<Ctx> {
+        let launched = denom(&mock_env(), "launched");
+        let ended = denom(&mock_env(), "ended");
+        let abandoned = denom(&mock_env(), "abandoned");
+
+        let ctx = initialized_contract_ctx()?;
+
+        let max_presale_length = ctx.config.max_presale_length;
+
+        ctx.create_shitcoin("creator", "LAUNCHED", "launched", 1_000_000)?
+            .create_shitcoin("creator", "ENDED", "ended", 1_000_000)?
+            .create_shitcoin_with(
+                "creator",
+                "ABANDONED",
+                "abandoned",
+                1_000_000,
+                ShitcoinParams {
+                    soft_cap: Some(1_000_000_000),
+                    ..Default::default()
+                },
+            )?
+            .create_shitcoin_with(
+                "creator",
+                "ACTIVE",
+                "active",
+                1_000_000,
+                ShitcoinParams {
+                    presale_length: Some(max_presale_length),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale("degen", &launched, 1_000_000)?
+            .enter_presale("degen", &ended, 1_000_000)?
+            .enter_presale("degen", &abandoned, 1_000_000)?
+            .launch_shitcoin(&launched)
+    }
+
+    fn presale_over_env(ctx: &Ctx) -> Env {
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
+
+        env
+    }
+
+    #[test]
+    fn status_filter() -> Result<()> {
+        let ctx = statuses_ctx()?;
+
+        let cases = [
+            (ShitcoinStatus::Active {}, vec!["ACTIVE"]),
+            (ShitcoinStatus::Ended {}, vec!["ENDED"]),
+            (ShitcoinStatus::Launched {}, vec!["LAUNCHED"]),
+            (ShitcoinStatus::Abandoned {}, vec!["ABANDONED"]),
+        ];
+
+        for (status, expected) in cases {
+            let page =
+                query_shitcoins(&ctx, presale_over_env(&ctx), None, None, None, Some(status))?;
+
+            assert_eq!(page.total, 4);
+            assert_eq!(tickers(&page), expected);
+            assert_eq!(page.next, None);
+        }
+
+        // before the presales end only the launched shitcoin is not active
+        let page = query_shitcoins(
+            &ctx,
+            mock_env(),
+            None,
+            None,
+            None,
+            Some(ShitcoinStatus::Active {}),
+        )?;
+
+        assert_eq!(tickers(&page), vec!["ENDED", "ABANDONED", "ACTIVE"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn status_filter_pages_skip_non_matching() -> Result<()> {
+        let ctx = statuses_ctx()?;
+
+        let env = presale_over_env(&ctx);
+
+        let not_launched = [
+            ShitcoinStatus::Ended {},
+            ShitcoinStatus::Abandoned {},
+            ShitcoinStatus::Active {},
+        ];
+
+        let mut found = vec![];
+
+        for status in not_launched {
+            let page = query_shitcoins(&ctx, env.clone(), None, Some(1), None, Some(status))?;
+
+            found.extend(tickers(&page));
+        }
+
+        assert_eq!(found, vec!["ENDED", "ABANDONED", "ACTIVE"]);
+
+        // the cursor points at the last match, the launched shitcoin in between is skipped
+        let page = query_shitcoins(
+            &ctx,
+            env.clone(),
+            None,
+            Some(1),
+            Some(SortOrder::Descending {}),
+            Some(ShitcoinStatus::Ended {}),
+        )?;
+
+        assert_eq!(tickers(&page), vec!["ENDED"]);
+        assert_eq!(page.next, Some(1));
+
+        let page = query_shitcoins(
+            &ctx,
+            env,
+            page.next,
+            Some(1),
+            Some(SortOrder::Descending {}),
+            Some(ShitcoinStatus::Ended {}),
+        )?;
+
+        assert!(page.shitcoins.is_empty());
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn status_filter_stops_at_scan_limit() -> Result<()> {
+        let ctx = garden_ctx(MAX_PAGE_SCAN as usize + 5)?;
+
+        let launched = Some(ShitcoinStatus::Launched {});
+
+        let page = query_shitcoins(&ctx, mock_env(), None, None, None, launched)?;
+
+        assert!(page.shitcoins.is_empty());
+        assert_eq!(page.next, Some(MAX_PAGE_SCAN - 1));
+
+        let page = query_shitcoins(&ctx, mock_env(), page.next, None, None, launched)?;
+
+        assert!(page.shitcoins.is_empty());
+        assert_eq!(page.next, None);
+
+        // the limit only counts matches, the scan limit everything looked at
+        let active = Some(ShitcoinStatus::Active {});
+
+        let page = query_shitcoins(&ctx, mock_env(), Some(50), None, None, active)?;
+
+        assert_eq!(tickers(&page), coin_tickers(51..61));
+        assert_eq!(page.next, Some(60));
+
+        Ok(())
+    }
+}
+
+mod preseeded_pool {
+    use super::*;
+
+    #[test]
+    fn empty_pool_seeded_at_presale_price() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = presale_ctx()?.launch_shitcoin(&denom)?;
+
+        let DexMsg::SeedPool { assets, .. } = &ctx.external.dex_msgs[1] else {
+            panic!("expected the pool to be seeded");
+        };
+
+        let pool = ctx.pool(&denom);
+
+        assert_eq!(&pool.assets, assets);
+        assert_eq!(pool.total_share, assets[0].amount);
+
+        Ok(())
+    }
+
+    #[test]
+    fn launch_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = presale_ctx().unwrap();
+
+        ctx.preseed_pool(&denom, 1, 1_000_000);
+
+        let err = ctx.launch_shitcoin(&denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[pool_preseeded] somebody seeded the pool before launch, claim a refund instead"
+        );
+    }
+
+    #[test]
+    fn donation_absorbed_at_launch() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = presale_ctx()?;
+
+        // far more than the whole raise
+        let donation = PRESALE_BUY_AMOUNT * 10;
+
+        ctx.donate_to_pool(&denom, donation);
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        let DexMsg::SeedPool { assets, .. } = &ctx.external.dex_msgs[1] else {
+            panic!("expected the pool to be seeded");
+        };
+
+        // the donation stays in the pool, backing the garden's LP share
+        let pool = ctx.pool(&denom);
+
+        assert_eq!(pool.assets[0].amount, assets[0].amount);
+        assert_eq!(
+            pool.assets[1].amount,
+            assets[1].amount + Uint128::new(donation)
+        );
+        assert_eq!(pool.total_share, assets[0].amount);
+
+        assert!(query_shitcoin(&ctx, &denom)?.launched);
+
+        Ok(())
+    }
+
+    #[test]
+    fn donation_does_not_open_refunds() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = presale_ctx().unwrap();
+
+        ctx.donate_to_pool(&denom, PRESALE_BUY_AMOUNT * 10);
+
+        let err = ctx.refund_presale("degen", &denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_not_failed] no refunds ser, the presale reached its soft cap"
+        );
+    }
+
+    #[test]
+    fn refunds_open() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = presale_ctx()?;
+
+        ctx.preseed_pool(&denom, 1, 1_000_000);
+
+        let ctx = ctx.refund_presale("degen", &denom)?;
+
+        let fee = PRESALE_BUY_AMOUNT * ctx.config.presale_fee_rate as u128 / 10_000;
+
+        assert_eq!(
+            ctx.external.balances[&("degen".to_owned(), ctx.presale_denom())],
+            PRESALE_BUY_AMOUNT - fee
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn emptied_pool_still_cannot_launch_after_refunds() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = presale_ctx()?;
+
+        ctx.preseed_pool(&denom, 1, 1_000_000);
+
+        let ctx = ctx.refund_presale("degen", &denom)?;
+
+        // the sniper pulls their liquidity again
+        let emptied = PoolResponse {
+            assets: ctx
+                .pool(&denom)
+                .assets
+                .into_iter()
+                .map(|asset| Asset {
+                    amount: Uint128::zero(),
+                    ..asset
+                })
+                .collect(),
+            total_share: Uint128::zero(),
+        };
+
+        ctx.external
+            .pools
+            .borrow_mut()
+            .insert(pool_address(&denom, &ctx.presale_denom()), emptied);
+
+        let err = ctx.launch_shitcoin(&denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[pool_preseeded] somebody seeded the pool before launch, claim a refund instead"
+        );
+
+        Ok(())
+    }
+}
+
+mod dex {
+    use cosmwasm_std::Decimal;
+
+    use crate::dex::ConcentratedPoolParams;
+
+    use super::*;
+
+    fn create_on(dex: Dex) -> Result<Ctx> {
+        initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            ShitcoinParams {
+                dex: Some(dex),
+                ..Default::default()
+            },
+        )
+    }
+    #[test]
+    fn defaults_to_config() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        assert_eq!(
+            query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?.dex,
+            Dex::AstroportXyk {}
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_launch() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = create_on(Dex::Mock {})?;
+
+        let pool_address = pool_address(&denom, &ctx.presale_denom());
+
+        assert!(matches!(
+            &ctx.external.dex_msgs[0],
+            DexMsg::CreatePool {
+                pair_type: None,
+                ..
+            }
+        ));
+
+        let ctx = ctx
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        assert_eq!(shitcoin.dex, Dex::Mock {});
+        assert_eq!(shitcoin.pair, Some(pool_address.clone()));
+        assert_eq!(shitcoin.lp_amount, Some(shitcoin.supply / Uint128::new(2)));
+
+        assert_eq!(
+            ctx.external.dex_msgs[1],
+            DexMsg::SeedPool {
+                contract: pool_address,
+                assets: vec![
+                    Asset {
+                        info: AssetInfo::native(&denom),
+                        amount: shitcoin.supply / Uint128::new(2)
+                    },
+                    Asset {
+                        info: AssetInfo::native(ctx.presale_denom()),
+                        amount: shitcoin.presale_raise
+                    }
+                ]
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_donation_launches() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = create_on(Dex::Mock {})?.enter_presale("degen", &denom, 1_000_000_000)?;
+
+        ctx.donate_to_pool(&denom, 10_000_000_000);
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        assert!(query_shitcoin(&ctx, &denom)?.launched);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_preseeded_launch_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = create_on(Dex::Mock {})
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000_000)
+            .unwrap();
+
+        ctx.preseed_pool(&denom, 1, 1_000_000);
+
+        let err = ctx.launch_shitcoin(&denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[pool_preseeded] somebody seeded the pool before launch, claim a refund instead"
+        );
+    }
+
+    #[test]
+    fn pcl_pool_created_at_launch_price() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = create_on(Dex::AstroportPcl {})?;
+
+        assert!(ctx.external.dex_msgs.is_empty());
+        assert_eq!(query_shitcoin(&ctx, &denom)?.pair, None);
+
+        let ctx = ctx
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        let DexMsg::CreatePool {
+            pair_type,
+            init_params,
+            ..
+        } = &ctx.external.dex_msgs[0]
+        else {
+            panic!("expected the pool to be created at launch");
+        };
+
+        assert_eq!(
+            pair_type,
+            &Some(PairType::Custom("concentrated".to_owned()))
+        );
+
+        let params: ConcentratedPoolParams = from_json(init_params.as_ref().unwrap())?;
+
+        assert_eq!(
+            params.price_scale,
+            Decimal::from_ratio(shitcoin.supply / Uint128::new(2), shitcoin.presale_raise)
+        );
+
+        let pool_address = pool_address(&denom, &ctx.presale_denom());
+
+        assert!(matches!(
+            &ctx.external.dex_msgs[1],
+            DexMsg::SeedPool { contract, .. } if contract == &pool_address
+        ));
+
+        assert_eq!(shitcoin.pair, Some(pool_address));
+        assert_eq!(shitcoin.lp_amount, Some(shitcoin.supply / Uint128::new(2)));
+
+        Ok(())
+    }
+
+    fn enable_dexes(ctx: &mut Ctx, dexes: &[Dex]) -> Result<Response, ContractError> {
+        let dex_factories = ctx
+            .config
+            .dex_factories
+            .iter()
+            .filter(|dex_factory| dexes.contains(&dex_factory.dex))
+            .cloned()
+            .collect();
+
+        execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: Some(dex_factories),
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )
+    }
+
+    #[test]
+    fn pools_created_through_the_dex_factory() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = create_on(Dex::Mock {})?;
+
+        assert!(matches!(
+            &ctx.external.dex_msgs[0],
+            DexMsg::CreatePool { contract, .. } if contract == "mock_factory"
+        ));
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    dex: Some(Dex::AstroportPcl {}),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?;
+
+        assert!(matches!(
+            &ctx.external.dex_msgs[0],
+            DexMsg::CreatePool { contract, .. } if contract == "pcl_factory"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_dex_fails() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        enable_dexes(&mut ctx, &[Dex::AstroportXyk {}])?;
+
+        let err = ctx
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    dex: Some(Dex::Mock {}),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[dex_not_enabled] the mock dex is not enabled"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn launch_uses_creation_factory_after_dex_disabled() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx =
+            create_on(Dex::AstroportPcl {})?.enter_presale("degen", &denom, 1_000_000_000)?;
+
+        enable_dexes(&mut ctx, &[Dex::AstroportXyk {}])?;
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        assert!(matches!(
+            &ctx.external.dex_msgs[0],
+            DexMsg::CreatePool { contract, .. } if contract == "pcl_factory"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn disabling_default_dex_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = enable_dexes(&mut ctx, &[Dex::Mock {}]).unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::DexNotEnabled {
+                dex: "astroport_xyk".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn duplicate_dex_factories_fail() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let mut dex_factories = ctx.config.dex_factories.clone();
+
+        dex_factories.push(DexFactory {
+            dex: Dex::AstroportXyk {},
+            address: "other_xyk_factory".to_owned(),
+        });
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: Some(dex_factories),
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::InvalidDexFactories);
+    }
+}
+
+mod presale_denoms {
+    use super::*;
+
+    fn create_in(presale_denom: &str) -> Result<Ctx> {
+        initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            ShitcoinParams {
+                presale_denom: Some(presale_denom.to_owned()),
+                ..Default::default()
+            },
+        )
+    }
+    fn update_presale_denoms(ctx: &mut Ctx, presale_denoms: &[&str]) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: Some(presale_denoms.iter().map(|d| d.to_string()).collect()),
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    #[test]
+    fn defaults_to_first_accepted() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        assert_eq!(
+            query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?.presale_denom,
+            "uatom"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn chosen_denom_used_from_presale_to_launch() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = create_in("uusdc")?;
+
+        let pool_address = pool_address(&denom, "uusdc");
+
+        assert_eq!(
+            ctx.external.dex_msgs[0],
+            DexMsg::CreatePool {
+                contract: "xyk_factory".to_owned(),
+                asset_infos: vec![AssetInfo::native(&denom), AssetInfo::native("uusdc")],
+                pair_type: Some(PairType::Xyk {}),
+                init_params: None,
+            }
+        );
+
+        let ctx = ctx
+            .enter_presale("degen", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom)?;
+
+        assert_eq!(shitcoin.presale_denom, "uusdc");
+
+        assert_eq!(
+            ctx.external.balances[&("fee_recipient".to_owned(), "uusdc".to_owned())],
+            2_500_000
+        );
+
+        assert_eq!(
+            ctx.external.dex_msgs[1],
+            DexMsg::SeedPool {
+                contract: pool_address,
+                assets: vec![
+                    Asset {
+                        info: AssetInfo::native(&denom),
+                        amount: shitcoin.supply / Uint128::new(2)
+                    },
+                    Asset {
+                        info: AssetInfo::native("uusdc"),
+                        amount: shitcoin.presale_raise
+                    }
                 ]
             }
         );
@@ -807,247 +4726,2218 @@ mod launch_shitcoin {
     }
 
     #[test]
-    fn presale_not_ended_fails() {
+    fn entering_with_another_accepted_denom_fails() {
+        let mut ctx = create_in("uusdc").unwrap();
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("degen", &[coin(1_000_000, "uatom")]),
+            ExecuteMsg::EnterPresale {
+                denom: denom(&mock_env(), "meme"),
+                referrer: None,
+                allowlist_proof: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[missing_presale_funds] you must send uusdc to enter the presale"
+        );
+    }
+
+    #[test]
+    fn unaccepted_denom_fails() {
+        let err = create_in("uosmo").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_denom_not_accepted] uosmo is not accepted for presales"
+        );
+    }
+
+    #[test]
+    fn delisted_denom_kept_by_existing_shitcoins() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = create_in("uusdc")?;
+
+        update_presale_denoms(&mut ctx, &["uatom"])?;
+
+        assert_eq!(query_config(&ctx)?.presale_denoms, vec!["uatom".to_owned()]);
+
+        let ctx = ctx.enter_presale("degen", &denom, 1_000_000)?;
+
+        assert_eq!(query_shitcoin(&ctx, &denom)?.presale_denom, "uusdc");
+
+        let err = ctx
+            .create_shitcoin_with(
+                "creator",
+                "PUMP",
+                "pumpcoin",
+                1_000_000,
+                ShitcoinParams {
+                    presale_denom: Some("uusdc".to_owned()),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[presale_denom_not_accepted] uusdc is not accepted for presales"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_allowlist_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        for presale_denoms in [vec![], vec!["uatom", "uatom"]] {
+            let err = update_presale_denoms(&mut ctx, &presale_denoms).unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "[invalid_presale_denoms] there has to be at least one presale denom and no \
+                 duplicates"
+            );
+        }
+    }
+}
+
+mod fees {
+    use super::*;
+
+    fn address(address: &str, weight: u32) -> FeeRecipient {
+        FeeRecipient {
+            destination: FeeDestination::Address {
+                address: address.to_owned(),
+            },
+            weight,
+        }
+    }
+
+    fn update_fees(
+        ctx: &mut Ctx,
+        fee_recipients: Option<Vec<FeeRecipient>>,
+        creator_fee_share: Option<u32>,
+    ) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients,
+                creator_fee_share,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    fn weighted_ctx() -> Result<Ctx> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        let fee_recipients = vec![
+            address("treasury", 2),
+            address("dev_fund", 1),
+            FeeRecipient {
+                destination: FeeDestination::Burn {},
+                weight: 1,
+            },
+        ];
+
+        update_fees(&mut ctx, Some(fee_recipients), Some(2_000))?;
+
+        Ok(ctx)
+    }
+    #[test]
+    fn create_fee_split_by_weight() -> Result<()> {
+        let ctx = weighted_ctx()?.create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        assert_eq!(balance(&ctx, "treasury", "untrn"), 500_000);
+        assert_eq!(balance(&ctx, "dev_fund", "untrn"), 250_000);
+        assert_eq!(ctx.external.burned["untrn"], 250_000);
+        assert_eq!(balance(&ctx, "creator", "untrn"), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn presale_fee_dust_goes_to_first_recipient() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        // a 101 fee gives the creator 20, leaving 81 to split 2:1:1
+        let ctx = weighted_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 20_200)?;
+
+        assert_eq!(balance(&ctx, "creator", "uatom"), 20);
+        assert_eq!(balance(&ctx, "treasury", "uatom"), 41);
+        assert_eq!(balance(&ctx, "dev_fund", "uatom"), 20);
+        assert_eq!(ctx.external.burned["uatom"], 20);
+
+        Ok(())
+    }
+
+    #[test]
+    fn withdraw_penalty_split_by_weight() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        // a 10% penalty on 1_000_000 is 100_000, 20_000 of it to the creator
+        let ctx = weighted_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_005_025)?
+            .withdraw_presale("degen", &denom, 1_000_000)?;
+
+        assert_eq!(balance(&ctx, "creator", "uatom"), 1_005 + 20_000);
+        assert_eq!(balance(&ctx, "treasury", "uatom"), 2_010 + 40_000);
+        assert_eq!(balance(&ctx, "dev_fund", "uatom"), 1_005 + 20_000);
+        assert_eq!(ctx.external.burned["uatom"], 1_005 + 20_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_creator_share_pays_only_the_platform() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?;
+
+        update_fees(&mut ctx, None, Some(0))?;
+
+        let ctx = ctx
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_000_000)?;
+
+        assert_eq!(balance(&ctx, "creator", "uatom"), 0);
+        assert_eq!(balance(&ctx, "fee_recipient", "uatom"), 5_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_fee_recipients_fail() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        for fee_recipients in [vec![], vec![address("treasury", 1), address("dev_fund", 0)]] {
+            let err = update_fees(&mut ctx, Some(fee_recipients), None).unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "[invalid_fee_recipients] there has to be at least one fee recipient and every \
+                 weight has to be greater than zero"
+            );
+        }
+    }
+
+    #[test]
+    fn creator_share_above_everything_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = update_fees(&mut ctx, None, Some(10_001)).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_creator_fee_share] creator fee share has to be at most 10000 bps"
+        );
+    }
+}
+
+mod referrals {
+    use crate::{error::ErrorCode, msg::ReferrerStats};
+
+    use super::*;
+
+    fn update_referral_fee_share(ctx: &mut Ctx, referral_fee_share: u32) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: Some(referral_fee_share),
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: None,
+                max_contribution: None,
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    fn query_referrer_stats(ctx: &Ctx, address: &str) -> Result<ReferrerStats> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ReferrerStats {
+                address: address.to_owned(),
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+    #[test]
+    fn referrer_paid_from_entry_fee() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        // a 5_000 fee gives the referrer 500, the rest is split evenly as usual
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale_with("degen", &denom, 1_000_000, Some("shiller"))?;
+
+        assert_eq!(balance(&ctx, "shiller", "uatom"), 500);
+        assert_eq!(balance(&ctx, "creator", "uatom"), 2_250);
+        assert_eq!(balance(&ctx, "fee_recipient", "uatom"), 2_250);
+
+        assert_eq!(
+            query_referrer_stats(&ctx, "shiller")?,
+            ReferrerStats {
+                address: "shiller".to_owned(),
+                referrals: 1,
+                earnings: vec![coin(500, "uatom")],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn earnings_tracked_per_presale_denom() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .create_shitcoin_with(
+                "creator",
+                "PUMP",
+                "pumpcoin",
+                1_000_000,
+                ShitcoinParams {
+                    presale_denom: Some("uusdc".to_owned()),
+                    ..Default::default()
+                },
+            )?
+            .enter_presale_with(
+                "degen1",
+                &denom(&mock_env(), "meme"),
+                1_000_000,
+                Some("shiller"),
+            )?
+            .enter_presale_with(
+                "degen2",
+                &denom(&mock_env(), "pump"),
+                2_000_000,
+                Some("shiller"),
+            )?
+            .enter_presale_with(
+                "degen2",
+                &denom(&mock_env(), "meme"),
+                1_000_000,
+                Some("shiller"),
+            )?;
+
+        assert_eq!(
+            query_referrer_stats(&ctx, "shiller")?,
+            ReferrerStats {
+                address: "shiller".to_owned(),
+                referrals: 3,
+                earnings: vec![coin(1_000, "uatom"), coin(1_000, "uusdc")],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_referral_share_counts_referrals_only() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?;
+
+        update_referral_fee_share(&mut ctx, 0)?;
+
+        let ctx = ctx
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale_with("degen", &denom, 1_000_000, Some("shiller"))?;
+
+        assert_eq!(balance(&ctx, "shiller", "uatom"), 0);
+        assert_eq!(balance(&ctx, "creator", "uatom"), 2_500);
+
+        let stats = query_referrer_stats(&ctx, "shiller")?;
+
+        assert_eq!(stats.referrals, 1);
+        assert!(stats.earnings.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_referrer_has_no_stats() -> Result<()> {
+        let ctx = initialized_contract_ctx()?;
+
+        assert_eq!(
+            query_referrer_stats(&ctx, "nobody")?,
+            ReferrerStats {
+                address: "nobody".to_owned(),
+                referrals: 0,
+                earnings: vec![],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_has_referrer() {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap();
+
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("degen", &[coin(1_000_000, ctx.presale_denom())]),
+            ExecuteMsg::EnterPresale {
+                denom,
+                referrer: Some("shiller".to_owned()),
+                allowlist_proof: None,
+            },
+        )
+        .unwrap();
+
+        let event = &response.events[0];
+
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "referrer" && attr.value == "shiller"));
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "referral_fee" && attr.value == "500"));
+    }
+
+    #[test]
+    fn self_referral_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale_with("degen", &denom, 1_000_000, Some("degen"))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[self_referral] nice try ser, you cannot refer yourself"
+        );
+    }
+
+    #[test]
+    fn creator_referral_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale_with("degen", &denom, 1_000_000, Some("creator"))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[creator_referral] the shitcoin creator cannot be a referrer"
+        );
+    }
+
+    #[test]
+    fn invalid_referrer_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = initialized_contract_ctx()
+            .unwrap()
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
+            .unwrap()
+            .enter_presale_with("degen", &denom, 1_000_000, Some("Shiller"))
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+
+        assert_eq!(err.code(), ErrorCode::Std);
+    }
+
+    #[test]
+    fn referral_share_above_everything_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = update_referral_fee_share(&mut ctx, 10_001).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_referral_fee_share] referral fee share has to be at most 10000 bps"
+        );
+    }
+}
+
+mod allowlist {
+    use cosmwasm_std::{Env, HexBinary};
+
+    use crate::{
+        error::ErrorCode,
+        merkle::{self, Hash},
+        msg::ShitcoinMetadata,
+    };
+
+    use super::*;
+
+    const ALLOWLIST_LENGTH: u64 = 60 * 60 * 24;
+
+    /// Builds the tree level by level, carrying odd nodes up unchanged, and returns the root with
+    /// the proof of every address.
+    fn allowlist_tree(addresses: &[&str]) -> (HexBinary, HashMap<String, Vec<HexBinary>>) {
+        let mut proofs: HashMap<String, Vec<HexBinary>> = HashMap::new();
+
+        // every node keeps track of the addresses below it
+        let mut level: Vec<(Hash, Vec<&str>)> = addresses
+            .iter()
+            .map(|address| (merkle::leaf(address), vec![*address]))
+            .collect();
+
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [(left, left_addresses), (right, right_addresses)] => {
+                        for address in left_addresses {
+                            proofs
+                                .entry(address.to_string())
+                                .or_default()
+                                .push(HexBinary::from(right.as_slice()));
+                        }
+
+                        for address in right_addresses {
+                            proofs
+                                .entry(address.to_string())
+                                .or_default()
+                                .push(HexBinary::from(left.as_slice()));
+                        }
+
+                        let addresses = [left_addresses.clone(), right_addresses.clone()].concat();
+
+                        (merkle::parent(left, right), addresses)
+                    }
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        (HexBinary::from(level[0].0.as_slice()), proofs)
+    }
+
+    fn allowlisted_ctx(addresses: &[&str]) -> Result<(Ctx, HashMap<String, Vec<HexBinary>>)> {
+        let (root, proofs) = allowlist_tree(addresses);
+
+        let ctx = initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            ShitcoinParams {
+                allowlist: Some(Allowlist {
+                    root,
+                    length: ALLOWLIST_LENGTH,
+                }),
+                ..Default::default()
+            },
+        )?;
+
+        Ok((ctx, proofs))
+    }
+
+    fn enter_allowlisted(
+        ctx: &mut Ctx,
+        env: Env,
+        degen: &str,
+        allowlist_proof: Option<Vec<HexBinary>>,
+    ) -> Result<Response, ContractError> {
+        execute(
+            ctx.deps.as_mut(),
+            env,
+            mock_info(degen, &[coin(1_000_000, ctx.presale_denom())]),
+            ExecuteMsg::EnterPresale {
+                denom: denom(&mock_env(), "meme"),
+                referrer: None,
+                allowlist_proof,
+            },
+        )
+    }
+    fn public_phase_env() -> Env {
+        let mut env = mock_env();
+
+        env.block.time = env.block.time.plus_seconds(ALLOWLIST_LENGTH);
+
+        env
+    }
+
+    #[test]
+    fn valid_proofs_enter() -> Result<()> {
+        let degens = ["degen1", "degen2", "degen3", "degen4", "degen5"];
+
+        let (mut ctx, proofs) = allowlisted_ctx(&degens)?;
+
+        for degen in degens {
+            enter_allowlisted(&mut ctx, mock_env(), degen, Some(proofs[degen].clone()))?;
+        }
+
+        assert_eq!(
+            query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?
+                .presale_raise
+                .u128(),
+            5 * 995_000
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn single_address_allowlist_has_an_empty_proof() -> Result<()> {
+        let (mut ctx, _) = allowlisted_ctx(&["degen"])?;
+
+        enter_allowlisted(&mut ctx, mock_env(), "degen", Some(vec![]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn someone_elses_proof_fails() {
+        let (mut ctx, proofs) = allowlisted_ctx(&["degen1", "degen2", "degen3"]).unwrap();
+
+        let err = enter_allowlisted(
+            &mut ctx,
+            mock_env(),
+            "griff",
+            Some(proofs["degen1"].clone()),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_on_allowlist] ser you are not on the allowlist"
+        );
+    }
+
+    #[test]
+    fn malformed_proof_fails() {
+        let (mut ctx, _) = allowlisted_ctx(&["degen1", "degen2"]).unwrap();
+
+        let err = enter_allowlisted(
+            &mut ctx,
+            mock_env(),
+            "degen1",
+            Some(vec![HexBinary::from(b"too short".as_slice())]),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_on_allowlist] ser you are not on the allowlist"
+        );
+    }
+
+    #[test]
+    fn missing_proof_fails_during_allowlist_phase() {
+        let (mut ctx, _) = allowlisted_ctx(&["degen1", "degen2"]).unwrap();
+
+        let err = enter_allowlisted(&mut ctx, mock_env(), "degen1", None).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "[allowlist_proof_required] this presale is allowlist only until {}, send a proof",
+                mock_env().block.time.seconds() + ALLOWLIST_LENGTH
+            )
+        );
+    }
+
+    #[test]
+    fn anyone_enters_once_the_allowlist_phase_ends() -> Result<()> {
+        let (mut ctx, _) = allowlisted_ctx(&["degen1", "degen2"])?;
+
+        let mut last_allowlist_second = public_phase_env();
+
+        last_allowlist_second.block.time = last_allowlist_second.block.time.minus_seconds(1);
+
+        let err = enter_allowlisted(&mut ctx, last_allowlist_second, "griff", None).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::AllowlistProofRequired);
+
+        enter_allowlisted(&mut ctx, public_phase_env(), "griff", None)?;
+
+        // proofs are not checked anymore, even bad ones
+        enter_allowlisted(&mut ctx, public_phase_env(), "griff", Some(vec![]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_has_phase_times() -> Result<()> {
+        let (root, _) = allowlist_tree(&["degen1", "degen2"]);
+
+        let (ctx, _) = allowlisted_ctx(&["degen1", "degen2"])?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?;
+
+        assert_eq!(shitcoin.allowlist_root, Some(root));
+        assert_eq!(
+            shitcoin.allowlist_end,
+            Some(mock_env().block.time.seconds() + ALLOWLIST_LENGTH)
+        );
+        assert_eq!(
+            shitcoin.presale_end,
+            mock_env().block.time.seconds() + ctx.config.presale_length
+        );
+
+        let ctx = ctx.create_shitcoin("creator", "PUMP", "pumpcoin", 1_000_000)?;
+
+        let shitcoin: ShitcoinMetadata = from_json(query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinMetadata {
+                denom: denom(&mock_env(), "pump"),
+            },
+        )?)?;
+
+        assert_eq!(shitcoin.allowlist_root, None);
+        assert_eq!(shitcoin.allowlist_end, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_allowlist_fails() {
+        let (root, _) = allowlist_tree(&["degen1", "degen2"]);
+
+        let presale_length = initialized_contract_ctx().unwrap().config.presale_length;
+
+        let cases = [
+            (
+                HexBinary::from(b"not a hash".as_slice()),
+                ALLOWLIST_LENGTH,
+                "[invalid_allowlist_root] allowlist root has to be a 32 byte sha256 hash",
+            ),
+            (
+                root.clone(),
+                0,
+                "[invalid_allowlist_length] allowlist phase has to be greater than zero and end \
+                 before the presale does",
+            ),
+            (
+                root,
+                presale_length,
+                "[invalid_allowlist_length] allowlist phase has to be greater than zero and end \
+                 before the presale does",
+            ),
+        ];
+
+        for (root, length, expected) in cases {
+            let err = initialized_contract_ctx()
+                .unwrap()
+                .create_shitcoin_with(
+                    "creator",
+                    "MEME",
+                    "memecoin",
+                    1_000_000,
+                    ShitcoinParams {
+                        allowlist: Some(Allowlist { root, length }),
+                        ..Default::default()
+                    },
+                )
+                .unwrap_err();
+
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+}
+
+mod contribution_limits {
+    use crate::msg::DegenMetadata;
+
+    use super::*;
+
+    fn update_contribution_limits(
+        ctx: &mut Ctx,
+        min_contribution: Option<u128>,
+        max_contribution: Option<u128>,
+    ) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("contract_deployer", &[]),
+            ExecuteMsg::UpdateConfig {
+                dex_factories: None,
+                dex: None,
+                fee_recipients: None,
+                creator_fee_share: None,
+                referral_fee_share: None,
+                create_fee_denom: None,
+                create_fee: None,
+                presale_denoms: None,
+                presale_length: None,
+                min_presale_length: None,
+                max_presale_length: None,
+                presale_fee_rate: None,
+                presale_withdraw_penalty_rate: None,
+                min_contribution: min_contribution.map(Into::into),
+                max_contribution: max_contribution.map(Into::into),
+                min_lp_allocation: None,
+                max_creator_allocation: None,
+                creator_vesting_cliff: None,
+                creator_vesting_duration: None,
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    // entering 1_000_000 is a 995_000 submission after the 0.5% fee
+    fn limited_ctx(params: ShitcoinParams) -> Result<Ctx> {
+        initialized_contract_ctx()?.create_shitcoin_with(
+            "creator",
+            "MEME",
+            "memecoin",
+            1_000_000,
+            ShitcoinParams {
+                min_contribution: Some(995_000),
+                max_contribution: Some(1_990_000),
+                ..params
+            },
+        )
+    }
+    fn query_degen(ctx: &Ctx, degen: &str) -> Result<DegenMetadata> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom(&mock_env(), "meme"),
+                degen: degen.to_owned(),
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+
+    #[test]
+    fn limits_in_metadata() -> Result<()> {
+        let ctx = limited_ctx(ShitcoinParams::default())?.enter_presale(
+            "degen",
+            &denom(&mock_env(), "meme"),
+            1_000_000,
+        )?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?;
+
+        assert_eq!(shitcoin.min_contribution, Some(995_000u128.into()));
+        assert_eq!(shitcoin.max_contribution, Some(1_990_000u128.into()));
+
+        assert_eq!(
+            query_degen(&ctx, "degen")?,
+            DegenMetadata {
+                presale_submission: 995_000u128.into(),
+                shitcoins_claimed: false,
+                presale_refunded: false,
+                min_contribution: Some(995_000u128.into()),
+                max_contribution: Some(1_990_000u128.into()),
+                remaining_contribution: Some(995_000u128.into()),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn remaining_contribution_bounded_by_hard_cap() -> Result<()> {
+        let ctx = limited_ctx(ShitcoinParams {
+            hard_cap: Some(2_985_000),
+            ..Default::default()
+        })?
+        .enter_presale("degen1", &denom(&mock_env(), "meme"), 2_000_000)?;
+
+        assert_eq!(
+            query_degen(&ctx, "degen1")?.remaining_contribution,
+            Some(Uint128::zero())
+        );
+        assert_eq!(
+            query_degen(&ctx, "degen2")?.remaining_contribution,
+            Some(995_000u128.into())
+        );
+
+        let ctx = ctx.create_shitcoin("creator", "PUMP", "pumpcoin", 1_000_000)?;
+
+        let degen: DegenMetadata = from_json(query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::DegenMetadata {
+                denom: denom(&mock_env(), "pump"),
+                degen: "degen1".to_owned(),
+            },
+        )?)?;
+
+        assert_eq!(degen.remaining_contribution, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn config_defaults_apply_to_new_shitcoins() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        update_contribution_limits(&mut ctx, Some(1_000), Some(2_000_000))?;
+
+        let config = query_config(&ctx)?;
+
+        assert_eq!(config.min_contribution, Some(1_000u128.into()));
+        assert_eq!(config.max_contribution, Some(2_000_000u128.into()));
+
+        let ctx = ctx.create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let shitcoin = query_shitcoin(&ctx, &denom(&mock_env(), "meme"))?;
+
+        assert_eq!(shitcoin.min_contribution, Some(1_000u128.into()));
+        assert_eq!(shitcoin.max_contribution, Some(2_000_000u128.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_removes_config_default() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        update_contribution_limits(&mut ctx, Some(1_000), Some(2_000_000))?;
+        update_contribution_limits(&mut ctx, Some(0), None)?;
+
+        let config = query_config(&ctx)?;
+
+        assert_eq!(config.min_contribution, None);
+        assert_eq!(config.max_contribution, Some(2_000_000u128.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn below_minimum_fails() {
+        let err = limited_ctx(ShitcoinParams::default())
+            .unwrap()
+            .enter_presale("degen", &denom(&mock_env(), "meme"), 500_000)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[contribution_below_minimum] you have to contribute at least 995000 in total"
+        );
+    }
+
+    #[test]
+    fn topping_up_above_minimum_passes() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = limited_ctx(ShitcoinParams::default())?
+            .enter_presale("degen", &denom, 1_000_000)?
+            .enter_presale("degen", &denom, 200)?;
+
+        assert_eq!(
+            query_degen(&ctx, "degen")?.presale_submission.u128(),
+            995_199
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn above_maximum_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = limited_ctx(ShitcoinParams::default())
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_000)
+            .unwrap()
+            .enter_presale("degen", &denom, 1_000_201)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[contribution_above_maximum] you can only contribute 995000 more to this presale"
+        );
+    }
+
+    #[test]
+    fn exactly_maximum_passes() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx =
+            limited_ctx(ShitcoinParams::default())?.enter_presale("degen", &denom, 2_000_000)?;
+
+        assert_eq!(
+            query_degen(&ctx, "degen")?.remaining_contribution,
+            Some(Uint128::zero())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn withdrawing_below_minimum_fails() {
+        let denom = denom(&mock_env(), "meme");
+
+        let err = limited_ctx(ShitcoinParams::default())
+            .unwrap()
+            .enter_presale("degen", &denom, 1_500_000)
+            .unwrap()
+            .withdraw_presale("degen", &denom, 1_000)
+            .unwrap()
+            .withdraw_presale("degen", &denom, 500_000)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[contribution_below_minimum] you have to contribute at least 995000 in total"
+        );
+    }
+
+    #[test]
+    fn withdrawing_everything_passes() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = limited_ctx(ShitcoinParams::default())?
+            .enter_presale("degen", &denom, 1_000_000)?
+            .withdraw_presale("degen", &denom, 995_000)?;
+
+        assert!(query_degen(&ctx, "degen")?.presale_submission.is_zero());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_limits_fail() {
+        for (min_contribution, max_contribution) in
+            [(Some(0), None), (None, Some(0)), (Some(2_000), Some(1_000))]
+        {
+            let err = initialized_contract_ctx()
+                .unwrap()
+                .create_shitcoin_with(
+                    "creator",
+                    "MEME",
+                    "memecoin",
+                    1_000_000,
+                    ShitcoinParams {
+                        min_contribution,
+                        max_contribution,
+                        ..Default::default()
+                    },
+                )
+                .unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "[invalid_contribution_limits] contribution limits have to be greater than zero \
+                 with the minimum at most the maximum"
+            );
+        }
+
+        let mut ctx = initialized_contract_ctx().unwrap();
+
+        let err = update_contribution_limits(&mut ctx, Some(2_000), Some(1_000)).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[invalid_contribution_limits] contribution limits have to be greater than zero with \
+             the minimum at most the maximum"
+        );
+    }
+}
+
+mod indexes {
+    use crate::msg::PositionPage;
+
+    use super::*;
+
+    fn query_shitcoins_by_creator(
+        ctx: &Ctx,
+        creator: &str,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<ShitcoinPage> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::ShitcoinsByCreator {
+                creator: creator.to_owned(),
+                start_after,
+                limit,
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+
+    fn query_positions_by_degen(
+        ctx: &Ctx,
+        degen: &str,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<PositionPage> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::PositionsByDegen {
+                degen: degen.to_owned(),
+                start_after,
+                limit,
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+    #[test]
+    fn shitcoins_by_creator() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator1", "MEME", "memecoin", 1_000_000)?
+            .create_shitcoin("creator2", "PEPE", "pepecoin", 1_000_000)?
+            .create_shitcoin("creator1", "DOGE", "dogecoin", 1_000_000)?;
+
+        let page = query_shitcoins_by_creator(&ctx, "creator1", None, None)?;
+
+        assert_eq!(page.total, 2);
+        assert_eq!(tickers(&page), vec!["MEME", "DOGE"]);
+        assert_eq!(page.next, None);
+
+        let page = query_shitcoins_by_creator(&ctx, "creator2", None, None)?;
+
+        assert_eq!(page.total, 1);
+        assert_eq!(tickers(&page), vec!["PEPE"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shitcoins_by_creator_paginated() -> Result<()> {
+        let mut ctx = initialized_contract_ctx()?;
+
+        for idx in 0..5 {
+            let ticker = format!("COIN{idx}");
+
+            ctx = ctx.create_shitcoin("creator", &ticker, &ticker.to_lowercase(), 1_000_000)?;
+        }
+
+        let page = query_shitcoins_by_creator(&ctx, "creator", None, Some(2))?;
+
+        assert_eq!(page.total, 5);
+        assert_eq!(tickers(&page), vec!["COIN0", "COIN1"]);
+        assert_eq!(page.next, Some(1));
+
+        let page = query_shitcoins_by_creator(&ctx, "creator", page.next, Some(2))?;
+
+        assert_eq!(tickers(&page), vec!["COIN2", "COIN3"]);
+        assert_eq!(page.next, Some(3));
+
+        let page = query_shitcoins_by_creator(&ctx, "creator", page.next, Some(2))?;
+
+        assert_eq!(tickers(&page), vec!["COIN4"]);
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn positions_by_degen() -> Result<()> {
+        let meme = denom(&mock_env(), "meme");
+        let pepe = denom(&mock_env(), "pepe");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .create_shitcoin("creator", "PEPE", "pepecoin", 1_000_000)?
+            .enter_presale("degen", &pepe, 1_000_000)?
+            .enter_presale("degen", &meme, 1_000_000)?
+            .enter_presale("degen", &pepe, 1_000_000)?;
+
+        let page = query_positions_by_degen(&ctx, "degen", None, None)?;
+
+        assert_eq!(page.total, 2);
+        assert_eq!(page.next, None);
+
+        let denoms: Vec<_> = page
+            .positions
+            .iter()
+            .map(|position| position.denom.clone())
+            .collect();
+
+        assert_eq!(denoms, vec![pepe.clone(), meme.clone()]);
+
+        for position in &page.positions {
+            assert!(!position.presale_submission.is_zero());
+            assert!(!position.shitcoins_claimed);
+            assert_eq!(position.claimable, Uint128::zero());
+        }
+
+        assert!(page.positions[0].presale_submission > page.positions[1].presale_submission);
+
+        Ok(())
+    }
+
+    #[test]
+    fn positions_by_degen_claimable() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen1", &denom, 1_000_000_000)?
+            .enter_presale("degen2", &denom, 1_000_000_000)?
+            .launch_shitcoin(&denom)?;
+
+        let page = query_positions_by_degen(&ctx, "degen1", None, None)?;
+
+        let claimable = page.positions[0].claimable;
+
+        assert!(!claimable.is_zero());
+
+        let ctx = ctx.claim_shitcoin("degen1", &denom)?;
+
+        assert_eq!(
+            *ctx.external
+                .balances
+                .get(&("degen1".to_owned(), denom.clone()))
+                .unwrap(),
+            claimable.u128()
+        );
+
+        let page = query_positions_by_degen(&ctx, "degen1", None, None)?;
+
+        assert!(page.positions[0].shitcoins_claimed);
+        assert_eq!(page.positions[0].claimable, Uint128::zero());
+
+        Ok(())
+    }
+
+    #[test]
+    fn positions_by_degen_skip_withdrawn_and_flag_refunded() -> Result<()> {
+        let meme = denom(&mock_env(), "meme");
+        let pepe = denom(&mock_env(), "pepe");
+        let doge = denom(&mock_env(), "doge");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin_with(
+                "creator",
+                "MEME",
+                "memecoin",
+                1_000_000,
+                ShitcoinParams {
+                    soft_cap: Some(1_000_000_000),
+                    ..Default::default()
+                },
+            )?
+            .create_shitcoin("creator", "PEPE", "pepecoin", 1_000_000)?
+            .create_shitcoin("creator", "DOGE", "dogecoin", 1_000_000)?
+            .enter_presale("degen", &meme, 1_000_000)?
+            .enter_presale("degen", &pepe, 1_000_000)?
+            .enter_presale("degen", &doge, 1_000_000)?
+            .withdraw_presale("degen", &pepe, 995_000)?;
+
+        // the withdrawn presale leaves a gap, the others keep their place
+        let page = query_positions_by_degen(&ctx, "degen", None, None)?;
+
+        let denoms: Vec<_> = page
+            .positions
+            .iter()
+            .map(|position| position.denom.clone())
+            .collect();
+
+        assert_eq!(page.total, 2);
+        assert_eq!(denoms, vec![meme.clone(), doge]);
+        assert!(!page.positions[0].presale_refunded);
+
+        let ctx = ctx.refund_presale("degen", &meme)?;
+
+        let page = query_positions_by_degen(&ctx, "degen", None, None)?;
+
+        assert_eq!(page.total, 2);
+        assert!(page.positions[0].presale_refunded);
+        assert!(!page.positions[1].presale_refunded);
+
+        Ok(())
+    }
+
+    #[test]
+    fn positions_by_degen_withdrawn_between_pages() -> Result<()> {
+        let meme = denom(&mock_env(), "meme");
+        let pepe = denom(&mock_env(), "pepe");
+        let doge = denom(&mock_env(), "doge");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .create_shitcoin("creator", "PEPE", "pepecoin", 1_000_000)?
+            .create_shitcoin("creator", "DOGE", "dogecoin", 1_000_000)?
+            .enter_presale("degen", &meme, 1_000_000)?
+            .enter_presale("degen", &pepe, 1_000_000)?
+            .enter_presale("degen", &doge, 1_000_000)?;
+
+        let denoms = |page: &PositionPage| -> Vec<String> {
+            page.positions
+                .iter()
+                .map(|position| position.denom.clone())
+                .collect()
+        };
+
+        let page = query_positions_by_degen(&ctx, "degen", None, Some(2))?;
+
+        assert_eq!(denoms(&page), vec![meme.clone(), pepe.clone()]);
+        assert_eq!(page.next, Some(1));
+
+        let ctx = ctx.withdraw_presale("degen", &meme, 995_000)?;
+
+        // nothing is skipped or seen twice
+        let page = query_positions_by_degen(&ctx, "degen", page.next, Some(2))?;
+
+        assert_eq!(page.total, 2);
+        assert_eq!(denoms(&page), vec![doge.clone()]);
+        assert_eq!(page.next, None);
+
+        // entering again puts the presale at the end
+        let ctx = ctx.enter_presale("degen", &meme, 1_000_000)?;
+
+        let page = query_positions_by_degen(&ctx, "degen", None, None)?;
+
+        assert_eq!(page.total, 3);
+        assert_eq!(denoms(&page), vec![pepe, doge, meme]);
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_address_is_empty() -> Result<()> {
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let page = query_shitcoins_by_creator(&ctx, "nobody", None, None)?;
+
+        assert_eq!(page.total, 0);
+        assert!(page.shitcoins.is_empty());
+        assert_eq!(page.next, None);
+
+        let page = query_positions_by_degen(&ctx, "nobody", None, None)?;
+
+        assert_eq!(page.total, 0);
+        assert!(page.positions.is_empty());
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+}
+
+mod launch_queries {
+    use cosmwasm_std::Decimal;
+
+    use crate::msg::{Claimable, LaunchSimulation};
+
+    use super::*;
+
+    fn split_presale_ctx() -> Result<Ctx> {
+        presale_ctx()?.enter_presale(
+            "degen2",
+            &denom(&mock_env(), "meme"),
+            PRESALE_BUY_AMOUNT / 3,
+        )
+    }
+
+    fn query_claimable(ctx: &Ctx, denom: &str, degen: &str) -> Result<Claimable> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::Claimable {
+                denom: denom.to_owned(),
+                degen: degen.to_owned(),
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+
+    fn query_simulate_launch(ctx: &Ctx, denom: &str) -> Result<LaunchSimulation> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::SimulateLaunch {
+                denom: denom.to_owned(),
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+    #[test]
+    fn claimable_matches_claim() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = split_presale_ctx()?;
+
+        let before_launch = query_claimable(&ctx, &denom, "degen2")?;
+
+        assert!(!before_launch.claimable);
+        assert!(!before_launch.amount.is_zero());
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        let claimable = query_claimable(&ctx, &denom, "degen2")?;
+
+        assert!(claimable.claimable);
+        assert_eq!(claimable.amount, before_launch.amount);
+
+        let ctx = ctx.claim_shitcoin("degen2", &denom)?;
+
+        assert_eq!(balance(&ctx, "degen2", &denom), claimable.amount.u128());
+
+        let claimed = query_claimable(&ctx, &denom, "degen2")?;
+
+        assert!(!claimed.claimable);
+        assert_eq!(claimed.amount, Uint128::zero());
+
+        Ok(())
+    }
+
+    #[test]
+    fn claimable_without_submission_is_zero() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = presale_ctx()?.launch_shitcoin(&denom)?;
+
+        let claimable = query_claimable(&ctx, &denom, "nobody")?;
+
+        assert_eq!(
+            claimable,
+            Claimable {
+                denom,
+                degen: "nobody".to_owned(),
+                amount: Uint128::zero(),
+                claimable: false,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn claimable_unknown_shitcoin_fails() -> Result<()> {
+        let ctx = presale_ctx()?;
+
+        assert!(query_claimable(&ctx, "factory/nope/nope", "degen").is_err());
+        assert!(query_simulate_launch(&ctx, "factory/nope/nope").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn simulation_matches_seeded_pool() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = split_presale_ctx()?;
+
+        let simulation = query_simulate_launch(&ctx, &denom)?;
+
+        // 0.5% entry fees, half the million supply goes to the pool
+        let presale_raise = 995_000_000 + 331_666_667;
+
+        assert_eq!(simulation.lp_shitcoin_amount, Uint128::new(500_000));
+        assert_eq!(simulation.lp_presale_amount, Uint128::new(presale_raise));
+        assert_eq!(
+            simulation.price,
+            Decimal::from_ratio(presale_raise, 500_000u128)
+        );
+        assert_eq!(simulation.market_cap, Uint128::new(presale_raise * 2));
+
+        let ctx = ctx.launch_shitcoin(&denom)?;
+
+        let pool = ctx.pool(&denom);
+
+        let pool_amount = |asset_denom: &str| {
+            pool.assets
+                .iter()
+                .find(|asset| asset.info == AssetInfo::native(asset_denom))
+                .map(|asset| asset.amount)
+        };
+
+        assert_eq!(pool_amount(&denom), Some(simulation.lp_shitcoin_amount));
+        assert_eq!(
+            pool_amount(&ctx.shitcoin_presale_denom(&denom)),
+            Some(simulation.lp_presale_amount)
+        );
+
+        Ok(())
+    }
+}
+
+mod presale_participants {
+    use crate::msg::{Participant, ParticipantPage};
+
+    use super::*;
+
+    fn query_participants(
+        ctx: &Ctx,
+        denom: &str,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<ParticipantPage> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::PresaleParticipants {
+                denom: denom.to_owned(),
+                start_after,
+                limit,
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+
+    fn addresses(page: &ParticipantPage) -> Vec<String> {
+        page.participants
+            .iter()
+            .map(|participant| participant.address.clone())
+            .collect()
+    }
+
+    #[test]
+    fn listed_in_order_of_entry() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen2", &denom, 1_000_000)?
+            .enter_presale("degen1", &denom, 2_000_000)?
+            .enter_presale("degen2", &denom, 1_000_000)?;
+
+        let page = query_participants(&ctx, &denom, None, None)?;
+
+        assert_eq!(page.total, 2);
+        assert_eq!(page.next, None);
+        assert_eq!(
+            page.participants,
+            vec![
+                Participant {
+                    address: "degen2".to_owned(),
+                    presale_submission: Uint128::new(1_990_000),
+                    shitcoins_claimed: false,
+                },
+                Participant {
+                    address: "degen1".to_owned(),
+                    presale_submission: Uint128::new(1_990_000),
+                    shitcoins_claimed: false,
+                },
+            ]
+        );
+
+        assert_eq!(query_shitcoin(&ctx, &denom)?.participants, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn paginated() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        for idx in 0..5 {
+            ctx = ctx.enter_presale(&format!("degen{idx}"), &denom, 1_000_000)?;
+        }
+
+        let page = query_participants(&ctx, &denom, None, Some(3))?;
+
+        assert_eq!(page.total, 5);
+        assert_eq!(addresses(&page), vec!["degen0", "degen1", "degen2"]);
+        assert_eq!(page.next, Some(2));
+
+        let page = query_participants(&ctx, &denom, page.next, Some(3))?;
+
+        assert_eq!(addresses(&page), vec!["degen3", "degen4"]);
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn withdrawing_everything_leaves_the_presale() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen1", &denom, 1_000_000)?
+            .enter_presale("degen2", &denom, 1_000_000)?
+            .enter_presale("degen3", &denom, 1_000_000)?
+            .withdraw_presale("degen1", &denom, 500_000)?;
+
+        assert_eq!(query_shitcoin(&ctx, &denom)?.participants, 3);
+        assert_eq!(query_participants(&ctx, &denom, None, None)?.total, 3);
+
+        let ctx = ctx.withdraw_presale("degen1", &denom, 495_000)?;
+
+        let page = query_participants(&ctx, &denom, None, None)?;
+
+        assert_eq!(query_shitcoin(&ctx, &denom)?.participants, 2);
+        assert_eq!(page.total, 2);
+        assert_eq!(addresses(&page), vec!["degen2", "degen3"]);
+
+        // entering again counts as the latest entry
+        let ctx = ctx.enter_presale("degen1", &denom, 1_000_000)?;
+
+        let page = query_participants(&ctx, &denom, None, None)?;
+
+        assert_eq!(query_shitcoin(&ctx, &denom)?.participants, 3);
+        assert_eq!(page.total, 3);
+        assert_eq!(addresses(&page), vec!["degen2", "degen3", "degen1"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn participant_leaving_mid_iteration() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        for idx in 0..5 {
+            ctx = ctx.enter_presale(&format!("degen{idx}"), &denom, 1_000_000)?;
+        }
+
+        let page = query_participants(&ctx, &denom, None, Some(2))?;
+
+        assert_eq!(addresses(&page), vec!["degen0", "degen1"]);
+        assert_eq!(page.next, Some(1));
+
+        // one already listed and one not yet listed leave
+        let ctx = ctx
+            .withdraw_presale("degen0", &denom, 995_000)?
+            .withdraw_presale("degen3", &denom, 995_000)?;
+
+        let page = query_participants(&ctx, &denom, page.next, Some(2))?;
+
+        assert_eq!(page.total, 3);
+        assert_eq!(addresses(&page), vec!["degen2", "degen4"]);
+        assert_eq!(page.next, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn claimed_status() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen1", &denom, 1_000_000)?
+            .enter_presale("degen2", &denom, 1_000_000)?
+            .launch_shitcoin(&denom)?
+            .claim_shitcoin("degen2", &denom)?;
+
+        let page = query_participants(&ctx, &denom, None, None)?;
+
+        assert!(!page.participants[0].shitcoins_claimed);
+        assert!(page.participants[1].shitcoins_claimed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_shitcoin_fails() -> Result<()> {
+        let ctx = initialized_contract_ctx()?;
+
+        assert!(query_participants(&ctx, "factory/nope/nope", None, None).is_err());
+
+        Ok(())
+    }
+}
+
+mod pause {
+    use crate::{
+        error::ErrorCode,
+        msg::{Operation, PauseStatus},
+    };
+
+    use super::*;
+
+    fn set_guardian(ctx: &mut Ctx, sender: &str, guardian: Option<&str>) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::SetGuardian {
+                guardian: guardian.map(str::to_owned),
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    fn pause(
+        ctx: &mut Ctx,
+        sender: &str,
+        operation: Operation,
+        denom: Option<&str>,
+    ) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::Pause {
+                operation,
+                denom: denom.map(str::to_owned),
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    fn unpause(
+        ctx: &mut Ctx,
+        sender: &str,
+        operation: Operation,
+        denom: Option<&str>,
+    ) -> Result<Response> {
+        let response = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::Unpause {
+                operation,
+                denom: denom.map(str::to_owned),
+            },
+        )?;
+
+        Ok(response)
+    }
+
+    fn query_pause_status(ctx: &Ctx, denom: Option<&str>) -> Result<PauseStatus> {
+        let query_response = query(
+            ctx.deps.as_ref(),
+            mock_env(),
+            QueryMsg::PauseStatus {
+                denom: denom.map(str::to_owned),
+            },
+        )?;
+
+        Ok(from_json(query_response)?)
+    }
+
+    fn error_code(err: Error) -> ErrorCode {
+        err.downcast::<ContractError>().unwrap().code()
+    }
+
+    fn guarded_ctx() -> Result<Ctx> {
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .create_shitcoin("creator", "PEPE", "pepecoin", 1_000_000)?;
+
+        set_guardian(&mut ctx, "contract_deployer", Some("guardian"))?;
+
+        Ok(ctx)
+    }
+
+    #[test]
+    fn guardian_pauses_everywhere() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = guarded_ctx()?.enter_presale("degen", &denom, 1_000_000)?;
+
+        assert_eq!(query_config(&ctx)?.guardian.as_deref(), Some("guardian"));
+
+        pause(&mut ctx, "guardian", Operation::LaunchShitcoin {}, None)?;
+
+        let err = ctx.launch_shitcoin(&denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[operation_paused] launch_shitcoin is paused, hold tight ser"
+        );
+        assert_eq!(error_code(err), ErrorCode::OperationPaused);
+
+        let mut ctx = guarded_ctx()?.enter_presale("degen", &denom, 1_000_000)?;
+
+        pause(&mut ctx, "guardian", Operation::LaunchShitcoin {}, None)?;
+        unpause(&mut ctx, "guardian", Operation::LaunchShitcoin {}, None)?;
+
+        ctx.launch_shitcoin(&denom)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn pause_for_one_shitcoin() -> Result<()> {
+        let meme = denom(&mock_env(), "meme");
+        let pepe = denom(&mock_env(), "pepe");
+
+        let mut ctx = guarded_ctx()?;
+
+        pause(
+            &mut ctx,
+            "contract_deployer",
+            Operation::EnterPresale {},
+            Some(&meme),
+        )?;
+
+        let mut ctx = ctx.enter_presale("degen", &pepe, 1_000_000)?;
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "degen",
+                &[coin(1_000_000, ctx.shitcoin_presale_denom(&meme))],
+            ),
+            ExecuteMsg::EnterPresale {
+                denom: meme.clone(),
+                referrer: None,
+                allowlist_proof: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::OperationPaused);
+
+        unpause(
+            &mut ctx,
+            "contract_deployer",
+            Operation::EnterPresale {},
+            Some(&meme),
+        )?;
+
+        ctx.enter_presale("degen", &meme, 1_000_000)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn global_and_shitcoin_pauses_are_independent() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = guarded_ctx()?;
+
+        pause(&mut ctx, "guardian", Operation::SetUrl {}, None)?;
+        pause(&mut ctx, "guardian", Operation::SetUrl {}, Some(&denom))?;
+        unpause(&mut ctx, "guardian", Operation::SetUrl {}, None)?;
+
+        let err = execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            ExecuteMsg::SetUrl {
+                denom: denom.clone(),
+                url: "https://meme.wtf".to_owned(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::OperationPaused);
+
+        let status = query_pause_status(&ctx, Some(&denom))?;
+
+        assert_eq!(status.paused, vec![]);
+        assert_eq!(status.denom_paused, vec![Operation::SetUrl {}]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pause_status() -> Result<()> {
         let denom = denom(&mock_env(), "meme");
 
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen", &denom, 1_000_000_000)
-            .unwrap();
+        let mut ctx = guarded_ctx()?;
 
-        let err = execute(
-            ctx.deps.as_mut(),
-            mock_env(),
-            mock_info("launcher", &[]),
-            ExecuteMsg::LaunchShitcoin {
-                denom: denom.to_owned(),
-            },
-        )
-        .unwrap_err();
+        assert_eq!(
+            query_pause_status(&ctx, None)?,
+            PauseStatus {
+                paused: vec![],
+                denom_paused: vec![],
+            }
+        );
+
+        pause(&mut ctx, "guardian", Operation::ClaimShitcoin {}, None)?;
+        pause(&mut ctx, "guardian", Operation::CreateShitcoin {}, None)?;
+        pause(
+            &mut ctx,
+            "guardian",
+            Operation::ExtendPresale {},
+            Some(&denom),
+        )?;
 
         assert_eq!(
-            err.to_string(),
-            "patience young grasshopper the presale is not over"
+            query_pause_status(&ctx, Some(&denom))?,
+            PauseStatus {
+                paused: vec![Operation::CreateShitcoin {}, Operation::ClaimShitcoin {}],
+                denom_paused: vec![Operation::ExtendPresale {}],
+            }
         );
+
+        assert!(query_pause_status(&ctx, Some("factory/nope/nope")).is_err());
+
+        Ok(())
     }
 
     #[test]
-    fn shitcoin_already_launched_fails() {
+    fn withdrawals_stay_open() -> Result<()> {
         let denom = denom(&mock_env(), "meme");
 
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen", &denom, 1_000_000_000)
-            .unwrap()
-            .launch_shitcoin(&denom)
-            .unwrap();
+        let mut ctx = guarded_ctx()?.enter_presale("degen", &denom, 1_000_000)?;
+
+        for operation in [
+            Operation::EnterPresale {},
+            Operation::ExtendPresale {},
+            Operation::LaunchShitcoin {},
+            Operation::ClaimShitcoin {},
+            Operation::SetUrl {},
+        ] {
+            pause(&mut ctx, "guardian", operation, None)?;
+            pause(&mut ctx, "guardian", operation, Some(&denom))?;
+        }
 
-        let mut env = mock_env();
+        ctx.withdraw_presale("degen", &denom, 995_000)?;
 
-        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length + 1);
+        Ok(())
+    }
 
-        let err = execute(
-            ctx.deps.as_mut(),
-            env,
-            mock_info("launcher", &[]),
-            ExecuteMsg::LaunchShitcoin {
-                denom: denom.to_owned(),
-            },
+    #[test]
+    fn events_emitted() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = guarded_ctx()?;
+
+        let response = pause(
+            &mut ctx,
+            "guardian",
+            Operation::ClaimShitcoin {},
+            Some(&denom),
+        )?;
+
+        assert_eq!(
+            response.events,
+            vec![Event::new("shitcoin-garden-config")
+                .add_attribute("kind", "paused")
+                .add_attribute("operation", "claim_shitcoin")
+                .add_attribute("by", "guardian")
+                .add_attribute("denom", &denom)]
+        );
+
+        let response = unpause(&mut ctx, "guardian", Operation::ClaimShitcoin {}, None)?;
+
+        assert_eq!(
+            response.events,
+            vec![Event::new("shitcoin-garden-config")
+                .add_attribute("kind", "unpaused")
+                .add_attribute("operation", "claim_shitcoin")
+                .add_attribute("by", "guardian")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_paused_everywhere_only() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = guarded_ctx()?;
+
+        let err = pause(
+            &mut ctx,
+            "guardian",
+            Operation::CreateShitcoin {},
+            Some(&denom),
         )
         .unwrap_err();
 
-        assert_eq!(err.to_string(), "shitcoin already launched");
+        assert_eq!(error_code(err), ErrorCode::CreatePausedPerShitcoin);
+
+        pause(&mut ctx, "guardian", Operation::CreateShitcoin {}, None)?;
+
+        let err = ctx
+            .create_shitcoin("creator", "DOGE", "dogecoin", 1_000_000)
+            .unwrap_err();
+
+        assert_eq!(error_code(err), ErrorCode::OperationPaused);
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_admin_or_guardian_fails() -> Result<()> {
+        let mut ctx = guarded_ctx()?;
+
+        let err = pause(&mut ctx, "degen", Operation::LaunchShitcoin {}, None).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "[not_guardian] only the garden admin or guardian can do that"
+        );
+
+        let err = set_guardian(&mut ctx, "guardian", Some("degen")).unwrap_err();
+
+        assert_eq!(error_code(err), ErrorCode::NotAdmin);
+
+        set_guardian(&mut ctx, "contract_deployer", None)?;
+
+        assert_eq!(query_config(&ctx)?.guardian, None);
+
+        let err = pause(&mut ctx, "guardian", Operation::LaunchShitcoin {}, None).unwrap_err();
+
+        assert_eq!(error_code(err), ErrorCode::NotGuardian);
+
+        Ok(())
     }
 }
 
-mod claim_shitcoin {
-    use crate::msg::{DegenMetadata, ShitcoinMetadata};
+mod moderation {
+    use crate::{
+        error::ErrorCode,
+        msg::{Moderation, ShitcoinMetadata},
+    };
 
     use super::*;
 
-    #[test]
-    fn happy_path() -> Result<()> {
-        const PRESALE_BUY_AMOUNT: u128 = 1_000_000_000;
-        let denom = denom(&mock_env(), "meme");
-
-        let ctx = initialized_contract_ctx()?
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
-            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
-            .enter_presale("degen1", &denom, PRESALE_BUY_AMOUNT)?
-            .enter_presale("degen2", &denom, PRESALE_BUY_AMOUNT)?
-            .launch_shitcoin(&denom)?
-            .claim_shitcoin("degen1", &denom)?
-            .claim_shitcoin("degen2", &denom)?;
+    fn moderate(
+        ctx: &mut Ctx,
+        sender: &str,
+        denom: &str,
+        moderation: Moderation,
+    ) -> Result<Response, ContractError> {
+        execute(
+            ctx.deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::ModerateShitcoin {
+                denom: denom.to_owned(),
+                moderation,
+            },
+        )
+    }
 
+    fn moderation(ctx: &Ctx, denom: &str) -> Result<Moderation> {
         let query_response = query(
             ctx.deps.as_ref(),
             mock_env(),
             QueryMsg::ShitcoinMetadata {
-                denom: denom.clone(),
+                denom: denom.to_owned(),
             },
         )?;
 
         let shitcoin: ShitcoinMetadata = from_json(query_response)?;
 
-        let claimable_supply = shitcoin.supply.u128() / 2;
+        Ok(shitcoin.moderation)
+    }
 
-        let query_response = query(
-            ctx.deps.as_ref(),
-            mock_env(),
-            QueryMsg::DegenMetadata {
-                denom: denom.clone(),
-                degen: "degen1".to_owned(),
-            },
-        )?;
+    fn delisted(reason: &str) -> Moderation {
+        Moderation::Delisted {
+            reason: reason.to_owned(),
+        }
+    }
 
-        let degen1: DegenMetadata = from_json(query_response)?;
+    #[test]
+    fn visible_by_default() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
 
-        assert!(degen1.shitcoins_claimed);
+        let ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
 
-        assert_eq!(
-            *ctx.external
-                .balances
-                .get(&("degen1".to_owned(), denom.clone()))
-                .unwrap(),
-            (claimable_supply * degen1.presale_submission.u128()) / shitcoin.presale_raise.u128()
-        );
+        assert_eq!(moderation(&ctx, &denom)?, Moderation::Visible {});
+
+        Ok(())
+    }
+
+    #[test]
+    fn hidden_shitcoins_stay_open() -> Result<()> {
+        let denom = denom(&mock_env(), "meme");
+
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        moderate(&mut ctx, "contract_deployer", &denom, Moderation::Hidden {})?;
+
+        assert_eq!(moderation(&ctx, &denom)?, Moderation::Hidden {});
 
         let query_response = query(
             ctx.deps.as_ref(),
             mock_env(),
-            QueryMsg::DegenMetadata {
-                denom: denom.clone(),
-                degen: "degen2".to_owned(),
+            QueryMsg::Shitcoins {
+                start_after: None,
+                limit: None,
+                order: None,
+                status: None,
             },
         )?;
 
-        let degen2: DegenMetadata = from_json(query_response)?;
+        let page: ShitcoinPage = from_json(query_response)?;
 
-        assert!(degen2.shitcoins_claimed);
+        assert_eq!(page.shitcoins[0].moderation, Moderation::Hidden {});
 
-        assert_eq!(
-            *ctx.external
-                .balances
-                .get(&("degen2".to_owned(), denom.clone()))
-                .unwrap(),
-            (claimable_supply * degen2.presale_submission.u128()) / shitcoin.presale_raise.u128()
-        );
+        ctx.enter_presale("degen", &denom, 1_000_000)?;
 
         Ok(())
     }
 
     #[test]
-    fn presale_ongoing_fails() {
+    fn delisting_closes_the_presale() -> Result<()> {
         let denom = denom(&mock_env(), "meme");
 
-        let mut ctx = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen", &denom, 1_000_000)
-            .unwrap();
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
+            .enter_presale("degen", &denom, 1_000_000)?;
 
-        let mut env = mock_env();
+        moderate(&mut ctx, "contract_deployer", &denom, delisted("rude"))?;
 
-        env.block.time = env.block.time.plus_seconds(ctx.config.presale_length - 1);
+        assert_eq!(moderation(&ctx, &denom)?, delisted("rude"));
 
         let err = execute(
             ctx.deps.as_mut(),
-            env,
-            mock_info("degen", &[]),
-            ExecuteMsg::ClaimShitcoin {
-                denom: denom.to_owned(),
+            mock_env(),
+            mock_info(
+                "degen",
+                &[coin(1_000_000, ctx.shitcoin_presale_denom(&denom))],
+            ),
+            ExecuteMsg::EnterPresale {
+                denom: denom.clone(),
+                referrer: None,
+                allowlist_proof: None,
             },
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "patience young grasshopper the presale is not over"
+            "[delisted] this shitcoin was delisted: rude"
         );
+        assert_eq!(err.code(), ErrorCode::Delisted);
+
+        // degens who are already in can still get out
+        let mut ctx = ctx.withdraw_presale("degen", &denom, 500_000)?;
+
+        moderate(
+            &mut ctx,
+            "contract_deployer",
+            &denom,
+            Moderation::Visible {},
+        )?;
+
+        assert_eq!(moderation(&ctx, &denom)?, Moderation::Visible {});
+
+        ctx.enter_presale("degen", &denom, 1_000_000)?;
+
+        Ok(())
     }
 
     #[test]
-    fn shitcoin_not_launched_fails() {
+    fn event_emitted() -> Result<()> {
         let denom = denom(&mock_env(), "meme");
 
-        let err = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen", &denom, 1_000_000)
-            .unwrap()
-            .claim_shitcoin("degen", &denom)
-            .unwrap_err();
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let response = moderate(&mut ctx, "contract_deployer", &denom, delisted("rude"))?;
 
         assert_eq!(
-            err.to_string(),
-            "shitcoin needs to be launched before claiming"
+            response.events,
+            vec![Event::new("shitcoin-garden")
+                .add_attribute("kind", "shitcoin-moderated")
+                .add_attribute("denom", &denom)
+                .add_attribute("moderation", "delisted")
+                .add_attribute("reason", "rude")]
         );
+
+        Ok(())
     }
 
     #[test]
-    fn shitcoins_already_claimed_fails() {
+    fn not_admin_fails() -> Result<()> {
         let denom = denom(&mock_env(), "meme");
 
-        let err = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen", &denom, 1_000_000)
-            .unwrap()
-            .launch_shitcoin(&denom)
-            .unwrap()
-            .claim_shitcoin("degen", &denom)
-            .unwrap()
-            .claim_shitcoin("degen", &denom)
-            .unwrap_err();
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
+
+        let err = moderate(&mut ctx, "creator", &denom, Moderation::Hidden {}).unwrap_err();
 
-        assert_eq!(err.to_string(), "shitcoins already claimed");
+        assert_eq!(err.code(), ErrorCode::NotAdmin);
+
+        Ok(())
     }
 
     #[test]
-    fn did_not_enter_presale_fails() {
+    fn invalid_reason_fails() -> Result<()> {
         let denom = denom(&mock_env(), "meme");
 
-        let err = initialized_contract_ctx()
-            .unwrap()
-            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)
-            .unwrap()
-            .enter_presale("degen", &denom, 1_000_000)
-            .unwrap()
-            .launch_shitcoin(&denom)
-            .unwrap()
-            .claim_shitcoin("griff", &denom)
-            .unwrap_err();
+        let mut ctx = initialized_contract_ctx()?
+            .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?;
 
-        assert_eq!(
-            err.to_string(),
-            "ser you did not enter this shitcoin presale"
-        );
-    }
-}
+        for reason in [String::new(), "x".repeat(257)] {
+            let err =
+                moderate(&mut ctx, "contract_deployer", &denom, delisted(&reason)).unwrap_err();
 
-#[test]
-fn shitcoins_query() -> Result<()> {
-    let ctx = initialized_contract_ctx()?
-        .create_shitcoin("creator", "MEME", "memecoin", 1_000_000)?
-        .create_shitcoin("creator", "EMERALD", "emerald", 1_000)?;
+            assert_eq!(
+                err.to_string(),
+                "[invalid_delist_reason] delist reason must be between 1 and 256 characters"
+            );
+        }
 
-    let binary = query(
-        ctx.deps.as_ref(),
-        mock_env(),
-        QueryMsg::Shitcoins {
-            page: None,
-            limit: Some(100),
-        },
-    )?;
+        assert_eq!(moderation(&ctx, &denom)?, Moderation::Visible {});
+
+        Ok(())
+    }
 
-    let _shitcoin_page: ShitcoinPage = cosmwasm_std::from_json(binary)?;
+    #[test]
+    fn unknown_shitcoin_fails() {
+        let mut ctx = initialized_contract_ctx().unwrap();
 
-    Ok(())
+        let err = moderate(
+            &mut ctx,
+            "contract_deployer",
+            "factory/nope/nope",
+            Moderation::Hidden {},
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::Std);
+    }
 }
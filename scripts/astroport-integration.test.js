@@ -32,13 +32,26 @@ const uploadReceipt = await client.upload(walletAddress, wasm, uploadFee, "SG-IY
 console.log("uploaded contract")
 
 const initMsg = {
-    pool_factory_address: poolFactory,
-    fee_recipient: walletAddress,
+    dex_factories: [
+        { dex: { astroport_xyk: {} }, address: poolFactory },
+        { dex: { astroport_pcl: {} }, address: poolFactory },
+    ],
+    dex: { astroport_xyk: {} },
+    fee_recipients: [{ destination: { address: { address: walletAddress } }, weight: 1 }],
+    creator_fee_share: 5000,
+    referral_fee_share: 1000,
     create_fee_denom: chainDenom,
     create_fee: "10000",
-    presale_denom: chainDenom,
+    presale_denoms: [chainDenom],
     presale_length: 20, // seconds
+    min_presale_length: 10,
+    max_presale_length: 60 * 60 * 24 * 7,
     presale_fee_rate: 50,
+    presale_withdraw_penalty_rate: 1000,
+    min_lp_allocation: 3000,
+    max_creator_allocation: 1000,
+    creator_vesting_cliff: 60 * 60 * 24 * 30,
+    creator_vesting_duration: 60 * 60 * 24 * 180,
 };
 const instantiateFee = calculateFee(500_000, gasPrice);
 const { contractAddress } = await client.instantiate(
@@ -12,13 +12,25 @@ const chainDenom     = process.env.DEPLOY_CHAIN_DENOM;
 const chainRpc       = process.env.DEPLOY_CHAIN_RPC;
 const chainGasPrice  = process.env.DEPLOY_CHAIN_GAS_PRICE;
 const label          = process.env.DEPLOY_LABEL;
-const poolFactory    = process.env.DEPLOY_POOL_FACTORY;
+const dexFactories   = process.env.DEPLOY_DEX_FACTORIES; // comma separated dex:factory, dexes are astroport_xyk, astroport_pcl or mock
+const dex            = process.env.DEPLOY_DEX; // the default, has to be one of the dex factories
 const createFeeDenom = process.env.DEPLOY_CREATE_FEE_DENOM;
 const createFee      = process.env.DEPLOY_CREATE_FEE;
-const feeRecipient   = process.env.DEPLOY_FEE_RECIPIENT;
-const presaleDenom   = process.env.DEPLOY_PRESALE_DENOM;
+const feeRecipients  = process.env.DEPLOY_FEE_RECIPIENTS; // comma separated address:weight, burn:weight burns
+const creatorFeeShare = process.env.DEPLOY_CREATOR_FEE_SHARE;
+const referralFeeShare = process.env.DEPLOY_REFERRAL_FEE_SHARE;
+const presaleDenoms  = process.env.DEPLOY_PRESALE_DENOMS; // comma separated, the first is the default
 const presaleLength  = process.env.DEPLOY_PRESALE_LENGTH;
+const minPresaleLength = process.env.DEPLOY_MIN_PRESALE_LENGTH;
+const maxPresaleLength = process.env.DEPLOY_MAX_PRESALE_LENGTH;
 const presaleFeeRate = process.env.DEPLOY_PRESALE_FEE_RATE;
+const withdrawPenaltyRate = process.env.DEPLOY_PRESALE_WITHDRAW_PENALTY_RATE;
+const minContribution = process.env.DEPLOY_MIN_CONTRIBUTION; // optional
+const maxContribution = process.env.DEPLOY_MAX_CONTRIBUTION; // optional
+const minLpAllocation = process.env.DEPLOY_MIN_LP_ALLOCATION;
+const maxCreatorAllocation = process.env.DEPLOY_MAX_CREATOR_ALLOCATION;
+const creatorVestingCliff = process.env.DEPLOY_CREATOR_VESTING_CLIFF;
+const creatorVestingDuration = process.env.DEPLOY_CREATOR_VESTING_DURATION;
 
 if (!walletMnemonic) throw new Error("DEPLOY_WALLET_MNEMONIC env var not set");
 if (!walletAddress)  throw new Error("DEPLOY_WALLET_ADDRESS env var not set");
@@ -27,13 +39,23 @@ if (!chainDenom)     throw new Error("DEPLOY_CHAIN_DENOM env var not set");
 if (!chainRpc)       throw new Error("DEPLOY_CHAIN_RPC env var not set");
 if (!chainGasPrice)  throw new Error("DEPLOY_CHAIN_GAS_PRICE env var not set");
 if (!label)          throw new Error("DEPLOY_LABEL env var not set");
-if (!poolFactory)    throw new Error("DEPLOY_POOL_FACTORY env var not set");
+if (!dexFactories)   throw new Error("DEPLOY_DEX_FACTORIES env var not set");
+if (!dex)            throw new Error("DEPLOY_DEX env var not set");
 if (!createFeeDenom) throw new Error("DEPLOY_CREATE_FEE_DENOM env var not set");
 if (!createFee)      throw new Error("DEPLOY_CREATE_FEE env var not set");
-if (!feeRecipient)   throw new Error("DEPLOY_FEE_RECIPIENT env var not set");
-if (!presaleDenom)   throw new Error("DEPLOY_PRESALE_DENOM env var not set");
+if (!feeRecipients)  throw new Error("DEPLOY_FEE_RECIPIENTS env var not set");
+if (!creatorFeeShare) throw new Error("DEPLOY_CREATOR_FEE_SHARE env var not set");
+if (!referralFeeShare) throw new Error("DEPLOY_REFERRAL_FEE_SHARE env var not set");
+if (!presaleDenoms)  throw new Error("DEPLOY_PRESALE_DENOMS env var not set");
 if (!presaleLength)  throw new Error("DEPLOY_PRESALE_LENGTH env var not set");
+if (!minPresaleLength) throw new Error("DEPLOY_MIN_PRESALE_LENGTH env var not set");
+if (!maxPresaleLength) throw new Error("DEPLOY_MAX_PRESALE_LENGTH env var not set");
 if (!presaleFeeRate) throw new Error("DEPLOY_PRESALE_FEE_RATE env var not set");
+if (!withdrawPenaltyRate) throw new Error("DEPLOY_PRESALE_WITHDRAW_PENALTY_RATE env var not set");
+if (!minLpAllocation) throw new Error("DEPLOY_MIN_LP_ALLOCATION env var not set");
+if (!maxCreatorAllocation) throw new Error("DEPLOY_MAX_CREATOR_ALLOCATION env var not set");
+if (!creatorVestingCliff) throw new Error("DEPLOY_CREATOR_VESTING_CLIFF env var not set");
+if (!creatorVestingDuration) throw new Error("DEPLOY_CREATOR_VESTING_DURATION env var not set");
 
 const gasPrice = GasPrice.fromString(chainGasPrice);
 const wallet = await DirectSecp256k1HdWallet.fromMnemonic(walletMnemonic, { prefix: chainPrefix });
@@ -46,13 +68,32 @@ const uploadReceipt = await client.upload(walletAddress, wasm, uploadFee, label)
 console.log(`uploaded contract: ${uploadReceipt.codeId}`);
 
 const initMsg = {
-    pool_factory_address: poolFactory,
-    fee_recipient: feeRecipient,
+    dex_factories: dexFactories.split(",").map(dexFactory => {
+        const [dex, address] = dexFactory.split(":");
+        return { dex: { [dex]: {} }, address };
+    }),
+    dex: { [dex]: {} },
+    fee_recipients: feeRecipients.split(",").map(feeRecipient => {
+        const [address, weight] = feeRecipient.split(":");
+        const destination = address === "burn" ? { burn: {} } : { address: { address } };
+        return { destination, weight: +weight };
+    }),
+    creator_fee_share: +creatorFeeShare,
+    referral_fee_share: +referralFeeShare,
     create_fee_denom: createFeeDenom,
     create_fee: createFee,
-    presale_denom: presaleDenom,
+    presale_denoms: presaleDenoms.split(","),
     presale_length: +presaleLength,
+    min_presale_length: +minPresaleLength,
+    max_presale_length: +maxPresaleLength,
     presale_fee_rate: +presaleFeeRate,
+    presale_withdraw_penalty_rate: +withdrawPenaltyRate,
+    min_contribution: minContribution || null,
+    max_contribution: maxContribution || null,
+    min_lp_allocation: +minLpAllocation,
+    max_creator_allocation: +maxCreatorAllocation,
+    creator_vesting_cliff: +creatorVestingCliff,
+    creator_vesting_duration: +creatorVestingDuration,
 };
 const instantiateFee = calculateFee(500_000, gasPrice);
 const { contractAddress } = await client.instantiate(
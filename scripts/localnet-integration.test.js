@@ -47,13 +47,23 @@ const { contractAddress: mdContractAddress} = await client.instantiate(
 console.log(`instantiated mock dex: ${mdContractAddress}`);
 
 const sgInitMsg = {
-    pool_factory_address: mdContractAddress,
-    fee_recipient: walletAddress,
+    dex_factories: [{ dex: { mock: {} }, address: mdContractAddress }],
+    dex: { mock: {} }, // the mock dex creates a pool contract per shitcoin and keeps real reserves
+    fee_recipients: [{ destination: { address: { address: walletAddress } }, weight: 1 }],
+    creator_fee_share: 5000,
+    referral_fee_share: 1000,
     create_fee_denom: chainDenom,
     create_fee: "10000",
-    presale_denom: chainDenom,
+    presale_denoms: [chainDenom],
     presale_length: 20, // seconds
+    min_presale_length: 10,
+    max_presale_length: 60 * 60 * 24 * 7,
     presale_fee_rate: 50,
+    presale_withdraw_penalty_rate: 1000,
+    min_lp_allocation: 3000,
+    max_creator_allocation: 1000,
+    creator_vesting_cliff: 60 * 60 * 24 * 30,
+    creator_vesting_duration: 60 * 60 * 24 * 180,
 };
 const { contractAddress: sgContractAddress } = await client.instantiate(
   walletAddress,
@@ -99,6 +109,13 @@ await client.execute(walletAddress, sgContractAddress, enterPresaleMsg, executeF
   const shitcoin = await client.queryContractSmart(sgContractAddress, { shitcoin_metadata: { denom } });
 
   console.log(`shitcoin presale entered: ${shitcoin.presale_raise}`);
+
+  // dust sent straight to the pool is tolerated and must not block the launch
+  await client.sendTokens(walletAddress, shitcoin.pair, [coin(1, chainDenom)], executeFee);
+
+  const reserves = await client.queryContractSmart(shitcoin.pair, { reserves: {} });
+
+  console.log(`donated to pool: ${JSON.stringify(reserves.reserves)}`);
 }
 
 console.log("waiting 20 seconds for presale to have ended");
@@ -3,7 +3,6 @@ import {calculateFee, GasPrice}  from "@cosmjs/stargate";
 import {coin} from "@cosmjs/proto-signing";
 
 const SHITCOIN_GARDEN = window.SHITCOIN_GARDEN;
-const PRESALE_DENOM = window.PRESALE_DENOM;
 const gasPrice = GasPrice.fromString("0.01untrn");
 
 document.addEventListener('alpine:init', () => {
@@ -242,7 +241,7 @@ document.addEventListener('alpine:init', () => {
       return this.enteringPresale[denom];
     },
 
-    enterPresale(wallet, denom, amount) {
+    enterPresale(wallet, denom, presaleDenom, amount) {
       const enterPresaleMsg = {
         enter_presale: {
           denom
@@ -255,7 +254,7 @@ document.addEventListener('alpine:init', () => {
 
       console.log(`entering presale with ${enterAmount}:`, enterPresaleMsg);
 
-      wallet.client.execute(wallet.account.address, SHITCOIN_GARDEN, enterPresaleMsg, executeFee, "", [coin(enterAmount, PRESALE_DENOM)])
+      wallet.client.execute(wallet.account.address, SHITCOIN_GARDEN, enterPresaleMsg, executeFee, "", [coin(enterAmount, presaleDenom)])
         .then(_ => {
           if (!this.isToastSuppressed('PresaleEntered')) {
             this.toast("Presale Entered! You brave (degenerate) soul", 'PresaleEntered');
@@ -4,7 +4,7 @@ use cosmos_sdk_proto::cosmos::{
     base::tendermint::v1beta1::GetLatestBlockRequest,
 };
 
-use crate::{BankClient, TmClient, PRESALE_DENOM};
+use crate::{BankClient, TmClient};
 
 #[tracing::instrument]
 pub async fn latest_block_timestamp(tm: &mut TmClient) -> Result<u64> {
@@ -24,10 +24,10 @@ pub async fn latest_block_timestamp(tm: &mut TmClient) -> Result<u64> {
 }
 
 #[tracing::instrument]
-pub async fn query_balance(bank: &mut BankClient, degen: &str) -> Result<u128> {
+pub async fn query_balance(bank: &mut BankClient, degen: &str, denom: &str) -> Result<u128> {
     let balance_query_req = QueryBalanceRequest {
         address: degen.to_owned(),
-        denom: PRESALE_DENOM.to_owned(),
+        denom: denom.to_owned(),
     };
 
     let query_response = bank.balance(balance_query_req).await?;
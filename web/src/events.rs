@@ -11,8 +11,9 @@ use tokio::sync::broadcast::Sender;
 use crate::{
     chain::latest_block_timestamp,
     model::{
-        query_degen_submission, query_shitcoin_metadata, query_shitcoin_presale_end,
-        query_shitcoin_presale_raise, query_shitcoin_url, ShitcoinMeta,
+        query_degen_submission, query_shitcoin_delist_reason, query_shitcoin_hidden,
+        query_shitcoin_metadata, query_shitcoin_presale_end, query_shitcoin_presale_raise,
+        query_shitcoin_url, ShitcoinMeta,
     },
     CwClient, SharedState, TmClient, SHITCOIN_GARDEN_CONTRACT,
 };
@@ -25,6 +26,11 @@ pub enum ContractEventKind {
     ShitcoinLaunched,
     ShitcoinClaimed,
     ShitcoinUrlSet,
+    PresaleRefunded,
+    PresaleWithdrawn,
+    VestingClaimed,
+    LpClaimed,
+    ShitcoinModerated,
 }
 
 impl ContractEventKind {
@@ -36,6 +42,11 @@ impl ContractEventKind {
             ContractEventKind::ShitcoinLaunched => "ShitcoinLaunched",
             ContractEventKind::ShitcoinClaimed => "ShitcoinClaimed",
             ContractEventKind::ShitcoinUrlSet => "ShitcoinUrlSet",
+            ContractEventKind::PresaleRefunded => "PresaleRefunded",
+            ContractEventKind::PresaleWithdrawn => "PresaleWithdrawn",
+            ContractEventKind::VestingClaimed => "VestingClaimed",
+            ContractEventKind::LpClaimed => "LpClaimed",
+            ContractEventKind::ShitcoinModerated => "ShitcoinModerated",
         }
     }
 
@@ -83,6 +94,11 @@ fn parse_shitcoin_garden_event(event: Event) -> ContractEvent {
         "shitcoin-launched" => ContractEventKind::ShitcoinLaunched,
         "shitcoin-claimed" => ContractEventKind::ShitcoinClaimed,
         "shitcoin-url-set" => ContractEventKind::ShitcoinUrlSet,
+        "presale-refunded" => ContractEventKind::PresaleRefunded,
+        "presale-withdrawn" => ContractEventKind::PresaleWithdrawn,
+        "vesting-claimed" => ContractEventKind::VestingClaimed,
+        "lp-claimed" => ContractEventKind::LpClaimed,
+        "shitcoin-moderated" => ContractEventKind::ShitcoinModerated,
         _ => panic!("unexpected event kind: {kind_str}"),
     };
 
@@ -140,7 +156,7 @@ pub async fn monitor_contract_events(
                 state.shitcoins.insert(denom.clone(), shitcoin.clone());
             }
 
-            ContractEventKind::PresaleEntered => {
+            ContractEventKind::PresaleEntered | ContractEventKind::PresaleWithdrawn => {
                 let presale_raise = query_shitcoin_presale_raise(cw.clone(), &denom).await?;
 
                 let degen = degen.as_ref().unwrap();
@@ -178,6 +194,29 @@ pub async fn monitor_contract_events(
                 let url = query_shitcoin_url(cw.clone(), &denom).await?;
                 state.shitcoins.get_mut(&denom).unwrap().url = url;
             }
+
+            ContractEventKind::PresaleRefunded => {
+                let degen = degen.as_ref().unwrap();
+
+                state
+                    .degens
+                    .get_mut(&(denom.clone(), degen.clone()))
+                    .unwrap()
+                    .refunded = true
+            }
+
+            ContractEventKind::ShitcoinModerated => {
+                let hidden = query_shitcoin_hidden(cw.clone(), &denom).await?;
+                let delist_reason = query_shitcoin_delist_reason(cw.clone(), &denom).await?;
+
+                let shitcoin = state.shitcoins.get_mut(&denom).unwrap();
+
+                shitcoin.hidden = hidden;
+                shitcoin.delist_reason = delist_reason;
+            }
+
+            // vesting and liquidity are not tracked by the garden view
+            ContractEventKind::VestingClaimed | ContractEventKind::LpClaimed => {}
         }
 
         let shitcoin = state.shitcoins.get(&denom).unwrap().to_owned();
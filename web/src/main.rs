@@ -8,7 +8,7 @@ use std::{sync::Arc, time::Duration};
 use anyhow::{Context, Result};
 use askama::Template;
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::{
         sse::{Event as SseEvent, Sse},
@@ -25,6 +25,7 @@ use cosmos_sdk_proto::{
     cosmwasm::wasm::v1::query_client::QueryClient as CwQueryClient,
 };
 use futures::{Stream, TryStreamExt};
+use serde::Deserialize;
 use shuttle_axum::AxumService;
 use shuttle_runtime::SecretStore;
 use tendermint_rpc::{client::CompatMode, WebSocketClient, WebSocketClientUrl};
@@ -38,7 +39,7 @@ use tower_http::{
 use tracing::{level_filters::LevelFilter, Level};
 use tracing_subscriber::EnvFilter;
 
-use crate::model::query_entire_contract_state;
+use crate::model::{query_degen_claimable, query_entire_contract_state};
 
 use self::chain::{latest_block_timestamp, query_balance};
 use self::events::{monitor_contract_events, ContractEventKind, ShitcoinEvent, ShitcoinStream};
@@ -52,9 +53,6 @@ pub type TmClient = TmQueryClient<GrpcChannel>;
 pub const SHITCOIN_GARDEN_CONTRACT: &str =
     "neutron14yg2vvwt39fan0a5gc380e65ftydlxrpaax2l72ecxfy0l7xttts0szf73";
 
-pub const PRESALE_DENOM: &str =
-    "ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9";
-
 struct AppError(anyhow::Error);
 
 impl IntoResponse for AppError {
@@ -70,6 +68,7 @@ impl IntoResponse for AppError {
 #[derive(Debug, Clone)]
 struct Client {
     bank: BankClient,
+    cw: CwClient,
     tm: TmClient,
 }
 
@@ -109,15 +108,11 @@ async fn degen_presales(
 ) -> Result<PresalesTemplate, AppError> {
     let state = state.read().await;
 
-    let balance = query_balance(&mut client.bank, &degen)
-        .await
-        .context("querying balance")?;
-
     let chain_timestamp = latest_block_timestamp(&mut client.tm)
         .await
         .context("querying latest block time")?;
 
-    let view = PresalesTemplate::new_with_degen(&state, chain_timestamp, degen, balance);
+    let view = PresalesTemplate::new_with_degen(&state, chain_timestamp, degen);
 
     Ok(view)
 }
@@ -159,20 +154,52 @@ async fn degen_presale(
     ))
 }
 
+#[derive(Debug, Deserialize)]
+struct BalanceParams {
+    denom: String, // the presale denom of the shitcoin being entered
+}
+
 #[tracing::instrument]
 async fn degen_balance(
     Path(degen): Path<String>,
+    Query(BalanceParams { denom }): Query<BalanceParams>,
     State(AppState { mut client, .. }): State<AppState>,
 ) -> Result<String, AppError> {
-    let balance = query_balance(&mut client.bank, &degen).await?;
+    let balance = query_balance(&mut client.bank, &degen, &denom).await?;
 
     Ok(view::balance(balance))
 }
 
+/// The payout comes from the garden's Claimable query, so it accounts for everything the contract
+/// does at launch.
+#[tracing::instrument]
+async fn degen_claimable(
+    Path((ticker, degen)): Path<(String, String)>,
+    State(AppState { state, client }): State<AppState>,
+) -> Result<String, AppError> {
+    let denom = full_denom(&ticker);
+
+    let supply = state
+        .read()
+        .await
+        .shitcoins
+        .get(&denom)
+        .map(|shitcoin| shitcoin.supply)
+        .context("unknown shitcoin")?;
+
+    let amount = query_degen_claimable(client.cw, &denom, &degen).await?;
+
+    Ok(view::claimable(amount, supply))
+}
+
 async fn handle_updated_shitcoin(event: ShitcoinEvent) -> Result<SseEvent> {
     let event_type = event.kind.sse_event_type();
 
-    if let ContractEventKind::ShitcoinClaimed = event.kind {
+    if let ContractEventKind::ShitcoinClaimed
+    | ContractEventKind::PresaleRefunded
+    | ContractEventKind::VestingClaimed
+    | ContractEventKind::LpClaimed = event.kind
+    {
         return Ok(SseEvent::default());
     }
 
@@ -214,7 +241,13 @@ async fn handle_shitcoin_event_with_degen(
 
     let event_type = event.kind.sse_event_type();
 
-    if let (ContractEventKind::ShitcoinClaimed, Some(claimant)) = (event.kind, event.degen.as_ref())
+    if let (
+        ContractEventKind::ShitcoinClaimed
+        | ContractEventKind::PresaleRefunded
+        | ContractEventKind::VestingClaimed
+        | ContractEventKind::LpClaimed,
+        Some(claimant),
+    ) = (event.kind, event.degen.as_ref())
     {
         if claimant != degen.as_str() {
             return Ok(SseEvent::default());
@@ -316,7 +349,7 @@ async fn server(grpc_address: &str, ws_address: &str) -> Result<AxumService> {
     ));
 
     let state = AppState {
-        client: Client { bank, tm },
+        client: Client { bank, cw, tm },
         state,
     };
 
@@ -328,6 +361,7 @@ async fn server(grpc_address: &str, ws_address: &str) -> Result<AxumService> {
         .route("/presale/:denom", get(presale))
         .route("/presale/:denom/:degen", get(degen_presale))
         .route("/balance/:degen", get(degen_balance))
+        .route("/claimable/:ticker/:degen", get(degen_claimable))
         .route("/sse", get(sse_handler))
         .route("/sse/:degen", get(sse_degen_handler))
         .nest_service("/static", ServeDir::new("web/static"))
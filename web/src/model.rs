@@ -1,12 +1,14 @@
 use std::collections::{BTreeMap, HashMap};
 
 use anyhow::Result;
-use cosmos_sdk_proto::cosmwasm::wasm::v1::QueryRawContractStateRequest;
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    QueryRawContractStateRequest, QuerySmartContractStateRequest,
+};
 use cosmos_sdk_proto::{
     cosmos::base::query::v1beta1::PageRequest,
     cosmwasm::wasm::v1::{Model, QueryAllContractStateRequest},
 };
-use futures::future::{try_join, try_join3, try_join5};
+use futures::future::{try_join3, try_join4, try_join5};
 
 use crate::{CwClient, SHITCOIN_GARDEN_CONTRACT};
 
@@ -16,16 +18,27 @@ pub struct ShitcoinMeta {
     pub ticker: String,
     pub name: String,
     pub url: String,
+    pub presale_denom: String,
     pub presale_end: u64,
     pub presale_raise: u128,
     pub supply: u128,
     pub launched: bool,
+    pub hidden: bool,
+    pub delist_reason: Option<String>,
+}
+
+impl ShitcoinMeta {
+    /// Moderated shitcoins are left out of listings and never show their name or icon.
+    pub fn is_moderated(&self) -> bool {
+        self.hidden || self.delist_reason.is_some()
+    }
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct DegenMeta {
     pub submission: u128,
     pub claimed: bool,
+    pub refunded: bool,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize)]
@@ -35,14 +48,18 @@ pub struct ShitcoinGardenState {
     pub degens: HashMap<(String, String), DegenMeta>,
 }
 
+const PRESALE_DENOM: &[u8] = b"PRESALE_DENOM";
 const PRESALE_END: &[u8] = b"PRESALE_END";
 const PRESALE_RAISE: &[u8] = b"PRESALE_RAISE";
 const PRESALE_SUBMISSION: &[u8] = b"PRESALE_SUBMISSION";
 const PRESALE_CLAIMED: &[u8] = b"PRESALE_CLAIMED";
+const PRESALE_REFUNDED: &[u8] = b"PRESALE_REFUNDED";
 const SHITCOIN_CREATOR: &[u8] = b"SHITCOIN_CREATOR";
 const SHITCOIN_TICKER: &[u8] = b"SHITCOIN_TICKER";
 const SHITCOIN_NAME: &[u8] = b"SHITCOIN_NAME";
 const SHITCOIN_URL: &[u8] = b"SHITCOIN_URL";
+const SHITCOIN_HIDDEN: &[u8] = b"SHITCOIN_HIDDEN";
+const SHITCOIN_DELIST_REASON: &[u8] = b"SHITCOIN_DELIST_REASON";
 const SHITCOIN_SUPPLY: &[u8] = b"SHITCOIN_SUPPLY";
 const SHITCOIN_LAUNCHED: &[u8] = b"SHITCOIN_LAUNCHED";
 const SHITCOIN_DENOM: &[u8] = b"SHITCOIN_DENOM";
@@ -55,6 +72,21 @@ fn add_model_to_state(model: Model, state: &mut ShitcoinGardenState) {
     };
 
     match prefix {
+        PRESALE_DENOM => {
+            // the legacy global presale denom is picked up by decode_contract_state
+            let Some(denom_bytes) = parts.next() else {
+                return;
+            };
+
+            let denom = std::str::from_utf8(denom_bytes).unwrap();
+
+            state
+                .shitcoins
+                .entry(denom.to_owned())
+                .or_default()
+                .presale_denom = String::from_utf8(model.value).unwrap();
+        }
+
         PRESALE_END => {
             let denom_bytes = parts.next().unwrap();
 
@@ -111,6 +143,22 @@ fn add_model_to_state(model: Model, state: &mut ShitcoinGardenState) {
                 .claimed = matches!(model.value.as_slice(), &[1]);
         }
 
+        PRESALE_REFUNDED => {
+            let denom_bytes = parts.next().unwrap();
+
+            let denom = std::str::from_utf8(denom_bytes).unwrap();
+
+            let degen_bytes = parts.next().unwrap();
+
+            let degen = std::str::from_utf8(degen_bytes).unwrap();
+
+            state
+                .degens
+                .entry((denom.to_owned(), degen.to_owned()))
+                .or_default()
+                .refunded = matches!(model.value.as_slice(), &[1]);
+        }
+
         SHITCOIN_CREATOR => {
             let denom_bytes = parts.next().unwrap();
 
@@ -147,6 +195,27 @@ fn add_model_to_state(model: Model, state: &mut ShitcoinGardenState) {
                 String::from_utf8(model.value).unwrap();
         }
 
+        SHITCOIN_HIDDEN => {
+            let denom_bytes = parts.next().unwrap();
+
+            let denom = std::str::from_utf8(denom_bytes).unwrap();
+
+            state.shitcoins.entry(denom.to_owned()).or_default().hidden =
+                matches!(model.value.as_slice(), &[1]);
+        }
+
+        SHITCOIN_DELIST_REASON => {
+            let denom_bytes = parts.next().unwrap();
+
+            let denom = std::str::from_utf8(denom_bytes).unwrap();
+
+            state
+                .shitcoins
+                .entry(denom.to_owned())
+                .or_default()
+                .delist_reason = Some(String::from_utf8(model.value).unwrap());
+        }
+
         SHITCOIN_SUPPLY => {
             let denom_bytes = parts.next().unwrap();
 
@@ -187,10 +256,27 @@ fn add_model_to_state(model: Model, state: &mut ShitcoinGardenState) {
 fn decode_contract_state(models: Vec<Model>) -> ShitcoinGardenState {
     let mut state = ShitcoinGardenState::default();
 
+    let mut legacy_presale_denom = None;
+
     for model in models {
+        // contracts from before multiple presale denoms keep a single global one
+        if model.key == PRESALE_DENOM {
+            legacy_presale_denom = Some(String::from_utf8(model.value).unwrap());
+
+            continue;
+        }
+
         add_model_to_state(model, &mut state);
     }
 
+    if let Some(legacy_presale_denom) = legacy_presale_denom {
+        state
+            .shitcoins
+            .values_mut()
+            .filter(|shitcoin| shitcoin.presale_denom.is_empty())
+            .for_each(|shitcoin| shitcoin.presale_denom = legacy_presale_denom.clone());
+    }
+
     state
 }
 
@@ -306,6 +392,45 @@ pub async fn query_shitcoin_url(cw: CwClient, denom: &str) -> Result<String> {
     Ok(url)
 }
 
+pub async fn query_shitcoin_hidden(cw: CwClient, denom: &str) -> Result<bool> {
+    let raw = query_shitcoin_meta_raw(cw, SHITCOIN_HIDDEN, denom).await?;
+    let hidden = matches!(raw.as_slice(), [1]);
+
+    Ok(hidden)
+}
+
+pub async fn query_shitcoin_delist_reason(cw: CwClient, denom: &str) -> Result<Option<String>> {
+    let raw = query_shitcoin_meta_raw(cw, SHITCOIN_DELIST_REASON, denom).await?;
+
+    if raw.is_empty() {
+        return Ok(None);
+    }
+
+    let reason = String::from_utf8(raw)?;
+
+    Ok(Some(reason))
+}
+
+pub async fn query_shitcoin_presale_denom(mut cw: CwClient, denom: &str) -> Result<String> {
+    let mut raw = query_shitcoin_meta_raw(cw.clone(), PRESALE_DENOM, denom).await?;
+
+    // contracts from before multiple presale denoms keep a single global one
+    if raw.is_empty() {
+        let response = cw
+            .raw_contract_state(QueryRawContractStateRequest {
+                address: SHITCOIN_GARDEN_CONTRACT.to_owned(),
+                query_data: PRESALE_DENOM.to_vec(),
+            })
+            .await?;
+
+        raw = response.into_inner().data;
+    }
+
+    let presale_denom = String::from_utf8(raw)?;
+
+    Ok(presale_denom)
+}
+
 pub async fn query_shitcoin_presale_end(cw: CwClient, denom: &str) -> Result<u64> {
     let raw = query_shitcoin_meta_raw(cw, PRESALE_END, denom).await?;
     let le_bytes_arr = raw.try_into().unwrap();
@@ -344,10 +469,11 @@ pub async fn query_shitcoin_metadata(cw: &mut CwClient, denom: &str) -> Result<S
     )
     .await?;
 
-    let (ticker, name, url) = try_join3(
+    let (ticker, name, url, presale_denom) = try_join4(
         query_shitcoin_ticker(cw.clone(), denom),
         query_shitcoin_name(cw.clone(), denom),
         query_shitcoin_url(cw.clone(), denom),
+        query_shitcoin_presale_denom(cw.clone(), denom),
     )
     .await?;
 
@@ -356,15 +482,42 @@ pub async fn query_shitcoin_metadata(cw: &mut CwClient, denom: &str) -> Result<S
         ticker,
         name,
         url,
+        presale_denom,
         presale_end,
         presale_raise,
         supply,
         launched,
+        // nobody has had the chance to moderate a shitcoin yet when it is created
+        hidden: false,
+        delist_reason: None,
     };
 
     Ok(shitcoin)
 }
 
+/// What the degen gets once the shitcoin launches, as the garden itself works it out.
+pub async fn query_degen_claimable(mut cw: CwClient, denom: &str, degen: &str) -> Result<u128> {
+    #[derive(serde::Deserialize)]
+    struct Claimable {
+        amount: String,
+    }
+
+    let query_data = serde_json::to_vec(&serde_json::json!({
+        "claimable": { "denom": denom, "degen": degen }
+    }))?;
+
+    let response = cw
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: SHITCOIN_GARDEN_CONTRACT.to_owned(),
+            query_data,
+        })
+        .await?;
+
+    let claimable: Claimable = serde_json::from_slice(&response.into_inner().data)?;
+
+    Ok(claimable.amount.parse()?)
+}
+
 pub async fn query_degen_submission(cw: CwClient, denom: &str, degen: &str) -> Result<u128> {
     let raw = query_degen_meta_raw(cw, PRESALE_SUBMISSION, denom, degen).await?;
 
@@ -385,21 +538,31 @@ pub async fn query_degen_claimed(cw: CwClient, denom: &str, degen: &str) -> Resu
     Ok(claimed)
 }
 
+#[allow(dead_code)]
+pub async fn query_degen_refunded(cw: CwClient, denom: &str, degen: &str) -> Result<bool> {
+    let raw = query_degen_meta_raw(cw, PRESALE_REFUNDED, denom, degen).await?;
+    let refunded = matches!(raw.as_slice(), [1]);
+
+    Ok(refunded)
+}
+
 #[allow(dead_code)]
 pub async fn query_degen_metadata(
     cw: &mut CwClient,
     denom: &str,
     degen: &str,
 ) -> Result<DegenMeta> {
-    let (submission, claimed) = try_join(
+    let (submission, claimed, refunded) = try_join3(
         query_degen_submission(cw.clone(), denom, degen),
         query_degen_claimed(cw.clone(), denom, degen),
+        query_degen_refunded(cw.clone(), denom, degen),
     )
     .await?;
 
     let degen = DegenMeta {
         submission,
         claimed,
+        refunded,
     };
 
     Ok(degen)
@@ -82,39 +82,32 @@ struct Shitcoin {
     ticker: String,
     name: String,
     url: String,
+    presale_denom: String,
     presale_end: u64,
     presale_raise: Amount,
     supply: Amount,
     ended: bool,
     launched: bool,
+    hidden: bool,
+    delist_reason: Option<String>,
     degen: Option<Degen>,
 }
 
 impl Shitcoin {
-    fn percent_of_presale(&self) -> Percent {
-        let Some(degen) = self.degen.as_ref() else {
-            return Percent(BigDecimal::zero());
-        };
-
-        if self.presale_raise.is_zero() {
-            return Percent(BigDecimal::zero());
-        }
-
-        Percent((&degen.presale_submission.0 / &self.presale_raise.0) * 100)
+    fn is_moderated(&self) -> bool {
+        self.hidden || self.delist_reason.is_some()
     }
 
-    fn percent_of_supply(&self) -> Percent {
-        Percent(self.percent_of_presale().0 / 2)
-    }
-
-    fn claimable_amount(&self) -> Amount {
-        let pos = self.percent_of_supply();
-
-        Amount((pos.0 * self.supply.mm().0) / 100)
+    fn display_name(&self) -> &str {
+        match (self.hidden, self.delist_reason.is_some()) {
+            (_, true) => "delisted",
+            (true, false) => "hidden",
+            (false, false) => &self.name,
+        }
     }
 
     fn icon_url(&self) -> String {
-        if self.url.is_empty() {
+        if self.url.is_empty() || self.is_moderated() {
             return "/static/shitcoin.png".to_owned();
         }
 
@@ -148,7 +141,6 @@ pub struct IndexTemplate;
 #[derive(Template, Default)]
 #[template(path = "presales.html")]
 pub struct PresalesTemplate {
-    balance: Option<Amount>,
     degen: Option<String>,
     shitcoins: Vec<Shitcoin>,
     last_block_time: u64,
@@ -165,10 +157,13 @@ fn get_shitcoin(
         ticker,
         name,
         url,
+        presale_denom,
         presale_end,
         presale_raise,
         supply,
         launched,
+        hidden,
+        delist_reason,
     } = state.shitcoins.get(&denom).cloned().unwrap();
 
     let ended = presale_end.saturating_sub(chain_timestamp) == 0;
@@ -190,11 +185,14 @@ fn get_shitcoin(
         ticker,
         name,
         url,
+        presale_denom,
         presale_end,
         presale_raise: presale_raise.into(),
         supply: supply.into(),
         ended,
         launched,
+        hidden,
+        delist_reason,
         degen,
     }
 }
@@ -207,6 +205,10 @@ fn get_shitcoins(
     let mut shitcoins = Vec::with_capacity(state.indexes.len());
 
     for denom in state.indexes.values().cloned().rev() {
+        if state.shitcoins[&denom].is_moderated() {
+            continue;
+        }
+
         let shitcoin = get_shitcoin(denom, state, chain_timestamp, degen);
         shitcoins.push(shitcoin);
     }
@@ -228,16 +230,12 @@ impl PresalesTemplate {
         state: &ShitcoinGardenState,
         last_block_time: u64,
         degen: String,
-        balance: u128,
     ) -> Self {
         let shitcoins = get_shitcoins(state, last_block_time, Some(&degen));
 
-        let balance = Some(balance.into());
-
         let degen = Some(degen);
 
         Self {
-            balance,
             degen,
             shitcoins,
             last_block_time,
@@ -279,10 +277,13 @@ impl UpdatedPresaleTemplate {
             ticker,
             name,
             url,
+            presale_denom,
             presale_end,
             presale_raise,
             supply,
             launched,
+            hidden,
+            delist_reason,
         } = shitcoin;
 
         let ended = presale_end.saturating_sub(last_block_time) == 0;
@@ -293,11 +294,14 @@ impl UpdatedPresaleTemplate {
             name,
             ticker,
             url,
+            presale_denom,
             presale_end,
             presale_raise: presale_raise.into(),
             supply: supply.into(),
             ended,
             launched,
+            hidden,
+            delist_reason,
             degen: None,
         };
 
@@ -363,3 +367,14 @@ impl PresaleTemplate {
 pub fn balance(amount: u128) -> String {
     Amount(BigDecimal::from(amount)).mm().to_string()
 }
+
+pub fn claimable(amount: u128, supply: u128) -> String {
+    let amount = Amount::from(amount);
+
+    let percent_of_supply = match supply {
+        0 => Percent(BigDecimal::zero()),
+        supply => Percent(amount.0.clone() * 100 / BigDecimal::from(supply)),
+    };
+
+    format!("{percent_of_supply}% of supply ({})", amount.mm())
+}